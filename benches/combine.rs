@@ -0,0 +1,82 @@
+//! Times minimizing functions of 16 to 24 variables, which is dominated by combining the implicants
+//! while finding the prime implicants.
+//!
+//! Each function is the union of a few random cubes, so it has many minterms but few prime implicants,
+//! and the number of implicants to combine stays similar as the variables grow.
+//! Run with `cargo bench`, and with `cargo bench --features parallel` to compare.
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use quine_mccluskey as qmc;
+use rand::{rngs::StdRng, seq::index::sample, Rng, SeedableRng};
+
+const ITERATIONS: u32 = 3;
+const CUBE_COUNT: usize = 8;
+const FREE_VARIABLE_COUNT: usize = 10;
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(528);
+
+    println!("variables   minterms  products  time");
+
+    for variable_count in [16, 18, 20, 22, 24] {
+        let minterms = random_cube_union(&mut rng, variable_count);
+        let variables = Vec::from_iter((0..variable_count).map(|i| format!("x{}", i)));
+
+        let (solution, time) = measure(|| {
+            qmc::minimize_minterms(&variables, &minterms, &[], false, None)
+                .unwrap()
+                .pop()
+                .unwrap()
+        });
+
+        println!(
+            "{:>9}  {:>9}  {:>8}  {:.2?}",
+            variable_count,
+            minterms.len(),
+            solution.to_string().split('∨').count(),
+            time
+        );
+    }
+}
+
+/// Returns the minterms of the union of [`CUBE_COUNT`] random cubes with [`FREE_VARIABLE_COUNT`] free variables.
+fn random_cube_union(rng: &mut StdRng, variable_count: u32) -> Vec<u32> {
+    let mut minterms = HashSet::new();
+
+    for _ in 0..CUBE_COUNT {
+        let mask = sample(rng, variable_count as usize, FREE_VARIABLE_COUNT)
+            .iter()
+            .fold(0u32, |mask, bit| mask | 1 << bit);
+        let value = rng.random::<u32>() & !mask & ((1 << variable_count) - 1);
+
+        // Every subset of the free bits.
+        let mut free = mask;
+        loop {
+            minterms.insert(value | free);
+            if free == 0 {
+                break;
+            }
+            free = (free - 1) & mask;
+        }
+    }
+
+    let mut minterms = Vec::from_iter(minterms);
+    minterms.sort_unstable();
+    minterms
+}
+
+/// Returns the result of `f` and the shortest time it took over [`ITERATIONS`] runs.
+fn measure<T>(f: impl Fn() -> T) -> (T, Duration) {
+    let mut result = None;
+    let mut shortest = Duration::MAX;
+
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        result = Some(f());
+        shortest = shortest.min(start.elapsed());
+    }
+
+    (result.unwrap(), shortest)
+}
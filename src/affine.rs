@@ -0,0 +1,86 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A function of the form `c ⊕ x1 ⊕ x2 ⊕ ...`, i.e. an affine function over GF(2).
+///
+/// See [`BooleanFunction::affine_form`](crate::BooleanFunction::affine_form).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AffineForm {
+    /// The constant `c`. The function is the complement of the XOR of the variables if it is `true`.
+    pub constant: bool,
+    /// The variables whose XOR is taken, in the order of the variables of the function.
+    pub variables: Vec<String>,
+}
+
+impl Display for AffineForm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.variables.is_empty() {
+            return write!(f, "{}", self.constant as u8);
+        }
+
+        write!(f, "{}", self.variables.join(" ⊕ "))?;
+
+        if self.constant {
+            write!(f, " ⊕ 1")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Solves the system of linear equations `c ⊕ a · term = output` over GF(2) for the given care terms.
+///
+/// Returns the constant `c` and the coefficients `a` as a mask of term bits, with free coefficients set to 0,
+/// or `None` if the system is inconsistent.
+pub fn fit(
+    variable_count: u32,
+    care_terms: impl IntoIterator<Item = (u32, bool)>,
+) -> Option<(bool, u32)> {
+    // The unknowns are the coefficients of the term bits and the constant at bit `variable_count`.
+    let mut basis: Vec<Option<(u32, bool)>> = vec![None; variable_count as usize + 1];
+
+    for (term, output) in care_terms {
+        let mut row = 1 << variable_count | term;
+        let mut rhs = output;
+
+        for bit in (0..=variable_count).rev() {
+            if row >> bit & 1 == 0 {
+                continue;
+            }
+
+            match basis[bit as usize] {
+                Some((pivot_row, pivot_rhs)) => {
+                    row ^= pivot_row;
+                    rhs ^= pivot_rhs;
+                }
+                None => {
+                    basis[bit as usize] = Some((row, rhs));
+                    break;
+                }
+            }
+        }
+
+        if row == 0 && rhs {
+            return None;
+        }
+    }
+
+    let mut solution = 0u32;
+
+    for bit in 0..=variable_count {
+        if let Some((row, rhs)) = basis[bit as usize] {
+            let lower_mask = (1 << bit) - 1;
+            let value = rhs ^ ((row & lower_mask & solution).count_ones() % 2 == 1);
+
+            solution |= (value as u32) << bit;
+        }
+    }
+
+    Some((
+        solution >> variable_count & 1 == 1,
+        solution & ((1 << variable_count) - 1),
+    ))
+}
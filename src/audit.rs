@@ -0,0 +1,145 @@
+use std::fmt::Write;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{implicant::Implicant, Cube};
+
+/// A decision made while simplifying the prime implicant chart.
+///
+/// Terms are the terms to cover, i.e. minterms in [`SOP`](crate::SOP) form and maxterms in [`POS`](crate::POS) form,
+/// and implicants are given as the cubes of the terms they cover, as in [`Cover::cubes`](crate::Cover::cubes).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(tag = "kind", rename_all = "snake_case")
+)]
+pub enum AuditEvent {
+    /// The implicant was extracted as essential because it is the only one covering the term.
+    EssentialPrimeImplicant { implicant: Cube, term: u32 },
+    /// The term was removed because every implicant covering `dominated_term` also covers it.
+    DominatingTermRemoved { term: u32, dominated_term: u32 },
+    /// The implicant was removed because `dominating_implicant` covers all of its remaining terms
+    /// with at most as many literals.
+    DominatedImplicantRemoved {
+        implicant: Cube,
+        dominating_implicant: Cube,
+    },
+}
+
+/// The decisions made while simplifying the prime implicant chart, in the order they were made.
+///
+/// See [`minimize_audited`](crate::minimize_audited).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuditLog {
+    /// The decisions in the order they were made.
+    pub events: Vec<AuditEvent>,
+}
+
+impl AuditLog {
+    pub(crate) fn new(reductions: &[Reduction], variable_count: u32) -> Self {
+        let cube = |implicant| Cube::from_implicant(implicant, variable_count);
+
+        AuditLog {
+            events: reductions
+                .iter()
+                .map(|reduction| match *reduction {
+                    Reduction::Essential { implicant, term } => {
+                        AuditEvent::EssentialPrimeImplicant {
+                            implicant: cube(implicant),
+                            term,
+                        }
+                    }
+                    Reduction::DominatingTerm {
+                        term,
+                        dominated_term,
+                    } => AuditEvent::DominatingTermRemoved {
+                        term,
+                        dominated_term,
+                    },
+                    Reduction::DominatedImplicant {
+                        implicant,
+                        dominating_implicant,
+                    } => AuditEvent::DominatedImplicantRemoved {
+                        implicant: cube(implicant),
+                        dominating_implicant: cube(dominating_implicant),
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the log as JSON, with the same structure as its `serde` serialization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let (_, audit_log) =
+    ///     qmc::minimize_audited(&["A", "B"], &[2, 3], &[0, 1], qmc::SOP, false, None).unwrap();
+    ///
+    /// assert_eq!(
+    ///     audit_log.to_json(),
+    ///     r#"{"events":[{"kind":"essential_prime_implicant","implicant":"1-","term":2}]}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::from(r#"{"events":["#);
+
+        for (i, event) in self.events.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+
+            // Cubes only consist of `0`, `1` and `-`, so they don't need escaping.
+            match event {
+                AuditEvent::EssentialPrimeImplicant { implicant, term } => write!(
+                    json,
+                    r#"{{"kind":"essential_prime_implicant","implicant":"{}","term":{}}}"#,
+                    implicant, term
+                ),
+                AuditEvent::DominatingTermRemoved {
+                    term,
+                    dominated_term,
+                } => write!(
+                    json,
+                    r#"{{"kind":"dominating_term_removed","term":{},"dominated_term":{}}}"#,
+                    term, dominated_term
+                ),
+                AuditEvent::DominatedImplicantRemoved {
+                    implicant,
+                    dominating_implicant,
+                } => write!(
+                    json,
+                    r#"{{"kind":"dominated_implicant_removed","implicant":"{}","dominating_implicant":"{}"}}"#,
+                    implicant, dominating_implicant
+                ),
+            }
+            .unwrap();
+        }
+
+        json.push_str("]}");
+
+        json
+    }
+}
+
+/// A decision recorded by the prime implicant chart, before the number of variables is known.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Reduction {
+    Essential {
+        implicant: Implicant,
+        term: u32,
+    },
+    DominatingTerm {
+        term: u32,
+        dominated_term: u32,
+    },
+    DominatedImplicant {
+        implicant: Implicant,
+        dominating_implicant: Implicant,
+    },
+}
@@ -0,0 +1,315 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{own_variables, validate_variables, BooleanFunction, Cover, Cube, Error, SOP};
+
+/// A single-output logic function in the Berkeley Logic Interchange Format (BLIF) used by synthesis tools such as ABC.
+///
+/// Only a model with a single `.names` table is supported. The supported keywords are `.model`, `.inputs`,
+/// `.outputs`, `.names` and `.end`, comments start with `#` and a line ending with `\` continues on the next one.
+///
+/// The last name of `.names` is the output and the others are the inputs, which are the variables of the function
+/// in the same order. Each row of the table is a cube of the inputs followed by the output value, which must be
+/// the same for all rows: if it is `1`, the cubes are the on-set and the rest of the terms the off-set,
+/// and if it is `0`, the cubes are the off-set and the rest of the terms the on-set. A table without rows is
+/// the constant 0.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let blif: qmc::Blif = "\
+/// .model example
+/// .inputs a b c
+/// .outputs f
+/// .names a b c f
+/// 1-1 1
+/// 0-0 1
+/// .end
+/// "
+/// .parse()
+/// .unwrap();
+///
+/// assert_eq!(blif.model(), Some("example"));
+/// assert_eq!(blif.output(), "f");
+///
+/// let mut solutions = blif.function().minimize(qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(a ∧ c) ∨ (~a ∧ ~c)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Blif {
+    model: Option<String>,
+    output: String,
+    function: BooleanFunction,
+}
+
+impl Blif {
+    /// Returns the name of the model, if any.
+    pub fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    /// Returns the name of the output.
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Returns the function of the output, whose variables are the inputs of the table.
+    pub fn function(&self) -> &BooleanFunction {
+        &self.function
+    }
+}
+
+impl FromStr for Blif {
+    type Err = Error;
+
+    /// Parses a BLIF file.
+    ///
+    /// Returns [`Error::InvalidBlif`] if the file is malformed or uses unsupported features,
+    /// and the same errors as [`minimize`](crate::minimize) for invalid input names.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut model = None;
+        let mut outputs = None;
+        let mut names = None;
+        let mut rows = vec![];
+
+        for (line_number, line) in logical_lines(s) {
+            let line = line.split('#').next().unwrap().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if !line.starts_with('.') {
+                if names.is_none() {
+                    return Err(invalid(line_number, "row outside of a .names table"));
+                }
+
+                rows.push((line_number, line.to_owned()));
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let keyword = words.next().unwrap();
+            let arguments = Vec::from_iter(words);
+
+            match keyword {
+                ".model" => model = arguments.first().map(|&name| name.to_owned()),
+                ".inputs" => {}
+                ".outputs" => outputs = Some(own_variables(&arguments)),
+                ".names" if names.is_some() => {
+                    return Err(invalid(
+                        line_number,
+                        "only a single .names table is supported",
+                    ))
+                }
+                ".names" if arguments.is_empty() => {
+                    return Err(invalid(line_number, "expected the names of the table"))
+                }
+                ".names" => names = Some(own_variables(&arguments)),
+                ".end" => break,
+                _ => {
+                    return Err(invalid(
+                        line_number,
+                        format!("unsupported keyword {}", keyword),
+                    ))
+                }
+            }
+        }
+
+        let mut inputs = names.ok_or_else(|| Error::InvalidBlif("missing .names".to_owned()))?;
+        let output = inputs.pop().unwrap();
+
+        if let Some(outputs) = outputs {
+            if outputs != [output.as_str()] {
+                return Err(Error::InvalidBlif(format!(
+                    ".outputs is {:?} but the table defines {:?}",
+                    outputs.join(" "),
+                    output
+                )));
+            }
+        }
+
+        validate_variables(&inputs)?;
+
+        let input_count = inputs.len();
+        let mut cover = HashSet::new();
+        let mut output_value = None;
+
+        for (line_number, line) in rows {
+            let line = line.replace(char::is_whitespace, "");
+
+            if line.len() != input_count + 1 || !line.is_ascii() {
+                return Err(invalid(
+                    line_number,
+                    format!(
+                        "expected {} input characters and an output value",
+                        input_count
+                    ),
+                ));
+            }
+
+            let (cube, value) = line.split_at(input_count);
+            let value = match value {
+                "1" => true,
+                "0" => false,
+                _ => {
+                    return Err(invalid(
+                        line_number,
+                        format!("invalid output value {:?}", value),
+                    ))
+                }
+            };
+
+            if *output_value.get_or_insert(value) != value {
+                return Err(invalid(line_number, "rows with different output values"));
+            }
+
+            let cube = cube
+                .parse::<Cube>()
+                .map_err(|_| invalid(line_number, format!("invalid cube {:?}", cube)))?;
+
+            cover.extend(cube.terms());
+        }
+
+        let (cover, rest): (Vec<u32>, Vec<u32>) =
+            (0..1 << input_count).partition(|term| cover.contains(term));
+        let function = if output_value == Some(false) {
+            BooleanFunction::new(&inputs, &rest, &cover)?
+        } else {
+            BooleanFunction::new(&inputs, &cover, &rest)?
+        };
+
+        Ok(Blif {
+            model,
+            output,
+            function,
+        })
+    }
+}
+
+/// Returns the `solutions` as a BLIF model named `model` with a `.names` table for each output, named after `outputs`.
+///
+/// The inputs of the model are the variables of the solutions, and the tables are the ones of [`Cover::to_blif`].
+/// A model of a single output is read back by [`Blif`] as the same function.
+///
+/// Returns [`Error::InvalidBlif`] if there are no solutions or the number of `outputs` is different from
+/// the number of solutions, and [`Error::MismatchedVariables`] if the solutions don't all have the same variables.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["A", "B"];
+/// let solutions = [
+///     qmc::BooleanFunction::new(&variables, &[1, 3], &[0, 2])
+///         .unwrap()
+///         .minimize(qmc::SOP, false, None).unwrap().remove(0),
+///     qmc::BooleanFunction::new(&variables, &[1, 2, 3], &[0])
+///         .unwrap()
+///         .minimize(qmc::SOP, false, None).unwrap().remove(0),
+/// ];
+///
+/// assert_eq!(
+///     qmc::solutions_to_blif("example", &solutions, &["x", "y"]).unwrap(),
+///     ".model example\n.inputs A B\n.outputs x y\n.names A B x\n-1 1\n.names A B y\n1- 1\n-1 1\n.end\n"
+/// );
+/// ```
+pub fn solutions_to_blif<T: AsRef<str>>(
+    model: &str,
+    solutions: &[Cover],
+    outputs: &[T],
+) -> Result<String, Error> {
+    let first_solution = solutions
+        .first()
+        .ok_or_else(|| Error::InvalidBlif("no solutions".to_owned()))?;
+
+    if outputs.len() != solutions.len() {
+        return Err(Error::InvalidBlif(format!(
+            "{} output names for {} solutions",
+            outputs.len(),
+            solutions.len()
+        )));
+    }
+
+    if let Some(solution) = solutions
+        .iter()
+        .find(|solution| solution.variables() != first_solution.variables())
+    {
+        return Err(Error::MismatchedVariables {
+            expected: first_solution.variables().to_vec(),
+            found: solution.variables().to_vec(),
+        });
+    }
+
+    let outputs = own_variables(outputs);
+    let mut blif = format!(
+        ".model {}\n.inputs {}\n.outputs {}\n",
+        model,
+        first_solution.variables().join(" "),
+        outputs.join(" ")
+    );
+
+    for (solution, output) in solutions.iter().zip(&outputs) {
+        blif.push_str(&write_names(solution, output));
+    }
+
+    blif.push_str(".end\n");
+
+    Ok(blif)
+}
+
+pub(crate) fn write_names(solution: &Cover, output: &str) -> String {
+    let mut names = format!(".names {} {}\n", solution.variables().join(" "), output);
+
+    // The table of a solution in POS form is its off-set, except for the constant 1 which has no sums.
+    if solution.form() != SOP && solution.is_one() {
+        names.push_str(&format!("{} 1\n", "-".repeat(solution.variables().len())));
+        return names;
+    }
+
+    let output_value = if solution.form() == SOP { '1' } else { '0' };
+
+    for cube in solution.cubes() {
+        names.push_str(&format!("{} {}\n", cube, output_value));
+    }
+
+    names
+}
+
+/// Returns the lines of `s` with their line numbers, where lines ending with `\` are joined with the next one.
+fn logical_lines(s: &str) -> Vec<(usize, String)> {
+    let mut lines = vec![];
+    let mut pending: Option<(usize, String)> = None;
+
+    for (i, line) in s.lines().enumerate() {
+        let (line_number, mut joined) = pending.take().unwrap_or((i + 1, String::new()));
+
+        match line.strip_suffix('\\') {
+            Some(line) => {
+                joined.push_str(line);
+                joined.push(' ');
+                pending = Some((line_number, joined));
+            }
+            None => {
+                joined.push_str(line);
+                lines.push((line_number, joined));
+            }
+        }
+    }
+
+    lines.extend(pending);
+    lines
+}
+
+fn invalid(line_number: usize, reason: impl Display) -> Error {
+    Error::InvalidBlif(format!("line {}: {}", line_number, reason))
+}
@@ -0,0 +1,34 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Polarity;
+
+/// A variable of a [`Cover`](crate::Cover) with the polarity it appears with in a [`Clause`] or a [`Product`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Literal {
+    /// The index of the variable in the variables of the solution.
+    pub index: usize,
+    pub variable: String,
+    pub polarity: Polarity,
+}
+
+/// A disjunction of literals, one of the sums of a solution in [`POS`](crate::POS) form.
+///
+/// A clause without literals is false. See [`Cover::clauses`](crate::Cover::clauses).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Clause {
+    /// The literals in the order of the variables of the solution.
+    pub literals: Vec<Literal>,
+}
+
+/// A conjunction of literals, one of the products of a solution in [`SOP`](crate::SOP) form.
+///
+/// A product without literals is true. See [`Cover::products`](crate::Cover::products).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Product {
+    /// The literals in the order of the variables of the solution.
+    pub literals: Vec<Literal>,
+}
@@ -0,0 +1,281 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{implicant::Implicant, Error, Form, Polarity, VariableSet, DEFAULT_VARIABLES};
+
+/// A set of terms written as a string of `0`, `1` and `-` characters, e.g. `"1--0"`.
+///
+/// Each character corresponds to a variable, with the first character being the first variable.
+/// `-` means the variable can be both 0 and 1, so `"1--0"` contains the terms 8, 10, 12 and 14.
+///
+/// # Example
+///
+/// Cubes can be used to pass rectangular regions of don't cares without listing their terms:
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let mut solutions = qmc::minimize_minterms_with_cubes(
+///     &qmc::DEFAULT_VARIABLES[..4],
+///     &[1, 3, 5, 7, 9],
+///     &["1-1-", "11--"],
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "D");
+/// ```
+///
+/// They can also be turned into terms for any of the other minimization functions with [`Cube::expand`]:
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let dont_cares = qmc::Cube::expand(4, &["1-1-", "11--"]).unwrap();
+///
+/// let mut solutions = qmc::minimize_minterms(
+///     &qmc::DEFAULT_VARIABLES[..4],
+///     &[1, 3, 5, 7, 9],
+///     &dont_cares,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "D");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct Cube {
+    implicant: Implicant,
+    variable_count: u32,
+}
+
+impl Cube {
+    /// Parses the given `cubes` and returns all the terms they contain.
+    ///
+    /// Returns [`Error::InvalidCube`] if a cube is malformed or its length is not `variable_count`.
+    ///
+    /// To use cubes as don't cares without listing their terms, see
+    /// [`minimize_minterms_with_cubes`](crate::minimize_minterms_with_cubes).
+    pub fn expand<T: AsRef<str>>(variable_count: usize, cubes: &[T]) -> Result<Vec<u32>, Error> {
+        let mut terms = vec![];
+
+        for cube in Cube::parse_all(variable_count, cubes)? {
+            terms.extend(cube.terms());
+        }
+
+        terms.sort_unstable();
+        terms.dedup();
+
+        Ok(terms)
+    }
+
+    /// Parses the given `cubes` of `variable_count` variables.
+    ///
+    /// Returns [`Error::InvalidCube`] if a cube is malformed or its length is not `variable_count`.
+    pub(crate) fn parse_all<T: AsRef<str>>(
+        variable_count: usize,
+        cubes: &[T],
+    ) -> Result<Vec<Cube>, Error> {
+        cubes
+            .iter()
+            .map(|cube| {
+                let cube: Cube = cube.as_ref().parse()?;
+
+                if cube.variable_count as usize != variable_count {
+                    return Err(Error::InvalidCube(cube.to_string()));
+                }
+
+                Ok(cube)
+            })
+            .collect()
+    }
+
+    /// Parses a cube of the given `variables`.
+    ///
+    /// Returns [`Error::InvalidCube`] if the cube is malformed or its length is not the number of variables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let variables = qmc::VariableSet::new(&["A", "B", "C"]).unwrap();
+    ///
+    /// assert_eq!(qmc::Cube::new(&variables, "1-0").unwrap().terms(), [4, 6]);
+    /// assert!(qmc::Cube::new(&variables, "1-").is_err());
+    /// ```
+    pub fn new(variables: &VariableSet, cube: &str) -> Result<Self, Error> {
+        let cube: Cube = cube.parse()?;
+
+        if cube.variable_count != variables.variable_count() {
+            return Err(Error::InvalidCube(cube.to_string()));
+        }
+
+        Ok(cube)
+    }
+
+    pub(crate) fn from_implicant(implicant: Implicant, variable_count: u32) -> Self {
+        Cube {
+            implicant,
+            variable_count,
+        }
+    }
+
+    pub(crate) fn implicant(&self) -> Implicant {
+        self.implicant
+    }
+
+    /// Returns the cube of the terms contained in both cubes, or `None` if they don't overlap.
+    pub(crate) fn intersection(&self, other: &Cube) -> Option<Cube> {
+        let (value, mask) = (self.implicant.value(), self.implicant.mask());
+        let (other_value, other_mask) = (other.implicant.value(), other.implicant.mask());
+
+        if (value ^ other_value) & !mask & !other_mask != 0 {
+            return None;
+        }
+
+        Some(Cube::from_implicant(
+            Implicant::from_parts(value | other_value, mask & other_mask),
+            self.variable_count,
+        ))
+    }
+
+    /// Returns the number of variables of the cube.
+    pub fn variable_count(&self) -> u32 {
+        self.variable_count
+    }
+
+    /// Returns the number of variables that are not `-`.
+    pub fn literal_count(&self) -> usize {
+        (self.variable_count - self.implicant.wildcard_count()) as usize
+    }
+
+    /// Returns the literal of the variable at the given `index` in the product (in [`SOP`](Form::SOP) form)
+    /// or sum (in [`POS`](Form::POS) form) represented by the cube, or `None` if the variable is `-`.
+    ///
+    /// In [`SOP`](Form::SOP) form a product contains the terms of its cube, so a variable that is `0` is negated.
+    /// In [`POS`](Form::POS) form a sum excludes the terms of its cube, so a variable that is `1` is negated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the number of variables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    /// use qmc::Polarity::{Negative, Positive};
+    ///
+    /// let cube: qmc::Cube = "1-0".parse().unwrap();
+    ///
+    /// // A ∧ ~C
+    /// assert_eq!(cube.literal(0, qmc::SOP), Some(Positive));
+    /// assert_eq!(cube.literal(1, qmc::SOP), None);
+    /// assert_eq!(cube.literal(2, qmc::SOP), Some(Negative));
+    ///
+    /// // ~A ∨ C
+    /// assert_eq!(cube.literal(0, qmc::POS), Some(Negative));
+    /// assert_eq!(cube.literal(2, qmc::POS), Some(Positive));
+    /// ```
+    pub fn literal(&self, index: usize, form: Form) -> Option<Polarity> {
+        assert!(
+            index < self.variable_count as usize,
+            "variable index out of bounds"
+        );
+
+        self.implicant
+            .literal(index, self.variable_count as usize, form)
+    }
+
+    /// Returns the terms contained in the cube in ascending order.
+    pub fn terms(&self) -> Vec<u32> {
+        let mut terms = Vec::from_iter(self.implicant.get_terms());
+        terms.sort_unstable();
+        terms
+    }
+
+    /// Returns whether the cube contains the given `term`.
+    pub fn contains(&self, term: u32) -> bool {
+        self.implicant.contains(term)
+    }
+}
+
+impl Default for Cube {
+    /// Returns the cube `-` of a single variable, e.g. to fill a buffer for [`minimize_const`](crate::minimize_const).
+    fn default() -> Self {
+        Cube::from_implicant(Implicant::from_parts(0, 1), 1)
+    }
+}
+
+impl FromStr for Cube {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || s.len() > DEFAULT_VARIABLES.len() {
+            return Err(Error::InvalidCube(s.to_owned()));
+        }
+
+        let mut value = 0;
+        let mut mask = 0;
+
+        for char in s.chars() {
+            value <<= 1;
+            mask <<= 1;
+
+            match char {
+                '0' => {}
+                '1' => value |= 1,
+                '-' => mask |= 1,
+                _ => return Err(Error::InvalidCube(s.to_owned())),
+            }
+        }
+
+        Ok(Cube::from_implicant(
+            Implicant::from_parts(value, mask),
+            s.len() as u32,
+        ))
+    }
+}
+
+impl Display for Cube {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for i in (0..self.variable_count).rev() {
+            if self.implicant.mask() >> i & 1 == 1 {
+                write!(f, "-")?;
+            } else {
+                write!(f, "{}", self.implicant.value() >> i & 1)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for Cube {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        // The cube of no variables, which is the only term of the constant 1 in SOP form, is written as "".
+        if value.is_empty() {
+            return Ok(Cube::from_implicant(Implicant::from_parts(0, 0), 0));
+        }
+
+        value.parse()
+    }
+}
+
+impl From<Cube> for String {
+    fn from(value: Cube) -> Self {
+        value.to_string()
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{implicant::Implicant, BooleanFunction, Cube, Error, VariableSet};
+
+const MAGIC: &[u8; 4] = b"QMCL";
+const VERSION: u8 = 1;
+
+/// The on-set, off-set and don't care set of a function given as cubes, with a compact binary encoding.
+///
+/// The encoding is, with all integers in little endian:
+///
+/// - The magic bytes `QMCL` and the format version `1`.
+/// - The number of variables as a `u8`, followed by each variable as a `u16` byte length and its UTF-8 bytes.
+/// - The on-set, off-set and don't care set, each as a `u32` cube count followed by the cubes.
+///   A cube is its value bits followed by its `-` bits, each in `ceil(variables / 8)` bytes
+///   with the last variable as the least significant bit.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = qmc::VariableSet::new(&["A", "B", "C"]).unwrap();
+/// let cube = |cube| qmc::Cube::new(&variables, cube).unwrap();
+///
+/// let cube_list =
+///     qmc::CubeList::new(&variables, &[cube("1--")], &[cube("00-")], &[cube("01-")]).unwrap();
+/// let bytes = cube_list.to_bytes().unwrap();
+///
+/// assert_eq!(qmc::CubeList::from_bytes(&bytes).unwrap(), cube_list);
+///
+/// let function = cube_list.to_function().unwrap();
+/// let mut solutions = function.minimize(qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "A");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CubeList {
+    variables: VariableSet,
+    on_set: Vec<Cube>,
+    off_set: Vec<Cube>,
+    dc_set: Vec<Cube>,
+}
+
+impl CubeList {
+    /// Creates a cube list from the cubes of the on-set, off-set and don't care set of the given `variables`.
+    ///
+    /// Returns [`Error::InvalidCube`] if a cube doesn't have a character for each variable
+    /// and [`Error::TermConflict`] if cubes of different sets overlap.
+    pub fn new(
+        variables: &VariableSet,
+        on_set: &[Cube],
+        off_set: &[Cube],
+        dc_set: &[Cube],
+    ) -> Result<Self, Error> {
+        for cube in on_set.iter().chain(off_set).chain(dc_set) {
+            if cube.variable_count() != variables.variable_count() {
+                return Err(Error::InvalidCube(cube.to_string()));
+            }
+        }
+
+        let mut conflicts = HashSet::new();
+
+        for (set, other_set) in [(on_set, off_set), (on_set, dc_set), (off_set, dc_set)] {
+            for cube in set {
+                for other_cube in other_set {
+                    if let Some(intersection) = cube.intersection(other_cube) {
+                        conflicts.extend(intersection.terms());
+                    }
+                }
+            }
+        }
+
+        if !conflicts.is_empty() {
+            return Err(Error::TermConflict(conflicts));
+        }
+
+        Ok(CubeList {
+            variables: variables.clone(),
+            on_set: on_set.to_vec(),
+            off_set: off_set.to_vec(),
+            dc_set: dc_set.to_vec(),
+        })
+    }
+
+    /// Returns the variables of the cube list.
+    pub fn variables(&self) -> &VariableSet {
+        &self.variables
+    }
+
+    /// Returns the cubes of the terms whose output is 1.
+    pub fn on_set(&self) -> &[Cube] {
+        &self.on_set
+    }
+
+    /// Returns the cubes of the terms whose output is 0.
+    pub fn off_set(&self) -> &[Cube] {
+        &self.off_set
+    }
+
+    /// Returns the cubes of the terms whose output is explicitly a don't care.
+    pub fn dc_set(&self) -> &[Cube] {
+        &self.dc_set
+    }
+
+    /// Returns the function given by the on-set and off-set. The rest of the terms are don't cares.
+    pub fn to_function(&self) -> Result<BooleanFunction, Error> {
+        BooleanFunction::from_cubes(&self.variables, &self.on_set, &self.off_set)
+    }
+
+    /// Encodes the cube list in the binary format described in [`CubeList`].
+    ///
+    /// Returns [`Error::InvalidCubeList`] if a variable is longer than `u16::MAX` bytes
+    /// or a set has more than `u32::MAX` cubes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let variable_count = self.variables.variable_count();
+        let cube_width = cube_width(variable_count);
+        let mut bytes = MAGIC.to_vec();
+
+        bytes.push(VERSION);
+        bytes.push(variable_count as u8);
+
+        for variable in self.variables.variables() {
+            let length = u16::try_from(variable.len()).map_err(|_| {
+                Error::InvalidCubeList(format!("variable {:?} is too long", variable))
+            })?;
+
+            bytes.extend(length.to_le_bytes());
+            bytes.extend(variable.as_bytes());
+        }
+
+        for set in [&self.on_set, &self.off_set, &self.dc_set] {
+            let cube_count = u32::try_from(set.len())
+                .map_err(|_| Error::InvalidCubeList("too many cubes".to_owned()))?;
+
+            bytes.extend(cube_count.to_le_bytes());
+
+            for cube in set {
+                let implicant = cube.implicant();
+
+                bytes.extend(&implicant.value().to_le_bytes()[..cube_width]);
+                bytes.extend(&implicant.mask().to_le_bytes()[..cube_width]);
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    /// Decodes a cube list in the binary format described in [`CubeList`].
+    ///
+    /// Returns [`Error::InvalidCubeList`] if the bytes are not in the format,
+    /// and the same errors as [`new`](Self::new) and [`VariableSet::new`] for invalid contents.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader { bytes };
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(Error::InvalidCubeList("missing magic bytes".to_owned()));
+        }
+
+        let version = reader.take_u8()?;
+
+        if version != VERSION {
+            return Err(Error::InvalidCubeList(format!(
+                "unsupported version {}",
+                version
+            )));
+        }
+
+        let variable_count = reader.take_u8()? as u32;
+        let mut variables = vec![];
+
+        for _ in 0..variable_count {
+            let length = reader.take_u16()?;
+            let variable = std::str::from_utf8(reader.take(length as usize)?)
+                .map_err(|_| Error::InvalidCubeList("variable is not valid UTF-8".to_owned()))?;
+
+            variables.push(variable.to_owned());
+        }
+
+        let variables = VariableSet::new(&variables)?;
+        let cube_width = cube_width(variable_count);
+        let variable_mask = (1u64 << variable_count) as u32 - 1;
+        let mut sets = [vec![], vec![], vec![]];
+
+        for set in &mut sets {
+            for _ in 0..reader.take_u32()? {
+                let mut value = [0; 4];
+                let mut mask = [0; 4];
+                value[..cube_width].copy_from_slice(reader.take(cube_width)?);
+                mask[..cube_width].copy_from_slice(reader.take(cube_width)?);
+
+                let (value, mask) = (u32::from_le_bytes(value), u32::from_le_bytes(mask));
+
+                if (value | mask) & !variable_mask != 0 {
+                    return Err(Error::InvalidCubeList(
+                        "cube has bits beyond the number of variables".to_owned(),
+                    ));
+                }
+
+                set.push(Cube::from_implicant(
+                    Implicant::from_parts(value, mask),
+                    variable_count,
+                ));
+            }
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(Error::InvalidCubeList("trailing bytes".to_owned()));
+        }
+
+        let [on_set, off_set, dc_set] = sets;
+
+        CubeList::new(&variables, &on_set, &off_set, &dc_set)
+    }
+}
+
+fn cube_width(variable_count: u32) -> usize {
+    (variable_count as usize + 7) / 8
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        if self.bytes.len() < count {
+            return Err(Error::InvalidCubeList("unexpected end of input".to_owned()));
+        }
+
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+
+        Ok(taken)
+    }
+
+    fn take_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn take_u16(&mut self) -> Result<u16, Error> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn take_u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{validate_variables, BooleanFunction, Error, DEFAULT_VARIABLES};
+
+/// A formula in conjunctive normal form read from a DIMACS CNF file, as used by SAT solvers.
+///
+/// The satisfying assignments of the clauses are the on-set of the function and the rest of the terms
+/// are the off-set, so minimizing it in [`POS`](crate::POS) form gives a smaller equivalent CNF.
+///
+/// Lines starting with `c` are comments, and the `p cnf` line gives the number of variables and clauses.
+/// The clauses are the integers after it, each ending with `0`, where `i` is the `i`th variable and `-i` is its
+/// negation. A line `%` ends the clauses as in the SATLIB benchmarks. Variable `1` is the first variable
+/// of the function.
+///
+/// The variables are named by comment lines like `c 1 A` as written by [`Cover::to_dimacs`](crate::Cover::to_dimacs)
+/// if every variable has one, and after [`DEFAULT_VARIABLES`] otherwise. Since all terms of the function
+/// are enumerated, the file should only have a few variables.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let dimacs: qmc::Dimacs = "\
+/// c (A ∨ B) ∧ (A ∨ ~B) ∧ (~A ∨ C)
+/// p cnf 3 3
+/// 1 2 0
+/// 1 -2 0
+/// -1 3 0
+/// "
+/// .parse()
+/// .unwrap();
+///
+/// assert_eq!(dimacs.clause_count(), 3);
+///
+/// let mut solutions = dimacs.function().minimize(qmc::POS, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "A ∧ C");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Dimacs {
+    clause_count: usize,
+    function: BooleanFunction,
+}
+
+impl Dimacs {
+    /// Returns the number of clauses of the formula.
+    pub fn clause_count(&self) -> usize {
+        self.clause_count
+    }
+
+    /// Returns the function of the formula.
+    pub fn function(&self) -> &BooleanFunction {
+        &self.function
+    }
+}
+
+impl FromStr for Dimacs {
+    type Err = Error;
+
+    /// Parses a DIMACS CNF file.
+    ///
+    /// Returns [`Error::InvalidDimacs`] if the file is malformed, and the same errors as
+    /// [`minimize`](crate::minimize) for an invalid number of variables or invalid names.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut header = None;
+        let mut names = HashMap::new();
+        let mut clauses = vec![];
+        let mut clause = vec![];
+
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+
+            if let Some(comment) = line.strip_prefix('c') {
+                let mut words = comment.trim().splitn(2, char::is_whitespace);
+
+                if let (Some(Ok(variable)), Some(name)) =
+                    (words.next().map(str::parse::<usize>), words.next())
+                {
+                    names.insert(variable, name.trim().to_owned());
+                }
+
+                continue;
+            }
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if line == "%" {
+                break;
+            }
+
+            if line.starts_with('p') {
+                if header.is_some() {
+                    return Err(invalid(line_number, "duplicate problem line"));
+                }
+
+                let words = Vec::from_iter(line.split_whitespace());
+
+                header = match words[..] {
+                    ["p", "cnf", variable_count, clause_count] => {
+                        match (variable_count.parse(), clause_count.parse()) {
+                            (Ok(variable_count), Ok(clause_count)) => {
+                                Some((variable_count, clause_count))
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+
+                if header.is_none() {
+                    return Err(invalid(
+                        line_number,
+                        "expected \"p cnf <variables> <clauses>\"",
+                    ));
+                }
+
+                continue;
+            }
+
+            let (variable_count, _): (usize, usize) =
+                header.ok_or_else(|| invalid(line_number, "clause before the problem line"))?;
+
+            for word in line.split_whitespace() {
+                let literal: i64 = word
+                    .parse()
+                    .map_err(|_| invalid(line_number, format!("invalid literal {:?}", word)))?;
+
+                if literal == 0 {
+                    clauses.push(std::mem::take(&mut clause));
+                } else if literal.unsigned_abs() as usize > variable_count {
+                    return Err(invalid(
+                        line_number,
+                        format!("literal {} of an undeclared variable", literal),
+                    ));
+                } else {
+                    clause.push(literal);
+                }
+            }
+        }
+
+        let (variable_count, clause_count) =
+            header.ok_or_else(|| Error::InvalidDimacs("missing problem line".to_owned()))?;
+
+        if !clause.is_empty() {
+            return Err(Error::InvalidDimacs(
+                "the last clause does not end with 0".to_owned(),
+            ));
+        }
+
+        if clauses.len() != clause_count {
+            return Err(Error::InvalidDimacs(format!(
+                "expected {} clauses but found {}",
+                clause_count,
+                clauses.len()
+            )));
+        }
+
+        let variables = if (1..=variable_count).all(|variable| names.contains_key(&variable)) {
+            Vec::from_iter((1..=variable_count).map(|variable| names.remove(&variable).unwrap()))
+        } else {
+            Vec::from_iter(
+                DEFAULT_VARIABLES
+                    .iter()
+                    .take(variable_count)
+                    .map(|&variable| variable.to_owned()),
+            )
+        };
+
+        if variables.len() != variable_count {
+            return Err(Error::InvalidVariableCount(variable_count));
+        }
+
+        validate_variables(&variables)?;
+
+        // A term falsifies a clause if it has the opposite value of each literal, which is
+        // impossible if the clause has both literals of a variable.
+        let falsifying_cubes = Vec::from_iter(clauses.iter().filter_map(|clause| {
+            let mut mask = 0u32;
+            let mut value = 0u32;
+
+            for &literal in clause {
+                let bit = 1 << (variable_count - literal.unsigned_abs() as usize);
+                let bit_value = if literal < 0 { bit } else { 0 };
+
+                if mask & bit != 0 && value & bit != bit_value {
+                    return None;
+                }
+
+                mask |= bit;
+                value |= bit_value;
+            }
+
+            Some((mask, value))
+        }));
+        let (minterms, maxterms): (Vec<u32>, Vec<u32>) =
+            (0..1 << variable_count).partition(|term| {
+                falsifying_cubes
+                    .iter()
+                    .all(|&(mask, value)| term & mask != value)
+            });
+
+        Ok(Dimacs {
+            clause_count,
+            function: BooleanFunction::new(&variables, &minterms, &maxterms)?,
+        })
+    }
+}
+
+fn invalid(line_number: usize, reason: impl Display) -> Error {
+    Error::InvalidDimacs(format!("line {}: {}", line_number, reason))
+}
@@ -0,0 +1,176 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parser::{is_name_char, parse_expr},
+    Error,
+};
+
+/// A boolean expression as a tree, e.g. to transform a [`Cover`](crate::Cover) or write it in a custom format.
+///
+/// See [`Cover::to_ast`](crate::Cover::to_ast).
+///
+/// `Display` writes the expression with the symbols of [`Cover`](crate::Cover)'s `Display` implementation
+/// and only the parentheses needed to parse it back, and [`FromStr`] parses the syntax of
+/// [`parse_expression`](crate::parse_expression) with any variables. Names that are not made of letters, digits
+/// and underscores, or that are a constant, are written in double quotes. Parsing a written expression gives
+/// the same expression, except that [`Expr::And`], [`Expr::Or`] and [`Expr::Xor`] of fewer than two expressions
+/// are written like a constant or the single expression, and that expressions nested more deeply than
+/// the parser allows are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+/// use qmc::Expr::{And, Not, Or, Var};
+///
+/// let expr: qmc::Expr = "(A | B) & ~(C & D) & E".parse().unwrap();
+///
+/// assert_eq!(
+///     expr,
+///     And(vec![
+///         Or(vec![Var("A".to_owned()), Var("B".to_owned())]),
+///         Not(Box::new(And(vec![Var("C".to_owned()), Var("D".to_owned())]))),
+///         Var("E".to_owned()),
+///     ])
+/// );
+/// assert_eq!(expr.to_string(), "(A ∨ B) ∧ ~(C ∧ D) ∧ E");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Expr {
+    /// The conjunction of the expressions, which is 1 if there are none.
+    And(Vec<Expr>),
+    /// The disjunction of the expressions, which is 0 if there are none.
+    Or(Vec<Expr>),
+    /// The exclusive disjunction of the expressions, which is 1 if an odd number of them are 1.
+    Xor(Vec<Expr>),
+    /// The negation of the expression.
+    Not(Box<Expr>),
+    /// The variable with the given name.
+    Var(String),
+    /// The constant 1 if `true` and 0 if `false`.
+    Const(bool),
+}
+
+impl Expr {
+    /// Returns the value of the expression, where the value of each variable is given by `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    /// use qmc::Expr::{And, Not, Var};
+    ///
+    /// let expr = And(vec![Var("A".to_owned()), Not(Box::new(Var("B".to_owned())))]);
+    ///
+    /// assert!(expr.evaluate(&|variable| variable == "A"));
+    /// assert!(!expr.evaluate(&|_| true));
+    /// ```
+    pub fn evaluate(&self, value: &impl Fn(&str) -> bool) -> bool {
+        match self {
+            Expr::And(exprs) => exprs.iter().all(|expr| expr.evaluate(value)),
+            Expr::Or(exprs) => exprs.iter().any(|expr| expr.evaluate(value)),
+            Expr::Xor(exprs) => exprs
+                .iter()
+                .fold(false, |result, expr| result != expr.evaluate(value)),
+            Expr::Not(expr) => !expr.evaluate(value),
+            Expr::Var(name) => value(name),
+            Expr::Const(constant) => *constant,
+        }
+    }
+
+    /// Returns how tightly the expression binds as written, where a higher value binds tighter.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::And(exprs) | Expr::Or(exprs) | Expr::Xor(exprs) if exprs.len() == 1 => {
+                exprs[0].precedence()
+            }
+            Expr::Or(exprs) if !exprs.is_empty() => 1,
+            Expr::Xor(exprs) if !exprs.is_empty() => 2,
+            Expr::And(exprs) if !exprs.is_empty() => 3,
+            Expr::Not(_) => 4,
+            _ => 5,
+        }
+    }
+
+    /// Writes the expression, in parentheses if it binds less tightly than `precedence`.
+    fn write(&self, f: &mut fmt::Formatter<'_>, precedence: u8) -> fmt::Result {
+        if self.precedence() < precedence {
+            write!(f, "(")?;
+            self.write(f, 0)?;
+            return write!(f, ")");
+        }
+
+        let (exprs, operator) = match self {
+            Expr::And(exprs) if exprs.is_empty() => return write!(f, "1"),
+            Expr::Or(exprs) | Expr::Xor(exprs) if exprs.is_empty() => return write!(f, "0"),
+            Expr::And(exprs) => (exprs, " ∧ "),
+            Expr::Or(exprs) => (exprs, " ∨ "),
+            Expr::Xor(exprs) => (exprs, " ⊕ "),
+            Expr::Not(expr) => {
+                write!(f, "~")?;
+                return expr.write(f, 4);
+            }
+            Expr::Var(name) => return write_name(f, name),
+            Expr::Const(constant) => return write!(f, "{}", *constant as u8),
+        };
+
+        if let [expr] = &exprs[..] {
+            return expr.write(f, precedence);
+        }
+
+        // An operand with the same operator is parenthesized so that it is parsed as a separate expression.
+        let operand_precedence = self.precedence() + 1;
+
+        for (i, expr) in exprs.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", operator)?;
+            }
+
+            expr.write(f, operand_precedence)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the `name` as it is if it is parsed back as the same variable, and in double quotes otherwise.
+fn write_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    if !name.is_empty() && name != "0" && name != "1" && name.chars().all(is_name_char) {
+        return write!(f, "{}", name);
+    }
+
+    write!(f, "\"")?;
+
+    for char in name.chars() {
+        if char == '"' || char == '\\' {
+            write!(f, "\\")?;
+        }
+
+        write!(f, "{}", char)?;
+    }
+
+    write!(f, "\"")
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+impl FromStr for Expr {
+    type Err = Error;
+
+    /// Parses an expression with the syntax of [`parse_expression`](crate::parse_expression), where any name is
+    /// a variable.
+    ///
+    /// Returns [`Error::InvalidExpression`] if the expression is malformed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_expr(s)
+    }
+}
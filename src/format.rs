@@ -0,0 +1,380 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::mem;
+
+use crate::solution::{c_identifier, python_identifier, verilog_identifier};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+thread_local! {
+    static DEFAULT_FORMAT: RefCell<ExpressionStyle> = RefCell::new(ExpressionStyle::default());
+}
+
+/// The symbols and spacing of an expression written by [`Cover::to_string_with`](crate::Cover::to_string_with).
+///
+/// The [`Display`](std::fmt::Display) implementation of [`Cover`](crate::Cover) uses the style set with
+/// [`set_default_format`], which is the [`Default`] one unless changed. There are presets for common languages,
+/// see [`Style`], which also make the names identifiers of the language like [`Cover::to_c_expr`](crate::Cover::to_c_expr)
+/// and the like do. Products (or sums) of more than one literal are parenthesized if there are more than one of them.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+///     .unwrap()
+///     .minimize(qmc::SOP, false, None)
+///     .unwrap()
+///     .remove(0);
+///
+/// let style = qmc::ExpressionStyle::default()
+///     .with_and("")
+///     .with_or("+")
+///     .with_negation(qmc::Negation::Postfix("'".to_owned()))
+///     .with_spaced(false);
+///
+/// assert_eq!(solution.to_string_with(&style), "(AB)+(A'C)");
+///
+/// let style = qmc::ExpressionStyle::default().with_negation(qmc::Negation::Overline);
+///
+/// assert_eq!(solution.to_string_with(style), "(A ∧ B) ∨ (A\u{305} ∧ C)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub struct ExpressionStyle {
+    /// The AND operator, which may be empty to write the literals of a product next to each other.
+    pub and: String,
+    /// The OR operator.
+    pub or: String,
+    /// How negated variables are written.
+    pub negation: Negation,
+    /// Whether to put a space on both sides of the operators.
+    pub spaced: bool,
+    /// The constant 1.
+    pub one: String,
+    /// The constant 0.
+    pub zero: String,
+    /// How names are turned into identifiers.
+    pub identifiers: Identifiers,
+}
+
+impl Default for ExpressionStyle {
+    /// Returns the style `(A ∧ B) ∨ (~A ∧ C)`.
+    fn default() -> Self {
+        ExpressionStyle::spaced("∧", "∨", "~", "1", "0", Identifiers::Verbatim)
+    }
+}
+
+impl ExpressionStyle {
+    /// Returns the style `(A & B) | (~A & C)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_string_with(qmc::ExpressionStyle::ascii()), "(A & B) | (~A & C)");
+    /// assert_eq!(solution.to_string_with(qmc::ExpressionStyle::c_style()), "(A && B) || (!A && C)");
+    /// assert_eq!(solution.to_string_with(qmc::ExpressionStyle::python()), "(A and B) or (not A and C)");
+    /// assert_eq!(solution.to_string_with(qmc::ExpressionStyle::verilog()), "(A & B) | (~A & C)");
+    /// ```
+    pub fn ascii() -> Self {
+        ExpressionStyle::spaced("&", "|", "~", "1", "0", Identifiers::Verbatim)
+    }
+
+    /// Returns the style of logical operators in C and similar languages, `(A && B) || (!A && C)`,
+    /// with the names made C identifiers.
+    pub fn c_style() -> Self {
+        ExpressionStyle::spaced("&&", "||", "!", "1", "0", Identifiers::C)
+    }
+
+    /// Returns the style of Python, `(A and B) or (not A and C)` with the constants `True` and `False`,
+    /// with the names made Python identifiers.
+    pub fn python() -> Self {
+        ExpressionStyle::spaced("and", "or", "not ", "True", "False", Identifiers::Python)
+    }
+
+    /// Returns the style of bitwise operators in Verilog, `(A & B) | (~A & C)` with the constants `1'b1` and `1'b0`,
+    /// with the names made Verilog identifiers.
+    pub fn verilog() -> Self {
+        ExpressionStyle::spaced("&", "|", "~", "1'b1", "1'b0", Identifiers::Verilog)
+    }
+
+    /// Returns the style with the given AND operator, which may be empty.
+    pub fn with_and(mut self, and: impl Into<String>) -> Self {
+        self.and = and.into();
+        self
+    }
+
+    /// Returns the style with the given OR operator.
+    pub fn with_or(mut self, or: impl Into<String>) -> Self {
+        self.or = or.into();
+        self
+    }
+
+    /// Returns the style with the given negation.
+    pub fn with_negation(mut self, negation: Negation) -> Self {
+        self.negation = negation;
+        self
+    }
+
+    /// Returns the style with `spaced` set to the given value.
+    pub fn with_spaced(mut self, spaced: bool) -> Self {
+        self.spaced = spaced;
+        self
+    }
+
+    /// Returns the style with the given constant 1.
+    pub fn with_one(mut self, one: impl Into<String>) -> Self {
+        self.one = one.into();
+        self
+    }
+
+    /// Returns the style with the given constant 0.
+    pub fn with_zero(mut self, zero: impl Into<String>) -> Self {
+        self.zero = zero.into();
+        self
+    }
+
+    /// Returns the style with the given way of turning names into identifiers.
+    pub fn with_identifiers(mut self, identifiers: Identifiers) -> Self {
+        self.identifiers = identifiers;
+        self
+    }
+
+    fn spaced(
+        and: &str,
+        or: &str,
+        not: &str,
+        one: &str,
+        zero: &str,
+        identifiers: Identifiers,
+    ) -> Self {
+        ExpressionStyle {
+            and: and.to_owned(),
+            or: or.to_owned(),
+            negation: Negation::Prefix(not.to_owned()),
+            spaced: true,
+            one: one.to_owned(),
+            zero: zero.to_owned(),
+            identifiers,
+        }
+    }
+
+    pub(crate) fn operator(&self, operator: &str) -> String {
+        if self.spaced {
+            format!(" {} ", operator)
+        } else {
+            operator.to_owned()
+        }
+    }
+}
+
+impl From<&ExpressionStyle> for ExpressionStyle {
+    fn from(style: &ExpressionStyle) -> Self {
+        style.clone()
+    }
+}
+
+/// A preset [`ExpressionStyle`] for a common language, which can be given to
+/// [`Cover::to_string_with`](crate::Cover::to_string_with) and [`set_default_format`] directly.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let solution = qmc::BooleanFunction::new(&["a b", "in", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+///     .unwrap()
+///     .minimize(qmc::SOP, false, None)
+///     .unwrap()
+///     .remove(0);
+///
+/// assert_eq!(solution.to_string_with(qmc::Style::Ascii), "(a b & in) | (~a b & C)");
+/// assert_eq!(solution.to_string_with(qmc::Style::CStyle), "(a_b && in) || (!a_b && C)");
+/// assert_eq!(solution.to_string_with(qmc::Style::Python), "(a_b and in_) or (not a_b and C)");
+/// assert_eq!(solution.to_string_with(qmc::Style::VerilogOps), "(\\a_b  & in) | (~\\a_b  & C)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Style {
+    /// [`ExpressionStyle::ascii`], `(A & B) | (~A & C)`.
+    Ascii,
+    /// [`ExpressionStyle::c_style`], `(A && B) || (!A && C)`.
+    CStyle,
+    /// [`ExpressionStyle::python`], `(A and B) or (not A and C)`.
+    Python,
+    /// [`ExpressionStyle::verilog`], `(A & B) | (~A & C)` with Verilog identifiers and constants.
+    VerilogOps,
+}
+
+impl From<Style> for ExpressionStyle {
+    fn from(style: Style) -> Self {
+        match style {
+            Style::Ascii => ExpressionStyle::ascii(),
+            Style::CStyle => ExpressionStyle::c_style(),
+            Style::Python => ExpressionStyle::python(),
+            Style::VerilogOps => ExpressionStyle::verilog(),
+        }
+    }
+}
+
+/// How an [`ExpressionStyle`] turns names into identifiers.
+///
+/// If a name would become the same identifier as an earlier one, it is suffixed with `_2`, `_3` and so on
+/// before being made an identifier, so different names always become different identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Identifiers {
+    /// Names are written as they are.
+    Verbatim,
+    /// Names are made C identifiers as in [`Cover::to_c_expr`](crate::Cover::to_c_expr).
+    C,
+    /// Names are made Python identifiers as in [`Cover::to_python_expr`](crate::Cover::to_python_expr).
+    Python,
+    /// Names that are not simple Verilog identifiers are escaped as in [`Cover::to_verilog`](crate::Cover::to_verilog).
+    Verilog,
+}
+
+impl Default for Identifiers {
+    fn default() -> Self {
+        Identifiers::Verbatim
+    }
+}
+
+impl Identifiers {
+    /// Returns the identifiers of the `names`, which are different if the names are.
+    pub(crate) fn identifiers<T: AsRef<str>>(&self, names: &[T]) -> Vec<String> {
+        let mut identifiers = Vec::with_capacity(names.len());
+        let mut taken = HashSet::new();
+
+        for name in names {
+            let name = name.as_ref();
+            let mut identifier = self.identifier(name);
+            let mut suffix = 2;
+
+            while !taken.insert(self.key(&identifier)) {
+                identifier = self.identifier(&format!("{}_{}", name, suffix));
+                suffix += 1;
+            }
+
+            identifiers.push(identifier);
+        }
+
+        identifiers
+    }
+
+    fn identifier(&self, name: &str) -> String {
+        match self {
+            Identifiers::Verbatim => name.to_owned(),
+            Identifiers::C => c_identifier(name),
+            Identifiers::Python => python_identifier(name),
+            Identifiers::Verilog => verilog_identifier(name),
+        }
+    }
+
+    /// Returns what makes `identifier` the same as another one.
+    fn key(&self, identifier: &str) -> String {
+        match (self, identifier.strip_prefix('\\')) {
+            // An escaped identifier is the same as the simple identifier with the same characters.
+            (Identifiers::Verilog, Some(escaped)) => escaped.trim_end().to_owned(),
+            _ => identifier.to_owned(),
+        }
+    }
+}
+
+/// How an [`ExpressionStyle`] writes a negated variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Negation {
+    /// The symbol is written before the variable, e.g. `~A` or `!A`.
+    Prefix(String),
+    /// The symbol is written after the variable, e.g. `A'`.
+    Postfix(String),
+    /// Each character of the variable is followed by a combining overline (U+0305), e.g. `A̅`.
+    Overline,
+}
+
+impl Negation {
+    pub(crate) fn negate(&self, identifier: String) -> String {
+        match self {
+            Negation::Prefix(symbol) => format!("{}{}", symbol, identifier),
+            Negation::Postfix(symbol) => format!("{}{}", identifier, symbol),
+            Negation::Overline => identifier
+                .chars()
+                .flat_map(|char| [char, '\u{305}'])
+                .collect(),
+        }
+    }
+}
+
+/// Sets the style used to display solutions on the current thread until the returned guard is dropped,
+/// which restores the previous one.
+///
+/// Other threads keep their own style, so formatting on one thread never changes the output of another.
+/// Guards should be dropped in the reverse order of their creation, as they are when they go out of scope.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 5], &[1, 3, 4, 6])
+///     .unwrap()
+///     .minimize(qmc::SOP, false, None)
+///     .unwrap()
+///     .remove(0);
+///
+/// {
+///     let _guard = qmc::set_default_format(qmc::ExpressionStyle::ascii());
+///
+///     assert_eq!(solution.to_string(), "(A & C) | (~A & ~C)");
+///     assert_eq!(solution.to_string_with(qmc::ExpressionStyle::default()), "(A ∧ C) ∨ (~A ∧ ~C)");
+/// }
+///
+/// assert_eq!(solution.to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+/// ```
+pub fn set_default_format(style: impl Into<ExpressionStyle>) -> DefaultFormatGuard {
+    DefaultFormatGuard {
+        previous: DEFAULT_FORMAT.with(|format| format.replace(style.into())),
+        _not_send: PhantomData,
+    }
+}
+
+/// Returns the style used to display solutions on the current thread, see [`set_default_format`].
+pub fn default_format() -> ExpressionStyle {
+    with_default_format(ExpressionStyle::clone)
+}
+
+pub(crate) fn with_default_format<T>(f: impl FnOnce(&ExpressionStyle) -> T) -> T {
+    DEFAULT_FORMAT.with(|format| f(&format.borrow()))
+}
+
+/// Restores the style used to display solutions when dropped, see [`set_default_format`].
+#[must_use = "the previous style is restored when the guard is dropped"]
+#[derive(Debug)]
+pub struct DefaultFormatGuard {
+    previous: ExpressionStyle,
+    // The style is restored on the thread it was set on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for DefaultFormatGuard {
+    fn drop(&mut self) {
+        let previous = mem::take(&mut self.previous);
+
+        DEFAULT_FORMAT.with(|format| format.replace(previous));
+    }
+}
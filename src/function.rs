@@ -0,0 +1,1012 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    affine::{self, AffineForm},
+    get_dont_cares, karnaugh, minimize_internal_with_timeout, own_variables,
+    threshold::{self, ThresholdForm},
+    validate_input, validate_variables, Conversion, Cover, Cube, Error, Form, Term, TruthTable,
+    VariableSet, SOP,
+};
+
+/// An incompletely specified boolean function.
+///
+/// `minterms` are the terms whose output is 1 and `maxterms` are the terms whose output is 0.
+/// The rest of the terms are don't care conditions.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // Y = (A ∧ B) ∨ (~A ∧ C)
+/// let function = qmc::BooleanFunction::new(
+///     &["A", "B", "C"],
+///     &[1, 3, 6, 7],
+///     &[0, 2, 4, 5],
+/// )
+/// .unwrap();
+///
+/// let mut solutions = function.exists(&["A"]).unwrap().minimize(qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "B ∨ C");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BooleanFunction {
+    variables: Vec<String>,
+    minterms: HashSet<u32>,
+    maxterms: HashSet<u32>,
+    dont_care_labels: Vec<(String, HashSet<u32>)>,
+}
+
+impl BooleanFunction {
+    /// Creates a boolean function from the given `minterms` and `maxterms`.
+    ///
+    /// Returns the same errors as [`minimize`](crate::minimize) for invalid input.
+    pub fn new<T: AsRef<str>>(
+        variables: &[T],
+        minterms: &[u32],
+        maxterms: &[u32],
+    ) -> Result<Self, Error> {
+        let variables = own_variables(variables);
+        let minterms = minterms.iter().copied().collect();
+        let maxterms = maxterms.iter().copied().collect();
+
+        validate_input(&variables, &minterms, &maxterms)?;
+
+        Ok(BooleanFunction {
+            variables,
+            minterms,
+            maxterms,
+            dont_care_labels: vec![],
+        })
+    }
+
+    /// Creates a boolean function from typed `minterms` and `maxterms` of the given `variables`.
+    ///
+    /// Returns [`Error::TermConflict`] if a term is both a minterm and a maxterm.
+    /// [`Error::TermOutOfBounds`] is only returned for terms created with a larger [`VariableSet`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let variables = qmc::VariableSet::new(&["A", "B"]).unwrap();
+    /// let term = |value| qmc::Term::new(&variables, value).unwrap();
+    ///
+    /// let function = qmc::BooleanFunction::from_terms(&variables, &[term(3)], &[term(0)]).unwrap();
+    ///
+    /// assert_eq!(function.evaluate(3), Some(true));
+    /// ```
+    pub fn from_terms(
+        variables: &VariableSet,
+        minterms: &[Term],
+        maxterms: &[Term],
+    ) -> Result<Self, Error> {
+        let minterms = Vec::from_iter(minterms.iter().map(Term::value));
+        let maxterms = Vec::from_iter(maxterms.iter().map(Term::value));
+
+        BooleanFunction::new(variables.variables(), &minterms, &maxterms)
+    }
+
+    /// Creates a boolean function from cubes of the minterms and cubes of the maxterms of the given `variables`.
+    ///
+    /// Returns [`Error::InvalidCube`] if a cube doesn't have a character for each variable
+    /// and [`Error::TermConflict`] if a minterm cube and a maxterm cube overlap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let variables = qmc::VariableSet::new(&["A", "B", "C"]).unwrap();
+    /// let cube = |cube| qmc::Cube::new(&variables, cube).unwrap();
+    ///
+    /// let function = qmc::BooleanFunction::from_cubes(&variables, &[cube("1--")], &[cube("00-")]).unwrap();
+    /// let mut solutions = function.minimize(qmc::SOP, false, None).unwrap();
+    ///
+    /// assert_eq!(solutions.pop().unwrap().to_string(), "A");
+    /// ```
+    pub fn from_cubes(
+        variables: &VariableSet,
+        minterms: &[Cube],
+        maxterms: &[Cube],
+    ) -> Result<Self, Error> {
+        let mut terms = [vec![], vec![]];
+
+        for (cubes, terms) in [minterms, maxterms].iter().zip(&mut terms) {
+            for cube in cubes.iter() {
+                if cube.variable_count() != variables.variable_count() {
+                    return Err(Error::InvalidCube(cube.to_string()));
+                }
+
+                terms.extend(cube.terms());
+            }
+        }
+
+        let [minterms, maxterms] = terms;
+
+        BooleanFunction::new(variables.variables(), &minterms, &maxterms)
+    }
+
+    /// Creates the function specified jointly by the given partial specifications of the same variables.
+    ///
+    /// The minterms and maxterms of the function are the union of the minterms and maxterms of the specifications,
+    /// so a term is a don't care only if it is a don't care in all of them. Don't care labels are not carried over.
+    ///
+    /// Returns [`Error::InvalidVariableCount`] if there are no specifications,
+    /// [`Error::MismatchedVariables`] if they don't have the same variables,
+    /// and [`Error::TermConflict`] if a term is a minterm in one specification and a maxterm in another.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let decoder = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0]).unwrap();
+    /// let control = qmc::BooleanFunction::new(&["A", "B"], &[2], &[0]).unwrap();
+    ///
+    /// let function = qmc::BooleanFunction::union(&[decoder, control]).unwrap();
+    /// let mut solutions = function.minimize(qmc::SOP, false, None).unwrap();
+    ///
+    /// assert_eq!(solutions.pop().unwrap().to_string(), "A");
+    /// ```
+    pub fn union(specifications: &[BooleanFunction]) -> Result<Self, Error> {
+        let variables = match specifications.first() {
+            Some(specification) => &specification.variables,
+            None => return Err(Error::InvalidVariableCount(0)),
+        };
+
+        let mut minterms = HashSet::new();
+        let mut maxterms = HashSet::new();
+
+        for specification in specifications {
+            if &specification.variables != variables {
+                return Err(Error::MismatchedVariables {
+                    expected: variables.clone(),
+                    found: specification.variables.clone(),
+                });
+            }
+
+            minterms.extend(&specification.minterms);
+            maxterms.extend(&specification.maxterms);
+        }
+
+        validate_input(variables, &minterms, &maxterms)?;
+
+        Ok(BooleanFunction {
+            variables: variables.clone(),
+            minterms,
+            maxterms,
+            dont_care_labels: vec![],
+        })
+    }
+
+    /// Returns the variables of the function.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the terms whose output is 1.
+    pub fn minterms(&self) -> &HashSet<u32> {
+        &self.minterms
+    }
+
+    /// Returns the terms whose output is 0.
+    pub fn maxterms(&self) -> &HashSet<u32> {
+        &self.maxterms
+    }
+
+    /// Returns the terms whose output is unspecified.
+    pub fn dont_cares(&self) -> HashSet<u32> {
+        get_dont_cares(self.variable_count(), &self.minterms, &self.maxterms)
+    }
+
+    /// Labels the given don't care `terms` with `label`, e.g. `"invalid opcode"`, so that
+    /// [`dont_care_usage`](Self::dont_care_usage) can report whether a solution relies on them.
+    ///
+    /// Labels are not carried over to the functions returned by [`exists`](Self::exists) and [`forall`](Self::forall).
+    ///
+    /// Returns [`Error::TermOutOfBounds`] or [`Error::TermConflict`] if any of the `terms` is not a don't care.
+    pub fn label_dont_cares(&mut self, label: &str, terms: &[u32]) -> Result<(), Error> {
+        let terms: HashSet<u32> = terms.iter().copied().collect();
+        let cares = self.minterms.union(&self.maxterms).copied().collect();
+
+        validate_input(&self.variables, &terms, &cares)?;
+
+        self.dont_care_labels.push((label.to_owned(), terms));
+
+        Ok(())
+    }
+
+    /// Returns the function with the terms in which not exactly one of the `group` of variables is 1
+    /// turned into don't cares, for inputs that are known to be one-hot.
+    ///
+    /// The terms are don't cares even if the function specifies them, and they are labeled
+    /// `"one-hot"` followed by the variables of the group for [`dont_care_usage`](Self::dont_care_usage).
+    /// Use [`at_most_one_group`](Self::at_most_one_group) if all variables of the group can be 0.
+    ///
+    /// Returns [`Error::UnknownVariable`] if a variable is not part of the function,
+    /// and the same errors as [`minimize`](crate::minimize) for an empty group or duplicate variables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// // Selects A when S0 is 1 and B when S1 is 1, and outputs 0 when both are 0.
+    /// let function = qmc::BooleanFunction::new(
+    ///     &["S0", "S1", "A", "B"],
+    ///     &[5, 7, 10, 11],
+    ///     &[0, 1, 2, 3, 4, 6, 8, 9],
+    /// )
+    /// .unwrap();
+    /// let function = function.one_hot_group(&["S0", "S1"]).unwrap();
+    ///
+    /// assert_eq!(function.dont_cares().len(), 8);
+    ///
+    /// let mut solutions = function.minimize(qmc::SOP, false, None).unwrap();
+    ///
+    /// // ~S0 is the same as S1 when exactly one of them is 1.
+    /// assert_eq!(solutions.pop().unwrap().to_string(), "(~S0 ∧ B) ∨ (~S1 ∧ A)");
+    /// ```
+    pub fn one_hot_group<T: AsRef<str>>(&self, group: &[T]) -> Result<Self, Error> {
+        self.with_group_constraint(group, "one-hot", |one_count| one_count == 1)
+    }
+
+    /// Returns the function with the terms in which more than one of the `group` of variables is 1
+    /// turned into don't cares, for mutually exclusive inputs.
+    ///
+    /// The terms are labeled `"at most one of"` followed by the variables of the group,
+    /// otherwise this is the same as [`one_hot_group`](Self::one_hot_group).
+    pub fn at_most_one_group<T: AsRef<str>>(&self, group: &[T]) -> Result<Self, Error> {
+        self.with_group_constraint(group, "at most one of", |one_count| one_count <= 1)
+    }
+
+    /// Returns the don't cares that the given `solution` of the function relies on in ascending order.
+    ///
+    /// These are the don't cares covered by the implicants of the solution, i.e. the ones
+    /// it outputs 1 for in [`SOP`] form or 0 for in [`POS`](crate::POS) form.
+    pub fn exploited_dont_cares(&self, solution: &Cover) -> Vec<u32> {
+        let mut dont_cares = Vec::from_iter(
+            self.dont_cares()
+                .into_iter()
+                .filter(|&term| solution.evaluate_term(term) == (solution.form() == SOP)),
+        );
+        dont_cares.sort_unstable();
+        dont_cares
+    }
+
+    /// Returns the labeled don't cares that the given `solution` of the function relies on,
+    /// in the order they were labeled. Labels whose don't cares are not relied on are omitted.
+    ///
+    /// See [`exploited_dont_cares`](Self::exploited_dont_cares) for which don't cares are relied on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let mut function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0]).unwrap();
+    /// function.label_dont_cares("unreachable state", &[1]).unwrap();
+    /// function.label_dont_cares("invalid opcode", &[2]).unwrap();
+    ///
+    /// let solutions = function.minimize(qmc::SOP, true, None).unwrap();
+    ///
+    /// for solution in &solutions {
+    ///     let usage = function.dont_care_usage(solution);
+    ///
+    ///     match solution.to_string().as_str() {
+    ///         "A" => assert_eq!(usage[0].label, "invalid opcode"),
+    ///         "B" => assert_eq!(usage[0].label, "unreachable state"),
+    ///         _ => unreachable!(),
+    ///     }
+    /// }
+    /// ```
+    pub fn dont_care_usage(&self, solution: &Cover) -> Vec<DontCareUsage> {
+        let exploited_dont_cares = self.exploited_dont_cares(solution);
+
+        self.dont_care_labels
+            .iter()
+            .filter_map(|(label, terms)| {
+                let terms = Vec::from_iter(
+                    exploited_dont_cares
+                        .iter()
+                        .copied()
+                        .filter(|term| terms.contains(term)),
+                );
+
+                (!terms.is_empty()).then(|| DontCareUsage {
+                    label: label.clone(),
+                    terms,
+                })
+            })
+            .collect()
+    }
+
+    /// Attaches a [`Certificate`] of the don't care assignments the given `solution` of the function depends on
+    /// to the solution, so that it is serialized along with it.
+    ///
+    /// The certificate records the [`digest`](Self::digest) of the function, so that [`complete`](Self::complete)
+    /// rejects it for any other function.
+    ///
+    /// Returns [`Error::MismatchedVariables`] if the solution doesn't have the variables of the function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1]).unwrap();
+    ///
+    /// let mut solution = function.minimize(qmc::SOP, false, None).unwrap().pop().unwrap();
+    /// function.certify(&mut solution).unwrap();
+    ///
+    /// let certificate = solution.certificate().unwrap();
+    /// assert_eq!(certificate.ones, [2]);
+    ///
+    /// let completed_function = function.complete(certificate).unwrap();
+    /// assert_eq!(completed_function.evaluate(2), Some(true));
+    /// ```
+    pub fn certify(&self, solution: &mut Cover) -> Result<(), Error> {
+        if solution.variables() != self.variables {
+            return Err(Error::MismatchedVariables {
+                expected: self.variables.clone(),
+                found: solution.variables().to_vec(),
+            });
+        }
+
+        let (mut ones, mut zeros): (Vec<u32>, Vec<u32>) = self
+            .dont_cares()
+            .into_iter()
+            .partition(|&term| solution.evaluate_term(term));
+
+        ones.sort_unstable();
+        zeros.sort_unstable();
+
+        solution.set_certificate(Certificate {
+            ones,
+            zeros,
+            function_digest: self.digest(),
+        });
+
+        Ok(())
+    }
+
+    /// Returns a digest of the variables, minterms and maxterms of the function.
+    ///
+    /// Functions with the same variables in the same order and the same minterms and maxterms have the same digest,
+    /// which is the 64-bit FNV-1a hash of the names of the variables each followed by a zero byte, the minterms
+    /// in ascending order, a byte of `0xFF` and the maxterms in ascending order, with the terms as 4 bytes in
+    /// little-endian order. It is the same on all platforms and in all releases.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1]).unwrap();
+    ///
+    /// assert_eq!(function.digest(), qmc::BooleanFunction::new(&["A", "B"], &[3], &[1, 0]).unwrap().digest());
+    /// assert_ne!(function.digest(), qmc::BooleanFunction::new(&["A", "B"], &[3], &[0]).unwrap().digest());
+    /// ```
+    pub fn digest(&self) -> u64 {
+        let mut digest = Fnv1a::new();
+
+        for variable in &self.variables {
+            digest.write(variable.as_bytes());
+            digest.write(&[0]);
+        }
+
+        let sorted = |terms: &HashSet<u32>| {
+            let mut terms = Vec::from_iter(terms.iter().copied());
+            terms.sort_unstable();
+            terms
+        };
+
+        for term in sorted(&self.minterms) {
+            digest.write(&term.to_le_bytes());
+        }
+
+        digest.write(&[0xFF]);
+
+        for term in sorted(&self.maxterms) {
+            digest.write(&term.to_le_bytes());
+        }
+
+        digest.finish()
+    }
+
+    /// Returns the fully specified function obtained by assigning the don't cares as given by the `certificate`.
+    ///
+    /// Returns [`Error::CertificateMismatch`] if the certificate was created for another function,
+    /// [`Error::TermOutOfBounds`] or [`Error::TermConflict`] if the certificate assigns a term
+    /// that is not a don't care and [`Error::IncompleteCertificate`] if it doesn't assign all don't cares.
+    pub fn complete(&self, certificate: &Certificate) -> Result<Self, Error> {
+        if certificate.function_digest != self.digest() {
+            return Err(Error::CertificateMismatch);
+        }
+
+        let ones = certificate.ones.iter().copied().collect();
+        let zeros = certificate.zeros.iter().copied().collect();
+
+        validate_input(&self.variables, &ones, &zeros)?;
+
+        let minterms: HashSet<u32> = self.minterms.union(&ones).copied().collect();
+        let maxterms: HashSet<u32> = self.maxterms.union(&zeros).copied().collect();
+
+        validate_input(&self.variables, &minterms, &maxterms)?;
+
+        if minterms.len() + maxterms.len() != 1 << self.variables.len() {
+            return Err(Error::IncompleteCertificate);
+        }
+
+        Ok(BooleanFunction {
+            variables: self.variables.clone(),
+            minterms,
+            maxterms,
+            dont_care_labels: vec![],
+        })
+    }
+
+    /// Returns the fully specified function that the given `solution` of the function implements,
+    /// along with how the solution resolved each don't care.
+    ///
+    /// Unlike [`exploited_dont_cares`](Self::exploited_dont_cares), this reports the don't cares forced to 1
+    /// and the ones forced to 0 regardless of the form of the solution.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1]).unwrap();
+    ///
+    /// let solution = function.minimize(qmc::POS, false, None).unwrap().pop().unwrap();
+    /// let completion = function.completion(&solution);
+    ///
+    /// assert_eq!(completion.forced_ones(), [2]);
+    /// assert!(completion.forced_zeros().is_empty());
+    /// assert_eq!(
+    ///     completion.truth_table().outputs(),
+    ///     [Some(false), Some(false), Some(true), Some(true)]
+    /// );
+    /// ```
+    pub fn completion(&self, solution: &Cover) -> CompletedFunction {
+        let (mut forced_ones, mut forced_zeros): (Vec<u32>, Vec<u32>) = self
+            .dont_cares()
+            .into_iter()
+            .partition(|&term| solution.evaluate_term(term));
+
+        forced_ones.sort_unstable();
+        forced_zeros.sort_unstable();
+
+        let function = BooleanFunction {
+            variables: self.variables.clone(),
+            minterms: self.minterms.iter().chain(&forced_ones).copied().collect(),
+            maxterms: self.maxterms.iter().chain(&forced_zeros).copied().collect(),
+            dont_care_labels: vec![],
+        };
+
+        CompletedFunction {
+            function,
+            forced_ones,
+            forced_zeros,
+        }
+    }
+
+    /// Returns the output of the given `term`, or `None` if it is a don't care.
+    pub fn evaluate(&self, term: u32) -> Option<bool> {
+        if self.minterms.contains(&term) {
+            Some(true)
+        } else if self.maxterms.contains(&term) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Karnaugh map of the function as an SVG image, with the products (or sums) of the given `solution`
+    /// highlighted as groups of cells.
+    ///
+    /// The first half of the variables (rounded down) select the row and the rest select the column,
+    /// both in Gray code order. The cells show `1`, `0` or `X` for a don't care. Groups that wrap around an edge
+    /// of the map are drawn in several pieces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has more than 6 variables or the variables of the solution and the function
+    /// are not the same.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5]).unwrap();
+    /// let solution = function.minimize(qmc::SOP, false, None).unwrap().pop().unwrap();
+    ///
+    /// let svg = function.to_karnaugh_svg(Some(&solution));
+    ///
+    /// assert!(svg.starts_with("<svg"));
+    /// assert_eq!(svg.matches("rx=\"8\"").count(), 2);
+    /// ```
+    pub fn to_karnaugh_svg(&self, solution: Option<&Cover>) -> String {
+        karnaugh::write_svg(self, solution)
+    }
+
+    /// Returns the Karnaugh map of the function as plain text, laid out like
+    /// [`to_karnaugh_svg`](Self::to_karnaugh_svg).
+    ///
+    /// If a `solution` is given, its products (or sums) are lettered `a`, `b`, ... in a legend below the map
+    /// and each cell is followed by the letters of the ones that cover it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has more than 6 variables or the variables of the solution and the function
+    /// are not the same.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6], &[0, 2, 4, 5]).unwrap();
+    /// let solution = function.minimize(qmc::SOP, false, None).unwrap().pop().unwrap();
+    ///
+    /// assert_eq!(
+    ///     function.to_karnaugh_string(None),
+    ///     "\
+    /// A \\ B C | 00 01 11 10
+    /// --------+------------
+    ///       0 | 0  1  1  0
+    ///       1 | 0  0  X  1
+    /// "
+    /// );
+    /// assert_eq!(
+    ///     function.to_karnaugh_string(Some(&solution)),
+    ///     "\
+    /// A \\ B C | 00 01 11 10
+    /// --------+------------
+    ///       0 | 0  1b 1b 0
+    ///       1 | 0  0  Xa 1a
+    ///
+    /// a: A ∧ B
+    /// b: ~A ∧ C
+    /// "
+    /// );
+    /// ```
+    pub fn to_karnaugh_string(&self, solution: Option<&Cover>) -> String {
+        karnaugh::write_text(self, solution)
+    }
+
+    /// Minimizes the function, returning [`Cover`]s.
+    ///
+    /// See [`minimize`](crate::minimize) for the meaning of the arguments.
+    pub fn minimize(
+        &self,
+        form: Form,
+        find_all_solutions: bool,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Cover>, Error> {
+        let dont_cares = self.dont_cares();
+        let conversion = Conversion::new(&self.variables, form, &dont_cares, find_all_solutions);
+        let terms = if form == SOP {
+            self.minterms.clone()
+        } else {
+            self.maxterms.clone()
+        };
+
+        let internal_solutions = minimize_internal_with_timeout(
+            self.variable_count(),
+            terms,
+            dont_cares,
+            form,
+            find_all_solutions,
+            timeout,
+        )?;
+
+        Ok(conversion.to_covers(&internal_solutions))
+    }
+
+    /// Existentially quantifies the given `variables`, removing them from the function.
+    ///
+    /// The output of a term is 1 if it is 1 for any value of the quantified variables
+    /// and 0 if it is 0 for all of them. Otherwise it is a don't care.
+    ///
+    /// Returns [`Error::UnknownVariable`] if a variable is not part of the function and
+    /// [`Error::InvalidVariableCount`] if no variables would remain.
+    pub fn exists<T: AsRef<str>>(&self, variables: &[T]) -> Result<Self, Error> {
+        self.quantify(variables, |output0, output1| match (output0, output1) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        })
+    }
+
+    /// Universally quantifies the given `variables`, removing them from the function.
+    ///
+    /// The output of a term is 1 if it is 1 for all values of the quantified variables
+    /// and 0 if it is 0 for any of them. Otherwise it is a don't care.
+    ///
+    /// Returns the same errors as [`exists`](Self::exists).
+    pub fn forall<T: AsRef<str>>(&self, variables: &[T]) -> Result<Self, Error> {
+        self.quantify(variables, |output0, output1| match (output0, output1) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        })
+    }
+
+    /// Returns the boolean difference (derivative) of the function with respect to `variable`,
+    /// removing the variable from the function.
+    ///
+    /// The output of a term is 1 if flipping the variable changes the output of the function
+    /// and 0 if it doesn't. If either output is a don't care, so is the derivative.
+    ///
+    /// Returns the same errors as [`exists`](Self::exists).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// // Y = (A ∧ B) ∨ (~A ∧ C)
+    /// let function = qmc::BooleanFunction::new(
+    ///     &["A", "B", "C"],
+    ///     &[1, 3, 6, 7],
+    ///     &[0, 2, 4, 5],
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut solutions = function.derivative("A").unwrap().minimize(qmc::SOP, false, None).unwrap();
+    ///
+    /// assert_eq!(solutions.pop().unwrap().to_string(), "(B ∧ ~C) ∨ (~B ∧ C)");
+    /// ```
+    pub fn derivative(&self, variable: &str) -> Result<Self, Error> {
+        self.eliminate(variable, |output0, output1| match (output0, output1) {
+            (Some(output0), Some(output1)) => Some(output0 != output1),
+            _ => None,
+        })
+    }
+
+    /// Returns the affine form of the function if its don't cares can be assigned so that it is
+    /// the XOR of some of its variables, optionally complemented.
+    ///
+    /// Such functions are the worst case for [`SOP`] and [`POS`](crate::POS) forms: the XOR of `k` variables
+    /// needs `2^(k - 1)` products (or sums) of `k` literals each, but only `k - 1` XOR gates.
+    /// If there are multiple affine forms, variables whose coefficient is not determined by the care terms are left out.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 2, 4, 7], &[0, 3, 5]).unwrap();
+    ///
+    /// assert_eq!(function.affine_form().unwrap().to_string(), "A ⊕ B ⊕ C");
+    /// assert_eq!(function.minimize(qmc::SOP, false, None).unwrap()[0].terms().len(), 4);
+    /// ```
+    pub fn affine_form(&self) -> Option<AffineForm> {
+        let care_terms = self
+            .minterms
+            .iter()
+            .map(|&term| (term, true))
+            .chain(self.maxterms.iter().map(|&term| (term, false)));
+
+        let (constant, coefficients) = affine::fit(self.variable_count(), care_terms)?;
+
+        let variables = self
+            .variables
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| coefficients >> (self.variables.len() - index - 1) & 1 == 1)
+            .map(|(_, variable)| variable.clone())
+            .collect();
+
+        Some(AffineForm {
+            constant,
+            variables,
+        })
+    }
+
+    /// Returns the threshold form of the function if its don't cares can be assigned so that
+    /// the output is 1 exactly when the weighted sum of the variables that are 1 reaches a threshold.
+    ///
+    /// Threshold functions like majority can be implemented with a single threshold gate or an adder and a comparator,
+    /// while their [`SOP`] and [`POS`](crate::POS) forms grow quickly. The weights are found heuristically,
+    /// so they are not necessarily the smallest ones, and `None` may be returned for threshold functions
+    /// that need large weights. A function that is not unate is never a threshold function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// // The majority of A, B and C
+    /// let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[3, 5, 6, 7], &[0, 1, 2, 4]).unwrap();
+    ///
+    /// assert_eq!(function.threshold_form().unwrap().to_string(), "A + B + C ≥ 2");
+    /// ```
+    pub fn threshold_form(&self) -> Option<ThresholdForm> {
+        let mut care_terms = Vec::from_iter(
+            self.minterms
+                .iter()
+                .map(|&term| (term, true))
+                .chain(self.maxterms.iter().map(|&term| (term, false))),
+        );
+        care_terms.sort_unstable();
+
+        let (weights, threshold) = threshold::fit(self.variable_count(), &care_terms)?;
+
+        Some(ThresholdForm {
+            weights: self.variables.iter().cloned().zip(weights).collect(),
+            threshold,
+        })
+    }
+
+    /// Returns the function with the given in-bounds `terms` turned into don't cares.
+    pub(crate) fn with_dont_cares(&self, terms: &HashSet<u32>) -> Self {
+        BooleanFunction {
+            variables: self.variables.clone(),
+            minterms: self.minterms.difference(terms).copied().collect(),
+            maxterms: self.maxterms.difference(terms).copied().collect(),
+            dont_care_labels: self.dont_care_labels.clone(),
+        }
+    }
+
+    /// Returns the function with the terms whose number of ones in the `group` of variables is not allowed
+    /// turned into don't cares labeled `label_prefix` followed by the variables of the group.
+    fn with_group_constraint<T: AsRef<str>>(
+        &self,
+        group: &[T],
+        label_prefix: &str,
+        is_allowed: impl Fn(u32) -> bool,
+    ) -> Result<Self, Error> {
+        let group = own_variables(group);
+
+        validate_variables(&group)?;
+
+        let mut group_mask = 0;
+
+        for variable in &group {
+            group_mask |= 1 << (self.variables.len() - self.variable_position(variable)? - 1);
+        }
+
+        let terms: HashSet<u32> = (0..1 << self.variables.len())
+            .filter(|&term: &u32| !is_allowed((term & group_mask).count_ones()))
+            .collect();
+
+        let mut function = self.with_dont_cares(&terms);
+        function
+            .dont_care_labels
+            .push((format!("{} {}", label_prefix, group.join(" ")), terms));
+
+        Ok(function)
+    }
+
+    pub(crate) fn variable_count(&self) -> u32 {
+        self.variables.len() as u32
+    }
+
+    pub(crate) fn variable_position(&self, variable: &str) -> Result<usize, Error> {
+        self.variables
+            .iter()
+            .position(|name| name == variable)
+            .ok_or_else(|| Error::UnknownVariable(variable.to_owned()))
+    }
+
+    /// Removes `variable` by combining the outputs of its two cofactors for each remaining term.
+    pub(crate) fn eliminate(
+        &self,
+        variable: &str,
+        combine: impl Fn(Option<bool>, Option<bool>) -> Option<bool>,
+    ) -> Result<Self, Error> {
+        let index = self.variable_position(variable)?;
+
+        if self.variables.len() == 1 {
+            return Err(Error::InvalidVariableCount(0));
+        }
+
+        let mut variables = self.variables.clone();
+        variables.remove(index);
+
+        let bit = self.variables.len() - index - 1;
+        let low_mask = (1 << bit) - 1;
+        let mut minterms = HashSet::new();
+        let mut maxterms = HashSet::new();
+
+        for term in 0..1 << variables.len() {
+            let term0 = (term & !low_mask) << 1 | (term & low_mask);
+            let term1 = term0 | 1 << bit;
+
+            match combine(self.evaluate(term0), self.evaluate(term1)) {
+                Some(true) => minterms.insert(term),
+                Some(false) => maxterms.insert(term),
+                None => false,
+            };
+        }
+
+        Ok(BooleanFunction {
+            variables,
+            minterms,
+            maxterms,
+            dont_care_labels: vec![],
+        })
+    }
+
+    fn quantify<T: AsRef<str>>(
+        &self,
+        variables: &[T],
+        combine: impl Fn(Option<bool>, Option<bool>) -> Option<bool> + Copy,
+    ) -> Result<Self, Error> {
+        let mut function = self.clone();
+
+        for variable in variables {
+            function = function.eliminate(variable.as_ref(), combine)?;
+        }
+
+        Ok(function)
+    }
+}
+
+/// Labeled don't cares that a [`Cover`] relies on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DontCareUsage {
+    pub label: String,
+    /// The don't cares with the label that the solution relies on in ascending order.
+    pub terms: Vec<u32>,
+}
+
+/// The don't care assignments a [`Cover`] depends on, created by [`BooleanFunction::certify`].
+///
+/// Downstream equivalence checks can be performed against the fully specified function
+/// returned by [`BooleanFunction::complete`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Certificate {
+    /// The don't cares the solution outputs 1 for in ascending order.
+    pub ones: Vec<u32>,
+    /// The don't cares the solution outputs 0 for in ascending order.
+    pub zeros: Vec<u32>,
+    /// The [`digest`](BooleanFunction::digest) of the function the solution was certified against.
+    pub function_digest: u64,
+}
+
+/// The 64-bit FNV-1a hash, which unlike the hashers of the standard library is specified.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The fully specified function that a [`Cover`] implements, created by [`BooleanFunction::completion`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CompletedFunction {
+    function: BooleanFunction,
+    forced_ones: Vec<u32>,
+    forced_zeros: Vec<u32>,
+}
+
+impl CompletedFunction {
+    /// Returns the function, which has no don't cares.
+    pub fn function(&self) -> &BooleanFunction {
+        &self.function
+    }
+
+    /// Returns the don't cares of the original function that the solution outputs 1 for in ascending order.
+    pub fn forced_ones(&self) -> &[u32] {
+        &self.forced_ones
+    }
+
+    /// Returns the don't cares of the original function that the solution outputs 0 for in ascending order.
+    pub fn forced_zeros(&self) -> &[u32] {
+        &self.forced_zeros
+    }
+
+    /// Returns the truth table of the function.
+    pub fn truth_table(&self) -> TruthTable {
+        let outputs = Vec::from_iter(
+            (0..1 << self.function.variables.len()).map(|term| self.function.evaluate(term)),
+        );
+
+        TruthTable::new(&self.function.variables, &outputs).unwrap()
+    }
+}
+
+/// The comparison of two specifications of a function, created by [`diff_functions`].
+///
+/// The terms are in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FunctionDiff {
+    /// The variables of both specifications.
+    pub variables: Vec<String>,
+    /// The terms specified with different outputs.
+    pub differ: Vec<u32>,
+    /// The terms specified with the same output.
+    pub agree: Vec<u32>,
+    /// The terms that are a don't care in at least one of the specifications,
+    /// so whether they agree depends on how the don't cares are assigned.
+    pub may_agree: Vec<u32>,
+}
+
+impl FunctionDiff {
+    /// Returns the function whose minterms are the terms that differ and maxterms are the terms that agree.
+    /// Minimizing it gives an expression of where the specifications differ.
+    pub fn difference(&self) -> BooleanFunction {
+        BooleanFunction {
+            variables: self.variables.clone(),
+            minterms: self.differ.iter().copied().collect(),
+            maxterms: self.agree.iter().copied().collect(),
+            dont_care_labels: vec![],
+        }
+    }
+}
+
+/// Compares the outputs of two specifications of a function with the same variables term by term.
+///
+/// Returns [`Error::MismatchedVariables`] if the specifications don't have the same variables.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let old = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1, 2]).unwrap();
+/// let new = qmc::BooleanFunction::new(&["A", "B"], &[2, 3], &[0]).unwrap();
+///
+/// let diff = qmc::diff_functions(&old, &new).unwrap();
+///
+/// assert_eq!(diff.differ, [2]);
+/// assert_eq!(diff.agree, [0, 3]);
+/// assert_eq!(diff.may_agree, [1]);
+///
+/// let mut solutions = diff.difference().minimize(qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "A ∧ ~B");
+/// ```
+pub fn diff_functions(a: &BooleanFunction, b: &BooleanFunction) -> Result<FunctionDiff, Error> {
+    if a.variables != b.variables {
+        return Err(Error::MismatchedVariables {
+            expected: a.variables.clone(),
+            found: b.variables.clone(),
+        });
+    }
+
+    let mut diff = FunctionDiff {
+        variables: a.variables.clone(),
+        differ: vec![],
+        agree: vec![],
+        may_agree: vec![],
+    };
+
+    for term in 0..1 << a.variables.len() {
+        match (a.evaluate(term), b.evaluate(term)) {
+            (Some(a_output), Some(b_output)) if a_output != b_output => diff.differ.push(term),
+            (Some(_), Some(_)) => diff.agree.push(term),
+            _ => diff.may_agree.push(term),
+        }
+    }
+
+    Ok(diff)
+}
@@ -0,0 +1,296 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    own_variables, validate_input, validate_variables, BooleanFunction, Cover, Error, Form,
+};
+
+/// Don't cares shared by all outputs of a multi-output function, such as the unreachable states of a state machine
+/// when minimizing its next-state logic.
+///
+/// The set is computed once and applied to the function of each output with [`apply`](Self::apply),
+/// or to all of them at once with [`minimize`](Self::minimize). Its terms are don't cares even if an output
+/// specifies them, and [`overridden_care_terms`](Self::overridden_care_terms) reports where that happens.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // A 2-bit state where state 3 is unreachable, and outputs specified for all states.
+/// let unreachable = qmc::GlobalDontCareSet::new(&["Q1", "Q0"], &[3]).unwrap();
+/// let outputs = [
+///     qmc::BooleanFunction::new(&["Q1", "Q0"], &[2, 3], &[0, 1]).unwrap(),
+///     qmc::BooleanFunction::new(&["Q1", "Q0"], &[1], &[0, 2, 3]).unwrap(),
+/// ];
+///
+/// assert_eq!(unreachable.overridden_care_terms(&outputs[1]).unwrap(), [3]);
+///
+/// let solutions = unreachable.minimize(&outputs, qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions[0][0].to_string(), "Q1");
+/// assert_eq!(solutions[1][0].to_string(), "Q0");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GlobalDontCareSet {
+    variables: Vec<String>,
+    terms: HashSet<u32>,
+}
+
+impl GlobalDontCareSet {
+    /// Creates a set of the given don't care `terms` of the `variables`.
+    ///
+    /// Returns the same errors as [`minimize`](crate::minimize) for invalid input.
+    pub fn new<T: AsRef<str>>(variables: &[T], terms: &[u32]) -> Result<Self, Error> {
+        let variables = own_variables(variables);
+        let terms = terms.iter().copied().collect();
+
+        validate_input(&variables, &terms, &HashSet::new())?;
+
+        Ok(GlobalDontCareSet { variables, terms })
+    }
+
+    /// Creates a set of the terms of the `variables` for which `is_dont_care` returns `true`.
+    ///
+    /// `is_dont_care` is called once for each of the 2^n terms in ascending order.
+    pub fn from_fn<T: AsRef<str>>(
+        variables: &[T],
+        is_dont_care: impl Fn(u32) -> bool,
+    ) -> Result<Self, Error> {
+        let variables = own_variables(variables);
+
+        validate_variables(&variables)?;
+
+        let terms = (0..1 << variables.len())
+            .filter(|&term| is_dont_care(term))
+            .collect();
+
+        Ok(GlobalDontCareSet { variables, terms })
+    }
+
+    /// Creates a set of the states of a state machine that are unreachable from the `initial_states`,
+    /// given its transition table as pairs of a state and one of its next states.
+    ///
+    /// The states are terms of the `state_variables`. Returns the same errors as [`minimize`](crate::minimize)
+    /// for invalid variables and states out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// // A counter 0 -> 1 -> 2 -> 0 where state 3 is never entered.
+    /// let unreachable =
+    ///     qmc::GlobalDontCareSet::unreachable_states(&["Q1", "Q0"], &[0], &[(0, 1), (1, 2), (2, 0), (3, 0)])
+    ///         .unwrap();
+    ///
+    /// assert_eq!(unreachable.terms().len(), 1);
+    /// assert!(unreachable.terms().contains(&3));
+    /// ```
+    pub fn unreachable_states<T: AsRef<str>>(
+        state_variables: &[T],
+        initial_states: &[u32],
+        transitions: &[(u32, u32)],
+    ) -> Result<Self, Error> {
+        let variables = own_variables(state_variables);
+        let states = initial_states
+            .iter()
+            .copied()
+            .chain(transitions.iter().flat_map(|&(from, to)| [from, to]))
+            .collect();
+
+        validate_input(&variables, &states, &HashSet::new())?;
+
+        let mut next_states = vec![vec![]; 1 << variables.len()];
+
+        for &(from, to) in transitions {
+            next_states[from as usize].push(to);
+        }
+
+        Ok(Self::unreachable(variables, initial_states, &next_states))
+    }
+
+    /// Creates a set of the states of a state machine that are unreachable from the `initial_states`,
+    /// given its next-state equations.
+    ///
+    /// The states are terms of the `state_variables`, and `next_state` has the equation of each state variable
+    /// in the same order, a [`Cover`] of the `state_variables` followed by the `input_variables`.
+    /// A state is reachable if some sequence of inputs leads to it from an initial state.
+    ///
+    /// Returns [`Error::InvalidNextStateCount`] if there is not exactly one equation for each state variable,
+    /// [`Error::MismatchedVariables`] if an equation doesn't have the expected variables,
+    /// and the same errors as [`minimize`](crate::minimize) for invalid variables and states out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// // A shift register that only shifts the input X in after a 0, so that Q1 and Q0 are never both 1.
+    /// let variables = ["Q1", "Q0", "X"];
+    /// let next_q1 = qmc::simplify_expression(&variables, "Q0", qmc::SOP, false, None).unwrap();
+    /// let next_q0 = qmc::simplify_expression(&variables, "X & ~Q0", qmc::SOP, false, None).unwrap();
+    ///
+    /// let unreachable = qmc::GlobalDontCareSet::unreachable_states_from_equations(
+    ///     &["Q1", "Q0"],
+    ///     &["X"],
+    ///     &[0],
+    ///     &[next_q1[0].clone(), next_q0[0].clone()],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(unreachable.terms().len(), 1);
+    /// assert!(unreachable.terms().contains(&3));
+    /// ```
+    pub fn unreachable_states_from_equations<T: AsRef<str>, U: AsRef<str>>(
+        state_variables: &[T],
+        input_variables: &[U],
+        initial_states: &[u32],
+        next_state: &[Cover],
+    ) -> Result<Self, Error> {
+        let variables = own_variables(state_variables);
+        let all_variables = Vec::from_iter(
+            variables.iter().cloned().chain(
+                input_variables
+                    .iter()
+                    .map(|variable| variable.as_ref().to_owned()),
+            ),
+        );
+
+        validate_variables(&all_variables)?;
+        validate_input(
+            &variables,
+            &initial_states.iter().copied().collect(),
+            &HashSet::new(),
+        )?;
+
+        if next_state.len() != variables.len() {
+            return Err(Error::InvalidNextStateCount {
+                expected: variables.len(),
+                found: next_state.len(),
+            });
+        }
+
+        if let Some(equation) = next_state
+            .iter()
+            .find(|equation| equation.variables() != all_variables)
+        {
+            return Err(Error::MismatchedVariables {
+                expected: all_variables,
+                found: equation.variables().to_vec(),
+            });
+        }
+
+        let input_count = input_variables.len();
+        let next_states = Vec::from_iter((0..1u32 << variables.len()).map(|state| {
+            let mut next_states = Vec::from_iter((0..1u32 << input_count).map(|input| {
+                next_state.iter().fold(0, |next, equation| {
+                    next << 1 | equation.evaluate_term(state << input_count | input) as u32
+                })
+            }));
+            next_states.sort_unstable();
+            next_states.dedup();
+            next_states
+        }));
+
+        Ok(Self::unreachable(variables, initial_states, &next_states))
+    }
+
+    /// Returns the variables of the set.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the don't cares of the set.
+    pub fn terms(&self) -> &HashSet<u32> {
+        &self.terms
+    }
+
+    /// Returns the terms of the set that the `function` specifies as minterms or maxterms in ascending order.
+    ///
+    /// Returns [`Error::MismatchedVariables`] if the function doesn't have the same variables as the set.
+    pub fn overridden_care_terms(&self, function: &BooleanFunction) -> Result<Vec<u32>, Error> {
+        self.check_variables(function)?;
+
+        let mut terms = Vec::from_iter(
+            self.terms
+                .iter()
+                .filter(|term| {
+                    function.minterms().contains(term) || function.maxterms().contains(term)
+                })
+                .copied(),
+        );
+        terms.sort_unstable();
+
+        Ok(terms)
+    }
+
+    /// Returns the `function` with the terms of the set as don't cares.
+    ///
+    /// Returns [`Error::MismatchedVariables`] if the function doesn't have the same variables as the set.
+    pub fn apply(&self, function: &BooleanFunction) -> Result<BooleanFunction, Error> {
+        self.check_variables(function)?;
+
+        Ok(function.with_dont_cares(&self.terms))
+    }
+
+    /// Minimizes the function of each output with the terms of the set as don't cares.
+    ///
+    /// Returns the solutions of each output in the same order as `functions`,
+    /// [`Error::MismatchedVariables`] if a function doesn't have the same variables as the set,
+    /// and the same errors as [`minimize`](crate::minimize) otherwise.
+    pub fn minimize(
+        &self,
+        functions: &[BooleanFunction],
+        form: Form,
+        find_all_solutions: bool,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Vec<Cover>>, Error> {
+        functions
+            .iter()
+            .map(|function| {
+                self.apply(function)?
+                    .minimize(form, find_all_solutions, timeout)
+            })
+            .collect()
+    }
+
+    /// Returns the set of the states not reachable from the `initial_states`,
+    /// where `next_states[state]` are the next states of each state.
+    fn unreachable(
+        variables: Vec<String>,
+        initial_states: &[u32],
+        next_states: &[Vec<u32>],
+    ) -> Self {
+        let mut is_reachable = vec![false; next_states.len()];
+        let mut pending = initial_states.to_vec();
+
+        while let Some(state) = pending.pop() {
+            if !is_reachable[state as usize] {
+                is_reachable[state as usize] = true;
+                pending.extend_from_slice(&next_states[state as usize]);
+            }
+        }
+
+        let terms = (0..next_states.len() as u32)
+            .filter(|&state| !is_reachable[state as usize])
+            .collect();
+
+        GlobalDontCareSet { variables, terms }
+    }
+
+    fn check_variables(&self, function: &BooleanFunction) -> Result<(), Error> {
+        if function.variables() == self.variables {
+            Ok(())
+        } else {
+            Err(Error::MismatchedVariables {
+                expected: self.variables.clone(),
+                found: function.variables().to_vec(),
+            })
+        }
+    }
+}
@@ -1,7 +1,27 @@
 use std::{cell::RefCell, collections::HashSet, ops::Deref};
+#[cfg(feature = "parallel")]
+use std::{
+    cmp::Reverse,
+    mem,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex, Once,
+    },
+    thread,
+};
 
 use crate::{implicant::Implicant, Form};
 
+/// The number of implicants checked at once by [`Candidates::for_each_combinable`].
+const LANES: usize = 8;
+
+/// The number of implicants of a group combined with the next group by a thread at once.
+#[cfg(feature = "parallel")]
+const CHUNK_SIZE: usize = 64;
+
 #[derive(Clone)]
 pub struct Group {
     implicants: HashSet<Implicant>,
@@ -34,30 +54,171 @@ impl Group {
         groups
     }
 
-    pub fn combine(&self, other: &Self) -> Self {
-        let mut combined_group = Group::new();
+    /// Returns the groups of the combinations of each group with the next one.
+    #[cfg(not(feature = "parallel"))]
+    pub fn combine_adjacent(groups: &[Group]) -> Vec<Group> {
+        (0..groups.len() - 1)
+            .map(|i| groups[i].combine(&groups[i + 1]))
+            .collect()
+    }
 
-        for &implicant in &self.implicants {
-            for &other_implicant in &other.implicants {
-                if let Some(combined_implicant) = implicant.combine(other_implicant) {
-                    combined_group.implicants.insert(combined_implicant);
-
-                    for mut combined_implicants in [
-                        self.combined_implicants.borrow_mut(),
-                        other.combined_implicants.borrow_mut(),
-                    ] {
-                        combined_implicants.insert(implicant);
-                        combined_implicants.insert(other_implicant);
+    /// Returns the groups of the combinations of each group with the next one.
+    ///
+    /// The sizes of the groups are skewed towards the middle ones, so instead of a thread per pair of groups,
+    /// the implicants of each pair are split into chunks that the threads take from a shared queue,
+    /// starting with the pairs with the most work. The threads are started once per process
+    /// and shared by all the rounds of all the minimizations, whichever thread they run on.
+    #[cfg(feature = "parallel")]
+    pub fn combine_adjacent(groups: &[Group]) -> Vec<Group> {
+        Group::combine_adjacent_with(groups, ThreadPool::shared())
+    }
+
+    #[cfg(feature = "parallel")]
+    fn combine_adjacent_with(groups: &[Group], pool: &ThreadPool) -> Vec<Group> {
+        let candidates = Vec::from_iter(groups.iter().map(Candidates::new));
+        let mut tasks = vec![];
+
+        for (pair, group_candidates) in candidates[..groups.len() - 1].iter().enumerate() {
+            for start in (0..group_candidates.implicants.len()).step_by(CHUNK_SIZE) {
+                tasks.push((pair, start));
+            }
+        }
+
+        tasks.sort_by_key(|&(pair, start)| {
+            let work = candidates[pair].implicants.len() * candidates[pair + 1].implicants.len();
+            (Reverse(work), pair, start)
+        });
+
+        let thread_count = pool.thread_count.min(tasks.len());
+
+        if thread_count <= 1 {
+            return (0..groups.len() - 1)
+                .map(|i| groups[i].combine(&groups[i + 1]))
+                .collect();
+        }
+
+        let round = Arc::new(Round {
+            candidates,
+            tasks,
+            next_task: AtomicUsize::new(0),
+            combined_groups: Vec::from_iter((1..groups.len()).map(|_| Mutex::default())),
+            combined_implicants: Vec::from_iter(groups.iter().map(|_| Mutex::default())),
+        });
+        let (done_sender, done_receiver) = mpsc::channel();
+
+        for _ in 0..thread_count {
+            let round = Arc::clone(&round);
+            let done_sender = done_sender.clone();
+
+            pool.execute(move || {
+                round.run();
+                done_sender.send(()).unwrap();
+            });
+        }
+
+        drop(done_sender);
+
+        for _ in 0..thread_count {
+            done_receiver
+                .recv()
+                .expect("a thread combining the implicants panicked");
+        }
+
+        for (group, combined_implicants) in groups.iter().zip(&round.combined_implicants) {
+            let combined_implicants = mem::take(&mut *combined_implicants.lock().unwrap());
+            let mut group_combined_implicants = group.combined_implicants.borrow_mut();
+
+            if group_combined_implicants.is_empty() {
+                *group_combined_implicants = combined_implicants;
+            } else {
+                group_combined_implicants.extend(combined_implicants);
+            }
+        }
+
+        Vec::from_iter(
+            groups
+                .iter()
+                .zip(&round.combined_groups)
+                .map(|(group, combined_group)| {
+                    let implicants = mem::take(&mut *combined_group.lock().unwrap());
+
+                    if !implicants.is_empty() {
+                        *group.was_combined.borrow_mut() = true;
                     }
 
-                    *self.was_combined.borrow_mut() = true;
+                    Group {
+                        implicants,
+                        ..Group::new()
+                    }
+                }),
+        )
+    }
+
+    /// Returns the groups of the combinations of each group with the next one, where `shared` are groups of
+    /// don't cares whose combinations among themselves are already known. Only the combinations with
+    /// at least one implicant of `groups` are made, and missing `shared` groups are empty.
+    pub fn combine_adjacent_with_shared(groups: &[Group], shared: &[Group]) -> Vec<Group> {
+        (0..groups.len() - 1)
+            .map(|i| {
+                let mut combined_group = groups[i].combine(&groups[i + 1]);
+
+                if let Some(next_shared_group) = shared.get(i + 1) {
+                    combined_group.extend(groups[i].combine(next_shared_group));
                 }
-            }
+
+                if let Some(shared_group) = shared.get(i) {
+                    combined_group.extend(shared_group.combine(&groups[i + 1]));
+                }
+
+                combined_group
+            })
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.implicants.is_empty()
+    }
+
+    fn extend(&mut self, other: Group) {
+        self.implicants.extend(other.implicants);
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        let mut combined_group = Group::new();
+        let candidates = Candidates::new(other);
+
+        for &implicant in &self.implicants {
+            candidates.for_each_combinable(implicant, |other_implicant| {
+                self.add_combination(other, implicant, other_implicant, &mut combined_group);
+            });
         }
 
         combined_group
     }
 
+    /// Adds the combination of `implicant` of this group and `other_implicant` of the `other` group
+    /// to the `combined_group` and marks them as combined.
+    fn add_combination(
+        &self,
+        other: &Self,
+        implicant: Implicant,
+        other_implicant: Implicant,
+        combined_group: &mut Group,
+    ) {
+        let combined_implicant = implicant.combine(other_implicant).unwrap();
+        combined_group.implicants.insert(combined_implicant);
+
+        for mut combined_implicants in [
+            self.combined_implicants.borrow_mut(),
+            other.combined_implicants.borrow_mut(),
+        ] {
+            combined_implicants.insert(implicant);
+            combined_implicants.insert(other_implicant);
+        }
+
+        *self.was_combined.borrow_mut() = true;
+    }
+
     pub fn get_prime_implicants(&self, dont_cares: &HashSet<u32>) -> Vec<Implicant> {
         self.implicants
             .difference(self.combined_implicants.borrow().deref())
@@ -70,3 +231,219 @@ impl Group {
         *self.was_combined.borrow()
     }
 }
+
+/// A round of [`Group::combine_adjacent`] shared by the threads, each of which merges the combinations it
+/// made into the shared sets once it runs out of tasks.
+#[cfg(feature = "parallel")]
+struct Round {
+    candidates: Vec<Candidates>,
+    tasks: Vec<(usize, usize)>,
+    next_task: AtomicUsize,
+    combined_groups: Vec<Mutex<HashSet<Implicant>>>,
+    combined_implicants: Vec<Mutex<HashSet<Implicant>>>,
+}
+
+#[cfg(feature = "parallel")]
+impl Round {
+    fn run(&self) {
+        let mut combined_groups = vec![HashSet::new(); self.combined_groups.len()];
+        let mut combined_implicants = vec![HashSet::new(); self.combined_implicants.len()];
+
+        while let Some(&(pair, start)) = self
+            .tasks
+            .get(self.next_task.fetch_add(1, Ordering::Relaxed))
+        {
+            let implicants = &self.candidates[pair].implicants;
+            let end = (start + CHUNK_SIZE).min(implicants.len());
+
+            for &implicant in &implicants[start..end] {
+                self.candidates[pair + 1].for_each_combinable(implicant, |other_implicant| {
+                    combined_groups[pair].insert(implicant.combine(other_implicant).unwrap());
+
+                    for combined_implicants in &mut combined_implicants[pair..pair + 2] {
+                        combined_implicants.insert(implicant);
+                        combined_implicants.insert(other_implicant);
+                    }
+                });
+            }
+        }
+
+        for (shared, own) in self
+            .combined_groups
+            .iter()
+            .chain(&self.combined_implicants)
+            .zip(combined_groups.into_iter().chain(combined_implicants))
+        {
+            if !own.is_empty() {
+                shared.lock().unwrap().extend(own);
+            }
+        }
+    }
+}
+
+/// Threads that run the jobs sent to them until the pool is dropped.
+#[cfg(feature = "parallel")]
+struct ThreadPool {
+    thread_count: usize,
+    // `Sender` is only `Sync` since Rust 1.72.
+    jobs: Mutex<mpsc::Sender<Box<dyn FnOnce() + Send>>>,
+}
+
+#[cfg(feature = "parallel")]
+impl ThreadPool {
+    /// Returns the pool shared by the whole process, with a thread for each available core,
+    /// which is created on the first call and never dropped.
+    fn shared() -> &'static ThreadPool {
+        static INIT: Once = Once::new();
+        static POOL: AtomicPtr<ThreadPool> = AtomicPtr::new(ptr::null_mut());
+
+        INIT.call_once(|| {
+            let pool =
+                ThreadPool::new(thread::available_parallelism().map_or(1, NonZeroUsize::get));
+            POOL.store(Box::into_raw(Box::new(pool)), Ordering::Release);
+        });
+
+        // SAFETY: `call_once` has returned, so the pointer was set to a leaked box, which is never freed
+        // or changed again, and `ThreadPool` is `Sync`.
+        unsafe { &*POOL.load(Ordering::Acquire) }
+    }
+
+    fn new(thread_count: usize) -> Self {
+        let (jobs, receiver) = mpsc::channel::<Box<dyn FnOnce() + Send>>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for _ in 0..thread_count {
+            let receiver = Arc::clone(&receiver);
+
+            thread::spawn(move || loop {
+                let job = receiver.lock().unwrap().recv();
+
+                match job {
+                    // A panicking job drops its sender of the round, which the caller reports.
+                    Ok(job) => drop(panic::catch_unwind(AssertUnwindSafe(job))),
+                    Err(_) => break,
+                }
+            });
+        }
+
+        ThreadPool {
+            thread_count,
+            jobs: Mutex::new(jobs),
+        }
+    }
+
+    fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        self.jobs.lock().unwrap().send(Box::new(job)).unwrap();
+    }
+}
+
+/// The implicants of a group with separate arrays of their values and masks, so that the implicants
+/// that can be combined with another one are found in chunks of lanes, which the compiler turns into
+/// SIMD comparisons, and the rest of them one by one.
+struct Candidates {
+    implicants: Vec<Implicant>,
+    values: Vec<u32>,
+    masks: Vec<u32>,
+}
+
+impl Candidates {
+    fn new(group: &Group) -> Self {
+        let implicants = Vec::from_iter(group.implicants.iter().copied());
+
+        Candidates {
+            values: Vec::from_iter(implicants.iter().map(Implicant::value)),
+            masks: Vec::from_iter(implicants.iter().map(Implicant::mask)),
+            implicants,
+        }
+    }
+
+    /// Calls `f` with each of the implicants that can be combined with `implicant`.
+    fn for_each_combinable(&self, implicant: Implicant, mut f: impl FnMut(Implicant)) {
+        let chunk_count = self.implicants.len() / LANES;
+
+        for chunk in 0..chunk_count {
+            let start = chunk * LANES;
+            let mut lanes = combinable_lanes(
+                implicant,
+                self.values[start..start + LANES].try_into().unwrap(),
+                self.masks[start..start + LANES].try_into().unwrap(),
+            );
+
+            while lanes != 0 {
+                f(self.implicants[start + lanes.trailing_zeros() as usize]);
+                lanes &= lanes - 1;
+            }
+        }
+
+        for &other_implicant in &self.implicants[chunk_count * LANES..] {
+            if implicant.combine(other_implicant).is_some() {
+                f(other_implicant);
+            }
+        }
+    }
+}
+
+/// Returns a bit for each lane that is set if the implicant with the value and mask of the lane
+/// can be combined with `implicant`, i.e. has the same mask and a value that differs in exactly one bit.
+fn combinable_lanes(implicant: Implicant, values: &[u32; LANES], masks: &[u32; LANES]) -> u8 {
+    let mut lanes = 0;
+
+    for i in 0..LANES {
+        let diff = implicant.value() ^ values[i];
+        let is_combinable =
+            (masks[i] == implicant.mask()) & (diff != 0) & (diff & diff.wrapping_sub(1) == 0);
+
+        lanes |= (is_combinable as u8) << i;
+    }
+
+    lanes
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_combine_adjacent_parallel() {
+        let mut rng = StdRng::seed_from_u64(529);
+        let pool = ThreadPool::new(4);
+        let terms = HashSet::from_iter((0..1 << 10).filter(|_| rng.random_bool(0.6)));
+        let mut groups = Group::group_terms(10, &terms, Form::SOP);
+
+        while groups.len() > 1 {
+            let expected_groups = groups.clone();
+            let expected_combined_groups = Vec::from_iter(
+                (0..groups.len() - 1).map(|i| expected_groups[i].combine(&expected_groups[i + 1])),
+            );
+            let combined_groups = Group::combine_adjacent_with(&groups, &pool);
+
+            for (combined_group, expected_group) in
+                combined_groups.iter().zip(&expected_combined_groups)
+            {
+                assert_eq!(combined_group.implicants, expected_group.implicants);
+            }
+
+            for (group, expected_group) in groups.iter().zip(&expected_groups) {
+                assert_eq!(
+                    group.combined_implicants,
+                    expected_group.combined_implicants
+                );
+                assert_eq!(group.was_combined(), expected_group.was_combined());
+            }
+
+            groups = combined_groups;
+        }
+    }
+
+    #[test]
+    fn test_shared_thread_pool() {
+        let pool = ThreadPool::shared() as *const ThreadPool as usize;
+        let other_pool = thread::spawn(|| ThreadPool::shared() as *const ThreadPool as usize)
+            .join()
+            .unwrap();
+
+        assert_eq!(pool, other_pool);
+    }
+}
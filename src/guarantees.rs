@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The revision of the minimization algorithms.
+///
+/// It is incremented whenever a change may make any entry point return different solutions for the same input,
+/// so that published results can be tied to the behavior that produced them.
+pub const ALGORITHM_REVISION: u32 = 1;
+
+/// How close a solution is guaranteed to be to a minimum one.
+///
+/// Minimum is with respect to the cost the entry point minimizes: the number of products (or sums)
+/// and then the number of literals for [`minimize`](crate::minimize), and the documented cost for the others,
+/// such as [`minimize_power_aware`](crate::minimize_power_aware).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Optimality {
+    /// The solution is exactly minimum.
+    Minimum,
+    /// The solution is correct but may not be minimum.
+    Heuristic,
+}
+
+impl Display for Optimality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Optimality::Minimum => write!(f, "minimum"),
+            Optimality::Heuristic => write!(f, "heuristic"),
+        }
+    }
+}
+
+/// What is guaranteed about a [`Cover`](crate::Cover), recorded with it so that it remains interpretable
+/// as the crate evolves.
+///
+/// The guarantees of the entry points are:
+///
+/// - The solutions of all `minimize` functions, [`BooleanFunction::minimize`](crate::BooleanFunction::minimize),
+///   [`reminimize_preserving`](crate::reminimize_preserving) and [`Cover::or`](crate::Cover::or)
+///   and its siblings are [`Minimum`](Optimality::Minimum).
+/// - The solution of [`minimize_anytime`](crate::minimize_anytime) is [`Minimum`](Optimality::Minimum)
+///   if the exact solution was found within the budget, and [`Heuristic`](Optimality::Heuristic) otherwise.
+/// - [`Cover::cofactor`](crate::Cover::cofactor) keeps the implicants of the solution,
+///   so the residual expressions are [`Heuristic`](Optimality::Heuristic).
+/// - [`Cover::complement`](crate::Cover::complement) keeps the guarantees of the solution.
+/// - [`Cover::normalize`](crate::Cover::normalize) makes the solution [`Heuristic`](Optimality::Heuristic)
+///   if it is not irredundant.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 3], &[0, 2])
+///     .unwrap()
+///     .minimize(qmc::SOP, false, None)
+///     .unwrap()
+///     .pop()
+///     .unwrap();
+///
+/// assert_eq!(solution.guarantees().algorithm_revision, qmc::ALGORITHM_REVISION);
+/// assert_eq!(solution.guarantees().optimality, qmc::Optimality::Minimum);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Guarantees {
+    /// The [`ALGORITHM_REVISION`] that produced the solution,
+    /// or 0 for a solution serialized by a release that didn't record its guarantees.
+    pub algorithm_revision: u32,
+    /// How close the solution is guaranteed to be to a minimum one.
+    pub optimality: Optimality,
+}
+
+impl Guarantees {
+    pub(crate) fn new(optimality: Optimality) -> Self {
+        Guarantees {
+            algorithm_revision: ALGORITHM_REVISION,
+            optimality,
+        }
+    }
+
+    /// Returns the guarantees of a solution serialized by a release that didn't record them,
+    /// whose only minimization was exact.
+    #[cfg(feature = "serde")]
+    pub(crate) fn unrecorded() -> Self {
+        Guarantees {
+            algorithm_revision: 0,
+            optimality: Optimality::Minimum,
+        }
+    }
+}
@@ -2,9 +2,16 @@
 
 use std::{cmp::Ordering, collections::HashSet, hash::Hash};
 
-use crate::{solution::Variable, Form};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    solution::{Polarity, Variable},
+    Form,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Implicant {
     value: u32,
     mask: u32,
@@ -18,6 +25,49 @@ impl Implicant {
         }
     }
 
+    pub fn from_parts(value: u32, mask: u32) -> Self {
+        Implicant {
+            value: value & !mask,
+            mask,
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    pub fn contains(&self, term: u32) -> bool {
+        term & !self.mask == self.value
+    }
+
+    pub fn covers(&self, other: Self) -> bool {
+        other.mask & !self.mask == 0 && (self.value ^ other.value) & !self.mask == 0
+    }
+
+    /// Fixes the bits in `fixed_mask` to the ones in `fixed_value` and removes them from the implicant.
+    /// Returns `None` if the implicant doesn't contain any term with the fixed bits.
+    pub fn cofactor(&self, fixed_mask: u32, fixed_value: u32, variable_count: u32) -> Option<Self> {
+        if (self.value ^ fixed_value) & fixed_mask & !self.mask != 0 {
+            return None;
+        }
+
+        let mut value = 0;
+        let mut mask = 0;
+
+        for i in (0..variable_count).rev() {
+            if fixed_mask >> i & 1 == 0 {
+                value = value << 1 | self.value >> i & 1;
+                mask = mask << 1 | self.mask >> i & 1;
+            }
+        }
+
+        Some(Implicant { value, mask })
+    }
+
     pub fn combine(&self, other: Self) -> Option<Self> {
         if self.mask == other.mask {
             let diff = self.value ^ other.value;
@@ -35,6 +85,21 @@ impl Implicant {
         }
     }
 
+    /// Returns the consensus of the implicants, the largest implicant in their union with terms of both,
+    /// or `None` if they don't conflict in exactly one bit that is fixed in both.
+    pub fn consensus(&self, other: Self) -> Option<Self> {
+        let conflict = (self.value ^ other.value) & !self.mask & !other.mask;
+
+        if conflict.is_power_of_two() {
+            Some(Implicant::from_parts(
+                (self.value | other.value) & !conflict,
+                self.mask & other.mask | conflict,
+            ))
+        } else {
+            None
+        }
+    }
+
     pub fn get_terms(&self) -> HashSet<u32> {
         fn get_terms_(value: u32, mask: u32, terms: &mut HashSet<u32>) {
             let wildcard_index = mask.trailing_zeros();
@@ -56,28 +121,62 @@ impl Implicant {
         terms
     }
 
+    /// Returns the probability of a term being in the implicant, given the independent probabilities
+    /// of each variable being 1.
+    pub fn probability(&self, one_probabilities: &[f64]) -> f64 {
+        let variable_count = one_probabilities.len();
+
+        one_probabilities
+            .iter()
+            .enumerate()
+            .map(|(index, &probability)| {
+                let i = variable_count - index - 1;
+
+                if (self.mask >> i) & 1 == 1 {
+                    1.0
+                } else if (self.value >> i) & 1 == 1 {
+                    probability
+                } else {
+                    1.0 - probability
+                }
+            })
+            .product()
+    }
+
     pub fn wildcard_count(&self) -> u32 {
         self.mask.count_ones()
     }
 
-    pub fn to_variables(self, variable_names: &[String], form: Form) -> Vec<Variable> {
-        let mut variables = vec![];
-        let variable_count = variable_names.len();
-
-        for i in (0..variable_count).rev() {
-            let value_bit = (self.value >> i) & 1;
-            let mask_bit = (self.mask >> i) & 1;
+    pub fn literal(&self, index: usize, variable_count: usize, form: Form) -> Option<Polarity> {
+        let i = variable_count - index - 1;
+        let value_bit = (self.value >> i) & 1;
+        let mask_bit = (self.mask >> i) & 1;
 
-            if mask_bit != 1 {
-                let index = variable_count - i - 1;
-                let is_negated =
-                    form == Form::SOP && value_bit == 0 || form == Form::POS && value_bit == 1;
+        if mask_bit == 1 {
+            return None;
+        }
 
-                variables.push(Variable::new(variable_names[index].clone(), is_negated));
-            }
+        // Implicants cover maxterms in POS form, so a variable is negated when its bit is 1.
+        if form == Form::SOP && value_bit == 0 || form == Form::POS && value_bit == 1 {
+            Some(Polarity::Negative)
+        } else {
+            Some(Polarity::Positive)
         }
+    }
+
+    pub fn to_variables(self, variable_names: &[String], form: Form) -> Vec<Variable> {
+        let variable_count = variable_names.len();
 
-        variables
+        (0..variable_count)
+            .filter_map(|index| {
+                self.literal(index, variable_count, form).map(|polarity| {
+                    Variable::new(
+                        variable_names[index].clone(),
+                        polarity == Polarity::Negative,
+                    )
+                })
+            })
+            .collect()
     }
 }
 
@@ -136,22 +235,5 @@ mod tests {
                 mask: u32::from_str_radix(&str.replace('1', "0").replace('-', "1"), 2).unwrap(),
             }
         }
-
-        pub fn to_str(self, variable_count: u32) -> String {
-            let mut str = String::new();
-
-            for i in (0..variable_count).rev() {
-                let value_bit = (self.value >> i) & 1;
-                let mask_bit = (self.mask >> i) & 1;
-
-                if mask_bit == 1 {
-                    str.push('-');
-                } else {
-                    str.push(if value_bit == 1 { '1' } else { '0' });
-                }
-            }
-
-            str
-        }
     }
 }
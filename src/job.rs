@@ -0,0 +1,210 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::{
+    problem::{io_error, json_error},
+    solutions_to_blif, solutions_to_pla, Blif, BooleanFunction, Cover, Error, ExpressionStyle, Pla,
+    Problem, SettingsProfile, POS,
+};
+
+/// Runs the job described by the JSON file at `path`, which minimizes a specification and writes
+/// the solutions in the requested formats, and returns the paths of the written files in order.
+///
+/// A job file looks like this:
+///
+/// ```json
+/// {
+///   "input": { "pla": "adder.pla" },
+///   "options": { "form": "SOP" },
+///   "outputs": [
+///     { "format": "verilog", "path": "build/adder.v" },
+///     { "format": "text", "path": "build/adder.txt" }
+///   ]
+/// }
+/// ```
+///
+/// `input` is one of:
+///
+/// - `{ "pla": path }` for a [`Pla`] file, whose outputs are minimized separately,
+/// - `{ "blif": path }` for a [`Blif`] file,
+/// - `{ "problem": path }` for a [`Problem`](crate::Problem) file,
+/// - the fields of a [`Problem`](crate::Problem) given inline, whose output is named `F0`.
+///
+/// `options` are the settings of a [`SettingsProfile`]. If they are missing, the ones of the problem are used
+/// and otherwise the default ones. The first solution of each output is written.
+///
+/// Each output has a `path` and one of these `format`s:
+///
+/// | Format    | Contents                                                   |
+/// |:---------:|:-----------------------------------------------------------|
+/// | `text`    | A line `output = solution` for each output, written in the default [`ExpressionStyle`] |
+/// | `pla`     | A PLA file of all outputs, see [`solutions_to_pla`]        |
+/// | `blif`    | A model of all outputs named after the job file, see [`solutions_to_blif`] |
+/// | `verilog` | A module named after each output, see [`Cover::to_verilog`] |
+/// | `smtlib`  | A `define-fun` named after each output, see [`Cover::to_smtlib`] |
+/// | `dimacs`  | A DIMACS CNF file of a single output in [`POS`] form, see [`Cover::to_dimacs`] |
+///
+/// The text output doesn't depend on [`set_default_format`](crate::set_default_format), so the same job always
+/// writes the same files.
+///
+/// Relative paths, both of inputs and outputs, are relative to the directory of the job file,
+/// and missing directories of outputs are created.
+///
+/// Returns [`Error::Io`] if a file can't be read or written, [`Error::InvalidJson`] if the job file is not a valid job
+/// or requests an output that can't be written, and the errors of reading the input and of [`SettingsProfile::minimize`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::fs;
+///
+/// use quine_mccluskey as qmc;
+///
+/// let directory = std::env::temp_dir().join(format!("qmc-job-doc-{}", std::process::id()));
+/// fs::create_dir_all(&directory).unwrap();
+/// fs::write(
+///     directory.join("job.json"),
+///     r#"{
+///         "input": { "variables": ["A", "B", "C"], "minterms": [0, 5], "maxterms": [1, 3, 4, 6] },
+///         "outputs": [{ "format": "text", "path": "out/f.txt" }]
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// let written = qmc::run_job(directory.join("job.json")).unwrap();
+///
+/// assert_eq!(written, [directory.join("out/f.txt")]);
+/// assert_eq!(fs::read_to_string(&written[0]).unwrap(), "F0 = (A ∧ C) ∨ (~A ∧ ~C)\n");
+/// # fs::remove_dir_all(&directory).unwrap();
+/// ```
+pub fn run_job(path: impl AsRef<Path>) -> Result<Vec<PathBuf>, Error> {
+    let path = path.as_ref();
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+    let job: Job =
+        serde_json::from_str(&fs::read_to_string(path).map_err(io_error)?).map_err(json_error)?;
+
+    let (outputs, functions, input_options) = read_input(job.input, directory)?;
+    let options = job
+        .options
+        .unwrap_or_else(|| input_options.unwrap_or_default());
+
+    let mut solutions = vec![];
+
+    for function in &functions {
+        solutions.push(options.minimize(function)?.remove(0));
+    }
+
+    let model = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("job");
+    let mut written = vec![];
+
+    for request in &job.outputs {
+        let contents = write_output(&request.format, model, &solutions, &outputs)?;
+        let output_path = directory.join(&request.path);
+
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+
+        fs::write(&output_path, contents).map_err(io_error)?;
+        written.push(output_path);
+    }
+
+    Ok(written)
+}
+
+#[derive(Deserialize)]
+struct Job {
+    input: InputSpec,
+    options: Option<SettingsProfile>,
+    #[serde(default)]
+    outputs: Vec<OutputRequest>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InputSpec {
+    Pla { pla: PathBuf },
+    Blif { blif: PathBuf },
+    Problem { problem: PathBuf },
+    Inline(Problem),
+}
+
+#[derive(Deserialize)]
+struct OutputRequest {
+    format: String,
+    path: PathBuf,
+}
+
+type Input = (Vec<String>, Vec<BooleanFunction>, Option<SettingsProfile>);
+
+fn read_input(input: InputSpec, directory: &Path) -> Result<Input, Error> {
+    let read = |path: PathBuf| fs::read_to_string(directory.join(path)).map_err(io_error);
+
+    match input {
+        InputSpec::Pla { pla } => {
+            let pla: Pla = read(pla)?.parse()?;
+
+            Ok((pla.outputs().to_vec(), pla.functions().to_vec(), None))
+        }
+        InputSpec::Blif { blif } => {
+            let blif: Blif = read(blif)?.parse()?;
+
+            Ok((
+                vec![blif.output().to_owned()],
+                vec![blif.function().clone()],
+                None,
+            ))
+        }
+        InputSpec::Problem { problem } => read_problem(read(problem)?.parse()?),
+        InputSpec::Inline(problem) => read_problem(problem),
+    }
+}
+
+fn read_problem(problem: Problem) -> Result<Input, Error> {
+    Ok((
+        vec!["F0".to_owned()],
+        vec![problem.function()?],
+        Some(problem.options),
+    ))
+}
+
+fn write_output(
+    format: &str,
+    model: &str,
+    solutions: &[Cover],
+    outputs: &[String],
+) -> Result<String, Error> {
+    let each = |write: &dyn Fn(&Cover, &str) -> Result<String, Error>| {
+        solutions
+            .iter()
+            .zip(outputs)
+            .map(|(solution, output)| write(solution, output))
+            .collect::<Result<String, Error>>()
+    };
+
+    match format {
+        "text" => each(&|solution, output| {
+            Ok(format!(
+                "{} = {}\n",
+                output,
+                solution.to_string_with(ExpressionStyle::default())
+            ))
+        }),
+        "pla" => solutions_to_pla(solutions, outputs),
+        "blif" => solutions_to_blif(model, solutions, outputs),
+        "verilog" => each(&|solution, output| solution.to_verilog(output)),
+        "smtlib" => each(&|solution, output| Ok(format!("{}\n", solution.to_smtlib(output)?))),
+        "dimacs" => match solutions {
+            [solution] if solution.form() == POS => solution.to_dimacs(),
+            _ => Err(Error::InvalidJson(
+                "dimacs output requires a single output in POS form".to_owned(),
+            )),
+        },
+        format => Err(Error::InvalidJson(format!("unknown format {:?}", format))),
+    }
+}
@@ -0,0 +1,396 @@
+use std::fmt::Write;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{report::escape, BooleanFunction, Cover};
+
+/// The largest number of variables of a function whose Karnaugh map is drawn.
+pub(crate) const MAX_VARIABLES: usize = 6;
+
+const CELL_SIZE: usize = 40;
+const LEFT_MARGIN: usize = 80;
+const TOP_MARGIN: usize = 60;
+const GROUP_COLORS: [&str; 8] = [
+    "#e41a1c", "#377eb8", "#4daf4a", "#984ea3", "#ff7f00", "#a65628", "#f781bf", "#999999",
+];
+
+/// A cell of a Karnaugh map, see [`Cover::karnaugh_groups`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KarnaughCell {
+    /// The row of the cell from the top, starting at 0.
+    pub row: usize,
+    /// The column of the cell from the left, starting at 0.
+    pub col: usize,
+    /// The term of the cell.
+    pub term: u32,
+}
+
+/// A block of adjacent cells of a Karnaugh map with the given first and last rows and columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KarnaughRectangle {
+    pub first_row: usize,
+    pub last_row: usize,
+    pub first_col: usize,
+    pub last_col: usize,
+}
+
+/// The cells of a Karnaugh map covered by a product (or sum) of a [`Cover`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KarnaughGroup {
+    /// The covered cells from top to bottom and then left to right.
+    pub cells: Vec<KarnaughCell>,
+    /// The blocks of adjacent covered cells, which are more than one if the group wraps around an edge
+    /// of the map or spans rows or columns that are not adjacent.
+    pub rectangles: Vec<KarnaughRectangle>,
+}
+
+/// The arrangement of the terms of a function in a Karnaugh map.
+///
+/// The first half of the variables (rounded down) select the row and the rest select the column,
+/// both in Gray code order.
+pub(crate) struct Layout {
+    pub(crate) row_bits: usize,
+    pub(crate) col_bits: usize,
+}
+
+impl Layout {
+    pub(crate) fn new(variable_count: usize) -> Self {
+        let row_bits = variable_count / 2;
+
+        Layout {
+            row_bits,
+            col_bits: variable_count - row_bits,
+        }
+    }
+
+    pub(crate) fn rows(&self) -> u32 {
+        1 << self.row_bits
+    }
+
+    pub(crate) fn cols(&self) -> u32 {
+        1 << self.col_bits
+    }
+
+    /// Returns the term of the cell at the given position.
+    pub(crate) fn term(&self, row: u32, col: u32) -> u32 {
+        gray_code(row) << self.col_bits | gray_code(col)
+    }
+
+    /// Returns the label of the given row, e.g. `01`.
+    pub(crate) fn row_label(&self, row: u32) -> String {
+        bits(gray_code(row), self.row_bits)
+    }
+
+    /// Returns the label of the given column, e.g. `110`.
+    pub(crate) fn col_label(&self, col: u32) -> String {
+        bits(gray_code(col), self.col_bits)
+    }
+}
+
+/// Returns the output of the `term` as `1`, `0` or `X` for a don't care.
+pub(crate) fn output(function: &BooleanFunction, term: u32) -> &'static str {
+    match function.evaluate(term) {
+        Some(true) => "1",
+        Some(false) => "0",
+        None => "X",
+    }
+}
+
+pub(crate) fn assert_drawable(function: &BooleanFunction, solution: Option<&Cover>) {
+    assert!(
+        function.variables().len() <= MAX_VARIABLES,
+        "expected at most {} variables",
+        MAX_VARIABLES
+    );
+
+    if let Some(solution) = solution {
+        assert_eq!(
+            solution.variables(),
+            function.variables(),
+            "solution and function have different variables"
+        );
+    }
+}
+
+pub(crate) fn write_svg(function: &BooleanFunction, solution: Option<&Cover>) -> String {
+    assert_drawable(function, solution);
+
+    let variables = function.variables();
+    let layout = Layout::new(variables.len());
+    let mut svg = String::new();
+
+    writeln!(
+        svg,
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" ",
+            "font-family=\"sans-serif\" font-size=\"14\" text-anchor=\"middle\" dominant-baseline=\"central\">"
+        ),
+        LEFT_MARGIN + layout.cols() as usize * CELL_SIZE + 10,
+        TOP_MARGIN + layout.rows() as usize * CELL_SIZE + 10,
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        "<text x=\"{}\" y=\"{}\">{} \\ {}</text>",
+        LEFT_MARGIN / 2,
+        TOP_MARGIN / 2,
+        escape(&variables[..layout.row_bits].join(" ")),
+        escape(&variables[layout.row_bits..].join(" "))
+    )
+    .unwrap();
+
+    for col in 0..layout.cols() {
+        writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\">{}</text>",
+            col_x(col) + CELL_SIZE / 2,
+            TOP_MARGIN - 15,
+            layout.col_label(col)
+        )
+        .unwrap();
+    }
+
+    for row in 0..layout.rows() {
+        writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\">{}</text>",
+            LEFT_MARGIN - 25,
+            row_y(row) + CELL_SIZE / 2,
+            layout.row_label(row)
+        )
+        .unwrap();
+    }
+
+    for row in 0..layout.rows() {
+        for col in 0..layout.cols() {
+            let term = layout.term(row, col);
+
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#999\"/>",
+                col_x(col),
+                row_y(row),
+                CELL_SIZE,
+                CELL_SIZE
+            )
+            .unwrap();
+            writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{}\" data-term=\"{}\">{}</text>",
+                col_x(col) + CELL_SIZE / 2,
+                row_y(row) + CELL_SIZE / 2,
+                term,
+                output(function, term)
+            )
+            .unwrap();
+        }
+    }
+
+    for (i, group) in solution.map(groups).unwrap_or_default().iter().enumerate() {
+        let color = GROUP_COLORS[i % GROUP_COLORS.len()];
+        let inset = 3 + 3 * (i % 4);
+
+        for rectangle in &group.rectangles {
+            writeln!(
+                svg,
+                concat!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"8\" ",
+                    "fill=\"{}\" fill-opacity=\"0.2\" stroke=\"{}\" stroke-width=\"2\"/>"
+                ),
+                col_x(rectangle.first_col as u32) + inset,
+                row_y(rectangle.first_row as u32) + inset,
+                (rectangle.last_col - rectangle.first_col + 1) * CELL_SIZE - 2 * inset,
+                (rectangle.last_row - rectangle.first_row + 1) * CELL_SIZE - 2 * inset,
+                color,
+                color
+            )
+            .unwrap();
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Returns the groups of cells of the products (or sums) of the solution in the order of its implicants.
+pub(crate) fn groups(solution: &Cover) -> Vec<KarnaughGroup> {
+    assert!(
+        solution.variables().len() <= MAX_VARIABLES,
+        "expected at most {} variables",
+        MAX_VARIABLES
+    );
+
+    let layout = Layout::new(solution.variables().len());
+
+    Vec::from_iter(solution.implicants().iter().map(|implicant| {
+        let mut cells = vec![];
+
+        for row in 0..layout.rows() {
+            for col in 0..layout.cols() {
+                let term = layout.term(row, col);
+
+                if implicant.contains(term) {
+                    cells.push(KarnaughCell {
+                        row: row as usize,
+                        col: col as usize,
+                        term,
+                    });
+                }
+            }
+        }
+
+        // An implicant covers every combination of its rows and columns, which form runs of adjacent positions.
+        let rows = runs(cells.iter().map(|cell| cell.row));
+        let cols = runs(cells.iter().map(|cell| cell.col));
+        let mut rectangles = vec![];
+
+        for &(first_row, last_row) in &rows {
+            for &(first_col, last_col) in &cols {
+                rectangles.push(KarnaughRectangle {
+                    first_row,
+                    last_row,
+                    first_col,
+                    last_col,
+                });
+            }
+        }
+
+        KarnaughGroup { cells, rectangles }
+    }))
+}
+
+pub(crate) fn write_text(function: &BooleanFunction, solution: Option<&Cover>) -> String {
+    assert_drawable(function, solution);
+
+    let variables = function.variables();
+    let layout = Layout::new(variables.len());
+    let implicants = solution.map_or(&[][..], Cover::implicants);
+
+    let corner = format!(
+        "{} \\ {}",
+        variables[..layout.row_bits].join(" "),
+        variables[layout.row_bits..].join(" ")
+    );
+    let cells = Vec::from_iter((0..layout.rows()).map(|row| {
+        Vec::from_iter((0..layout.cols()).map(|col| {
+            let term = layout.term(row, col);
+            let mut cell = output(function, term).to_owned();
+
+            cell.extend(
+                (0..implicants.len())
+                    .filter(|&i| implicants[i].contains(term))
+                    .map(group_letter),
+            );
+
+            cell
+        }))
+    }));
+
+    let row_label_width = corner.chars().count().max(layout.row_bits);
+    let cell_width = cells
+        .iter()
+        .flatten()
+        .map(String::len)
+        .max()
+        .unwrap()
+        .max(layout.col_bits);
+
+    let mut lines = vec![];
+    let col_labels = Vec::from_iter((0..layout.cols()).map(|col| layout.col_label(col)));
+
+    lines.push(format!(
+        "{:>rw$} | {}",
+        corner,
+        pad(&col_labels, cell_width),
+        rw = row_label_width
+    ));
+    lines.push(format!(
+        "{}-+-{}",
+        "-".repeat(row_label_width),
+        "-".repeat((cell_width + 1) * layout.cols() as usize - 1)
+    ));
+
+    for (row, cells) in cells.iter().enumerate() {
+        lines.push(format!(
+            "{:>rw$} | {}",
+            layout.row_label(row as u32),
+            pad(cells, cell_width),
+            rw = row_label_width
+        ));
+    }
+
+    if !implicants.is_empty() {
+        let solution = solution.unwrap();
+
+        lines.push(String::new());
+        lines.extend(implicants.iter().enumerate().map(|(i, &implicant)| {
+            format!(
+                "{}: {}",
+                group_letter(i),
+                Cover::new(&[implicant], variables, solution.form())
+            )
+        }));
+    }
+
+    let mut text = Vec::from_iter(lines.iter().map(|line| line.trim_end())).join("\n");
+    text.push('\n');
+    text
+}
+
+/// Returns the letter marking the cells covered by the `i`th implicant of a solution.
+fn group_letter(i: usize) -> char {
+    let letters = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    letters.get(i).map_or('*', |&letter| letter as char)
+}
+
+fn pad(strings: &[String], width: usize) -> String {
+    Vec::from_iter(
+        strings
+            .iter()
+            .map(|string| format!("{:<width$}", string, width = width)),
+    )
+    .join(" ")
+}
+
+fn gray_code(i: u32) -> u32 {
+    i ^ i >> 1
+}
+
+fn bits(value: u32, bit_count: usize) -> String {
+    (0..bit_count)
+        .rev()
+        .map(|i| if value >> i & 1 == 1 { '1' } else { '0' })
+        .collect()
+}
+
+fn col_x(col: u32) -> usize {
+    LEFT_MARGIN + col as usize * CELL_SIZE
+}
+
+fn row_y(row: u32) -> usize {
+    TOP_MARGIN + row as usize * CELL_SIZE
+}
+
+/// Returns the first and last positions of the runs of consecutive `positions`, which are in ascending order
+/// but may repeat.
+fn runs(positions: impl Iterator<Item = usize>) -> Vec<(usize, usize)> {
+    let mut positions = Vec::from_iter(positions);
+    positions.sort_unstable();
+    positions.dedup();
+
+    let mut runs: Vec<(usize, usize)> = vec![];
+
+    for position in positions {
+        match runs.last_mut() {
+            Some((_, last)) if *last + 1 == position => *last = position,
+            _ => runs.push((position, position)),
+        }
+    }
+
+    runs
+}
@@ -24,29 +24,120 @@
 //! [`minimize`] is sufficient for all use cases. But also check [`minimize_minterms`] and
 //! [`minimize_maxterms`] to see if they are more suitable for your use case.
 //!
+//! Regions of don't cares can be given as cubes like `"1--0"` without listing their terms to
+//! [`minimize_minterms_with_cubes`] and [`minimize_maxterms_with_cubes`], and regions of terms can be given
+//! to any of the functions with [`Cube::expand`].
+//!
+//! [`BooleanFunction`] can be used to transform a function before minimizing it.
+//! It can also be created from [`Term`]s and [`Cube`]s checked against a [`VariableSet`].
+//! Specifications given as cubes can be exchanged in a compact binary format with [`CubeList`]
+//! and read from Berkeley PLA files with [`Pla`], single-output BLIF tables with [`Blif`] or CNF formulas with [`Dimacs`].
+//! Solutions can be written as PLA files with [`Cover::to_pla`] and BLIF tables with [`Cover::to_blif`].
+//! Hand-written expressions can be turned into minterms and maxterms with [`parse_expression`]
+//! or minimized directly with [`simplify_expression`], and truth tables exported by circuit simulators
+//! can be read with [`TableExport`].
+//! Settings shared across runs can be kept in a [`SettingsProfile`]. With the `serde` feature, whole problems
+//! can be stored in JSON files with [`Problem`], and [`run_job`] runs a whole minimization step described by
+//! a JSON job file.
+//! Solutions are displayed with `∧` and `∨` unless another [`ExpressionStyle`] is chosen for the current thread
+//! with [`set_default_format`].
+//!
 //! # Feature flags
 //!
-//! * `serde` -- Derives the [`Serialize`] and [`Deserialize`] traits for structs and enums.
+//! * `serde` -- Derives the [`Serialize`] and [`Deserialize`] traits for structs and enums,
+//!   and adds [`Problem`] and [`run_job`] for JSON files.
+//! * `audit` -- Enables `minimize_audited`, which records the decisions made while simplifying the prime implicant chart.
+//! * `parallel` -- Combines the implicants while finding the prime implicants on all available cores.
 
 #![deny(deprecated)]
 
+mod affine;
+#[cfg(feature = "audit")]
+mod audit;
+mod blif;
+mod clause;
+mod cube;
+mod cube_list;
+mod dimacs;
+mod expr;
+mod format;
+mod function;
+mod global_dont_cares;
 mod group;
+mod guarantees;
 mod implicant;
+#[cfg(feature = "serde")]
+mod job;
+mod karnaugh;
+mod parser;
 mod petrick;
+mod pla;
 mod prime_implicant_chart;
+#[cfg(feature = "serde")]
+mod problem;
+mod profile;
+mod report;
+mod small;
 mod solution;
+mod table_export;
+mod threshold;
 mod timeout_signal;
-
+mod truth_table;
+mod variable_set;
+pub mod verification;
+mod warning;
+
+pub use affine::AffineForm;
+#[cfg(feature = "audit")]
+pub use audit::{AuditEvent, AuditLog};
+pub use blif::{solutions_to_blif, Blif};
+pub use clause::{Clause, Literal, Product};
+pub use cube::Cube;
+pub use cube_list::CubeList;
+pub use dimacs::Dimacs;
+pub use expr::Expr;
+pub use format::{
+    default_format, set_default_format, DefaultFormatGuard, ExpressionStyle, Identifiers, Negation,
+    Style,
+};
+pub use function::{
+    diff_functions, BooleanFunction, Certificate, CompletedFunction, DontCareUsage, FunctionDiff,
+};
+pub use global_dont_cares::GlobalDontCareSet;
+pub use guarantees::{Guarantees, Optimality, ALGORITHM_REVISION};
+#[cfg(feature = "serde")]
+pub use job::run_job;
+pub use karnaugh::{KarnaughCell, KarnaughGroup, KarnaughRectangle};
+pub use parser::{parse_expression, simplify_expression, MAX_NESTING_DEPTH};
+pub use pla::{solutions_to_pla, Pla};
+#[cfg(feature = "serde")]
+pub use problem::Problem;
+pub use profile::{CostModel, SettingsProfile};
+pub use report::MinimizeReport;
+pub use small::{minimize_const, MAX_CONST_VARIABLES};
+pub use solution::Cover;
+pub use solution::LiteralSensitization;
+pub use solution::Polarity;
 pub use solution::Solution;
+pub use solution::Substitution;
 pub use solution::Variable;
+pub use solution::SOLUTION_SCHEMA_VERSION;
+pub use table_export::TableExport;
+pub use threshold::ThresholdForm;
+pub use truth_table::{BitOrder, TruthTable};
+pub use variable_set::{Term, VariableSet};
+pub use warning::Warning;
 #[doc(hidden)]
 pub use Form::{POS, SOP};
 
-use std::collections::HashSet;
-use std::ops::Not;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::io;
+use std::ops::{Not, Range};
 use std::sync::{mpsc, Arc};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -54,7 +145,8 @@ use serde::{Deserialize, Serialize};
 use crate::group::Group;
 use crate::implicant::{Implicant, VariableSort};
 use crate::petrick::Petrick;
-use crate::prime_implicant_chart::PrimeImplicantChart;
+use crate::prime_implicant_chart::{column_terms, PrimeImplicantChart};
+use crate::solution::covers_all_terms;
 use crate::timeout_signal::{TTimeoutSignal, TimeoutSignalAtomicBool, TimeoutSignalNoOp};
 
 /// Minimizes the boolean function represented by the given `minterms` and `maxterms`.
@@ -63,6 +155,7 @@ use crate::timeout_signal::{TTimeoutSignal, TimeoutSignalAtomicBool, TimeoutSign
 ///
 /// `minterms` represent the terms whose output is 1 and `maxterms` represent the terms whose output is 0.
 /// The rest of the terms are inferred to be don't care conditions.
+/// The first variable is the most significant bit of a term, see [`term_assignment`].
 ///
 /// `form` determines whether the minimized expression is of the form [`SOP`] (Sum of Products) or [`POS`] (Product of Sums).
 ///
@@ -71,6 +164,13 @@ use crate::timeout_signal::{TTimeoutSignal, TimeoutSignalAtomicBool, TimeoutSign
 ///
 /// If `timeout` is specified, the function will return [`Error::Timeout`] if the solution is not found within the given time.
 ///
+/// If every term is a don't care, both constants are solutions. The solution is the constant without products (or sums),
+/// i.e. 0 in [`SOP`] form and 1 in [`POS`] form, followed by the other constant if `find_all_solutions` is `true`.
+/// [`BooleanFunction::minimize`] returns them as [`Cover`]s with the warning [`Warning::AllDontCares`].
+///
+/// The solutions are [`Solution`]s. To analyze, transform or export them, minimize a [`BooleanFunction`] instead,
+/// which returns [`Cover`]s.
+///
 /// # Example
 ///
 /// Let's minimize the boolean function expressed by the following truth table:
@@ -135,33 +235,15 @@ pub fn minimize<T: AsRef<str>>(
     find_all_solutions: bool,
     timeout: Option<Duration>,
 ) -> Result<Vec<Solution>, Error> {
-    let variables = own_variables(variables);
-
-    let variable_count = variables.len();
-    let variable_count =
-        u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
-
-    let minterms = minterms.iter().copied().collect();
-    let maxterms = maxterms.iter().copied().collect();
-
-    validate_input(&variables, &minterms, &maxterms)?;
-
-    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
-    let terms = if form == SOP { minterms } else { maxterms };
-
-    let internal_solutions = minimize_internal_with_timeout(
-        variable_count,
-        terms,
-        dont_cares,
+    minimize_term_sets(
+        own_variables(variables),
+        minterms.iter().copied().collect(),
+        maxterms.iter().copied().collect(),
         form,
         find_all_solutions,
         timeout,
-    )?;
-
-    Ok(internal_solutions
-        .iter()
-        .map(|solution| Solution::new(solution, &variables, form))
-        .collect())
+    )
+    .map(to_enum_solutions)
 }
 
 /// Minimizes the boolean function represented by the given `minterms` and `dont_cares`.
@@ -219,6 +301,8 @@ pub fn minimize_minterms<T: AsRef<str>>(
 
     validate_input(&variables, &minterms, &dont_cares)?;
 
+    let conversion = Conversion::new(&variables, SOP, &dont_cares, find_all_solutions);
+
     let internal_solutions = minimize_internal_with_timeout(
         variable_count,
         minterms,
@@ -228,10 +312,7 @@ pub fn minimize_minterms<T: AsRef<str>>(
         timeout,
     )?;
 
-    Ok(internal_solutions
-        .iter()
-        .map(|solution| Solution::new(solution, &variables, SOP))
-        .collect())
+    Ok(to_enum_solutions(conversion.to_covers(&internal_solutions)))
 }
 
 /// Minimizes the boolean function represented by the given `maxterms` and `dont_cares`.
@@ -289,6 +370,8 @@ pub fn minimize_maxterms<T: AsRef<str>>(
 
     validate_input(&variables, &maxterms, &dont_cares)?;
 
+    let conversion = Conversion::new(&variables, POS, &dont_cares, find_all_solutions);
+
     let internal_solutions = minimize_internal_with_timeout(
         variable_count,
         maxterms,
@@ -298,125 +381,2020 @@ pub fn minimize_maxterms<T: AsRef<str>>(
         timeout,
     )?;
 
-    Ok(internal_solutions
-        .iter()
-        .map(|solution| Solution::new(solution, &variables, POS))
-        .collect())
+    Ok(to_enum_solutions(conversion.to_covers(&internal_solutions)))
 }
 
-/// The form of a boolean expression.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum Form {
-    /// Sum of Products
-    SOP,
-    /// Product of Sums
-    POS,
+/// Minimizes the boolean function represented by the given `minterms` and the don't cares for which
+/// `is_dont_care` returns `true`, like [`minimize_minterms`].
+///
+/// This saves listing large, regular don't care regions that are easier to describe, but not enumerating them:
+/// `is_dont_care` is called once for each of the 2^n terms in ascending order, so this takes time and memory
+/// proportional to 2^n like [`minimize_minterms`]. Use [`minimize_minterms_with_cubes`] for don't cares
+/// given as cubes, which are not enumerated.
+///
+/// Returns [`Error::TermConflict`] if `is_dont_care` returns `true` for any of the `minterms`,
+/// and the same errors as [`minimize_minterms`] otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // Whether the BCD digit is at least 5, where the codes above 9 are unused.
+/// let mut solutions = qmc::minimize_minterms_with(
+///     &qmc::DEFAULT_VARIABLES[..4],
+///     &[5, 6, 7, 8, 9],
+///     |term| term > 9,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "A ∨ (B ∧ C) ∨ (B ∧ D)"
+/// );
+/// ```
+pub fn minimize_minterms_with<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    is_dont_care: impl Fn(u32) -> bool,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    minimize_with_dont_care_predicate(
+        own_variables(variables),
+        minterms,
+        is_dont_care,
+        SOP,
+        find_all_solutions,
+        timeout,
+    )
 }
 
-/// All letters of the English alphabet in uppercase.
-pub static DEFAULT_VARIABLES: [&str; 26] = [
-    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S",
-    "T", "U", "V", "W", "X", "Y", "Z",
-];
-
-/// Error types for bad input and timeout.
-#[derive(Debug, thiserror::Error, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum Error {
-    /// The number of variables was less than 1 or greater than `DEFAULT_VARIABLES.len()`.
-    #[error("Invalid variable count: {0} (expected 1 <= variables.len() <= {max_len})", max_len = DEFAULT_VARIABLES.len())]
-    InvalidVariableCount(usize),
-    /// Variable was 0, 1, empty string or string with leading or trailing whitespace.
-    #[error("0, 1, empty string and strings with leading or trailing whitespace are not allowed as variables.")]
-    InvalidVariable,
-    /// There were duplicate variables.
-    #[error("Duplicate variables are not allowed: {0:?}")]
-    DuplicateVariables(HashSet<String>),
-    /// There were terms out of bounds for the given number of variables.
-    #[error("Terms out of bounds: {:?} (expected < {} for {} variables)", offending_terms, 1 << variable_count, variable_count)]
-    TermOutOfBounds {
-        offending_terms: HashSet<u32>,
-        variable_count: usize,
-    },
-    /// There were conflicting terms between the given term sets.
-    #[error("Conflicting terms between term sets: {0:?}")]
-    TermConflict(HashSet<u32>),
-    /// Could not find the solution in time.
-    #[error("Could not find the solution in time.")]
-    Timeout,
+/// Minimizes the boolean function represented by the given `maxterms` and the don't cares for which
+/// `is_dont_care` returns `true`, like [`minimize_maxterms`].
+///
+/// See [`minimize_minterms_with`] for how `is_dont_care` is used and the errors returned.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // Whether the BCD digit is at least 5, where the codes above 9 are unused.
+/// let mut solutions = qmc::minimize_maxterms_with(
+///     &qmc::DEFAULT_VARIABLES[..4],
+///     &[0, 1, 2, 3, 4],
+///     |term| term > 9,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "(A ∨ B) ∧ (~B ∨ C ∨ D)"
+/// );
+/// ```
+pub fn minimize_maxterms_with<T: AsRef<str>>(
+    variables: &[T],
+    maxterms: &[u32],
+    is_dont_care: impl Fn(u32) -> bool,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    minimize_with_dont_care_predicate(
+        own_variables(variables),
+        maxterms,
+        is_dont_care,
+        POS,
+        find_all_solutions,
+        timeout,
+    )
 }
 
-fn minimize_internal_with_timeout(
-    variable_count: u32,
-    terms: HashSet<u32>,
-    dont_cares: HashSet<u32>,
-    form: Form,
+/// Minimizes the boolean function represented by the given `minterms` and the terms of the `dont_cares` cubes,
+/// like [`minimize_minterms`] but without listing the terms of the cubes.
+///
+/// The prime implicants are found from the ones of the `minterms` alone and the cubes, so the work depends on
+/// the number of cubes rather than on how many terms they contain. See [`Cube`] for the format of the cubes.
+///
+/// Returns [`Error::InvalidCube`] if a cube is malformed or doesn't have a character for each variable,
+/// [`Error::TermConflict`] if any of the `minterms` is in a cube, and the same errors as [`minimize_minterms`] otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // Whether the BCD digit is at least 5, where the codes above 9 are unused.
+/// let mut solutions = qmc::minimize_minterms_with_cubes(
+///     &qmc::DEFAULT_VARIABLES[..4],
+///     &[5, 6, 7, 8, 9],
+///     &["1-1-", "11--"],
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "A ∨ (B ∧ C) ∨ (B ∧ D)"
+/// );
+/// ```
+pub fn minimize_minterms_with_cubes<T: AsRef<str>, U: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    dont_cares: &[U],
     find_all_solutions: bool,
     timeout: Option<Duration>,
-) -> Result<Vec<Vec<Implicant>>, Error> {
-    let timeout = match timeout {
-        Some(timeout) => timeout,
-        None => {
-            return minimize_internal(
-                variable_count,
-                &terms,
-                &dont_cares,
-                form,
-                find_all_solutions,
-                &TimeoutSignalNoOp,
-            )
-        }
-    };
-
-    let (sender, receiver) = mpsc::channel();
+) -> Result<Vec<Cover>, Error> {
+    minimize_with_dont_care_cubes(
+        own_variables(variables),
+        minterms.iter().copied().collect(),
+        dont_cares,
+        SOP,
+        find_all_solutions,
+        timeout,
+    )
+}
 
-    let outer_timeout_signal = Arc::new(TimeoutSignalAtomicBool::default());
-    let timeout_signal = outer_timeout_signal.clone();
+/// Minimizes the boolean function represented by the given `maxterms` and the terms of the `dont_cares` cubes,
+/// like [`minimize_maxterms`] but without listing the terms of the cubes.
+///
+/// See [`minimize_minterms_with_cubes`] for how the cubes are used and the errors returned.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // Whether the BCD digit is at least 5, where the codes above 9 are unused.
+/// let mut solutions = qmc::minimize_maxterms_with_cubes(
+///     &qmc::DEFAULT_VARIABLES[..4],
+///     &[0, 1, 2, 3, 4],
+///     &["1-1-", "11--"],
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "(A ∨ B) ∧ (~B ∨ C ∨ D)"
+/// );
+/// ```
+pub fn minimize_maxterms_with_cubes<T: AsRef<str>, U: AsRef<str>>(
+    variables: &[T],
+    maxterms: &[u32],
+    dont_cares: &[U],
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    minimize_with_dont_care_cubes(
+        own_variables(variables),
+        maxterms.iter().copied().collect(),
+        dont_cares,
+        POS,
+        find_all_solutions,
+        timeout,
+    )
+}
 
-    let mut _worker_thread_builder = thread::Builder::new();
-    #[cfg(debug_assertions)]
-    {
-        _worker_thread_builder =
-            _worker_thread_builder.name("quine-mccluskey worker thread".into());
-    }
+/// Minimizes the boolean function with the given `outputs` of all terms in ascending order,
+/// where `None` is a don't care.
+///
+/// This is a shorthand for [`TruthTable::new`] followed by [`TruthTable::minimize`].
+/// Returns [`Error::InvalidOutputCount`] if the number of outputs is not 2 to the power of the number of variables,
+/// and the same errors as [`minimize`] otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let outputs = [Some(true), Some(false), None, Some(false), Some(false), Some(true), Some(false), None];
+///
+/// let mut solutions =
+///     qmc::minimize_outputs(&qmc::DEFAULT_VARIABLES[..3], &outputs, qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "(A ∧ C) ∨ (~A ∧ ~C)"
+/// );
+/// ```
+pub fn minimize_outputs<T: AsRef<str>>(
+    variables: &[T],
+    outputs: &[Option<bool>],
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    TruthTable::new(variables, outputs)?.minimize(form, find_all_solutions, timeout)
+}
 
-    let worker_thread = _worker_thread_builder
-        .spawn(move || {
-            sender
-                .send(minimize_internal(
-                    variable_count,
-                    &terms,
-                    &dont_cares,
-                    form,
-                    find_all_solutions,
-                    timeout_signal.as_ref(),
-                ))
-                .unwrap();
-        })
-        .expect("failed to spawn quine-mccluskey worker thread");
+/// Minimizes the boolean function whose output for each term is given by `oracle`.
+///
+/// `oracle` is called once for each of the 2^n terms in ascending order and returns `None` for don't cares.
+/// The rest of the arguments are the same as [`minimize`].
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // Whether the 3-bit number is a multiple of 3, or don't care for 0.
+/// let mut solutions = qmc::minimize_fn(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     |term| if term == 0 { None } else { Some(term % 3 == 0) },
+///     qmc::SOP,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "(A ∧ B ∧ ~C) ∨ (~A ∧ B ∧ C)"
+/// );
+/// ```
+pub fn minimize_fn<T: AsRef<str>>(
+    variables: &[T],
+    oracle: impl Fn(u32) -> Option<bool>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let variables = own_variables(variables);
 
-    let result = receiver.recv_timeout(timeout);
+    validate_variables(&variables)?;
 
-    outer_timeout_signal.signal();
-    worker_thread
-        .join()
-        .expect("failed to join quine-mccluskey worker thread");
+    let outputs = Vec::from_iter((0..1 << variables.len()).map(oracle));
 
-    result.unwrap()
+    minimize_outputs(&variables, &outputs, form, find_all_solutions, timeout)
 }
 
-fn minimize_internal(
-    variable_count: u32,
-    terms: &HashSet<u32>,
-    dont_cares: &HashSet<u32>,
-    form: Form,
-    find_all_solutions: bool,
-    timeout_signal: &impl TTimeoutSignal,
-) -> Result<Vec<Vec<Implicant>>, Error> {
-    let prime_implicants =
+/// Minimizes the boolean function whose minterms and don't cares are given as packed bits.
+///
+/// Bit `i % 64` of `on_set[i / 64]` is set if term `i` is a minterm, and likewise for `dc_set` and don't cares.
+/// The rest of the terms are maxterms, including the ones past the end of both slices.
+/// The rest of the arguments are the same as [`minimize`].
+///
+/// Returns [`Error::TermOutOfBounds`] if a bit is set for a term out of bounds,
+/// [`Error::TermConflict`] if a bit is set in both `on_set` and `dc_set`,
+/// and the same errors as [`minimize`] for invalid variables.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let mut solutions = qmc::minimize_from_bits(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0b0010_0001],
+///     &[0b1000_0100],
+///     qmc::SOP,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "(A ∧ C) ∨ (~A ∧ ~C)"
+/// );
+/// ```
+pub fn minimize_from_bits<T: AsRef<str>>(
+    variables: &[T],
+    on_set: &[u64],
+    dc_set: &[u64],
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let variables = own_variables(variables);
+
+    validate_variables(&variables)?;
+
+    let variable_count = variables.len() as u32;
+    let term_count = 1usize << variable_count;
+
+    let offending_terms: HashSet<u32> = unpack_terms(on_set.iter().copied())
+        .chain(unpack_terms(dc_set.iter().copied()))
+        .filter(|&term| term >= term_count)
+        .map(|term| u32::try_from(term).unwrap_or(u32::MAX))
+        .collect();
+
+    if !offending_terms.is_empty() {
+        return Err(Error::TermOutOfBounds {
+            offending_terms,
+            variable_count: variables.len(),
+        });
+    }
+
+    let conflicts: HashSet<u32> = unpack_terms(on_set.iter().zip(dc_set).map(|(on, dc)| on & dc))
+        .map(|term| term as u32)
+        .collect();
+
+    if !conflicts.is_empty() {
+        return Err(Error::TermConflict(conflicts));
+    }
+
+    let dont_cares: HashSet<u32> = unpack_terms(dc_set.iter().copied())
+        .map(|term| term as u32)
+        .collect();
+    let terms: HashSet<u32> = if form == SOP {
+        unpack_terms(on_set.iter().copied())
+            .map(|term| term as u32)
+            .collect()
+    } else {
+        // The terms that are neither minterms nor don't cares are the maxterms.
+        let word = |words: &[u64], i: usize| words.get(i).copied().unwrap_or(0);
+
+        unpack_terms((0..(term_count + 63) / 64).map(|i| !(word(on_set, i) | word(dc_set, i))))
+            .filter(|&term| term < term_count)
+            .map(|term| term as u32)
+            .collect()
+    };
+
+    let conversion = Conversion::new(&variables, form, &dont_cares, find_all_solutions);
+
+    let internal_solutions = minimize_internal_with_timeout(
+        variable_count,
+        terms,
+        dont_cares,
+        form,
+        find_all_solutions,
+        timeout,
+    )?;
+
+    Ok(conversion.to_covers(&internal_solutions))
+}
+
+/// Minimizes the boolean function whose specified terms are given as pairs of a term and its output.
+///
+/// The terms that don't appear in `terms` are don't cares, and a term may appear more than once with the same output.
+/// This avoids collecting the terms into separate minterms and maxterms first when they come from an iterator.
+/// The rest of the arguments are the same as [`minimize`].
+///
+/// Returns [`Error::TermConflict`] if a term appears with both outputs, and the same errors as [`minimize`] otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let terms = [(0, true), (1, false), (3, false), (4, false), (5, true), (6, false)];
+///
+/// let mut solutions =
+///     qmc::minimize_terms(&qmc::DEFAULT_VARIABLES[..3], terms, qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "(A ∧ C) ∨ (~A ∧ ~C)"
+/// );
+/// ```
+pub fn minimize_terms<T: AsRef<str>>(
+    variables: &[T],
+    terms: impl IntoIterator<Item = (u32, bool)>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let mut minterms = HashSet::new();
+    let mut maxterms = HashSet::new();
+
+    for (term, output) in terms {
+        if output {
+            minterms.insert(term);
+        } else {
+            maxterms.insert(term);
+        }
+    }
+
+    minimize_term_sets(
+        own_variables(variables),
+        minterms,
+        maxterms,
+        form,
+        find_all_solutions,
+        timeout,
+    )
+}
+
+/// Minimizes the boolean function whose specified terms are the keys of `outputs`, mapped to their outputs.
+///
+/// The terms that are not keys of `outputs` are don't cares, which suits sparse specifications.
+/// This is the same as [`minimize_terms`] with the entries of `outputs`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use quine_mccluskey as qmc;
+///
+/// let outputs = HashMap::from([(0, true), (5, true), (1, false), (3, false), (4, false), (6, false)]);
+///
+/// let mut solutions =
+///     qmc::minimize_map(&qmc::DEFAULT_VARIABLES[..3], &outputs, qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(
+///     solutions.pop().unwrap().to_string(),
+///     "(A ∧ C) ∨ (~A ∧ ~C)"
+/// );
+/// ```
+pub fn minimize_map<T: AsRef<str>>(
+    variables: &[T],
+    outputs: &HashMap<u32, bool>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    minimize_terms(
+        variables,
+        outputs.iter().map(|(&term, &output)| (term, output)),
+        form,
+        find_all_solutions,
+        timeout,
+    )
+}
+
+/// Minimizes the boolean function whose minterms and maxterms are given as ranges of terms,
+/// such as `128..256` for the upper half of the terms of 8 variables.
+///
+/// This suits functions with long runs of contiguous terms, like address decoders. The ranges are split into
+/// aligned cubes, so only the terms of the `minterms` in [`SOP`] form, or of the `maxterms` in [`POS`] form,
+/// are listed and the don't cares between the ranges are not listed at all.
+/// Single terms can be given as `term..term + 1`. The rest of the arguments are the same as [`minimize`].
+///
+/// Returns [`Error::TermOutOfBounds`] with the first out of bounds term of each offending range
+/// if a range exceeds the terms of the variables, and the same errors as [`minimize`] otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // Selects the addresses from 0x40 to 0x7F, with the addresses from 0xC0 unused.
+/// let mut solutions = qmc::minimize_ranges(
+///     &qmc::DEFAULT_VARIABLES[..8],
+///     &[0x40..0x80],
+///     &[0x00..0x40, 0x80..0xC0],
+///     qmc::SOP,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "B");
+/// ```
+pub fn minimize_ranges<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[Range<u32>],
+    maxterms: &[Range<u32>],
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let variables = own_variables(variables);
+
+    validate_variables(&variables)?;
+
+    let term_count = 1 << variables.len();
+    let terms_out_of_bounds: HashSet<u32> = minterms
+        .iter()
+        .chain(maxterms)
+        .filter(|range| !range.is_empty() && range.end > term_count)
+        .map(|range| range.start.max(term_count))
+        .collect();
+
+    if !terms_out_of_bounds.is_empty() {
+        return Err(Error::TermOutOfBounds {
+            offending_terms: terms_out_of_bounds,
+            variable_count: variables.len(),
+        });
+    }
+
+    let (care_ranges, other_ranges) = if form == SOP {
+        (minterms, maxterms)
+    } else {
+        (maxterms, minterms)
+    };
+    let conflicts: HashSet<u32> = care_ranges
+        .iter()
+        .flat_map(|care| {
+            other_ranges
+                .iter()
+                .flat_map(move |other| care.start.max(other.start)..care.end.min(other.end))
+        })
+        .collect();
+
+    if !conflicts.is_empty() {
+        return Err(Error::TermConflict(conflicts));
+    }
+
+    // Only the terms the solutions have to cover are listed, and the rest are given as cubes.
+    let terms = care_ranges.iter().cloned().flatten().collect();
+    let care_cubes = Vec::from_iter(care_ranges.iter().cloned().flat_map(range_cubes));
+    let mut ranges = Vec::from_iter(
+        minterms
+            .iter()
+            .chain(maxterms)
+            .filter(|range| !range.is_empty())
+            .cloned(),
+    );
+    ranges.sort_unstable_by_key(|range| range.start);
+
+    let mut dont_care_ranges = vec![];
+    let mut next_term = 0;
+
+    for range in ranges {
+        if range.start > next_term {
+            dont_care_ranges.push(next_term..range.start);
+        }
+
+        next_term = next_term.max(range.end);
+    }
+
+    if next_term < term_count {
+        dont_care_ranges.push(next_term..term_count);
+    }
+
+    minimize_with_cube_implicants(
+        variables,
+        terms,
+        Some(care_cubes),
+        Vec::from_iter(dont_care_ranges.into_iter().flat_map(range_cubes)),
+        form,
+        find_all_solutions,
+        timeout,
+    )
+}
+
+/// Splits the `range` of terms into the largest cubes whose terms are aligned runs of a power of two.
+fn range_cubes(range: Range<u32>) -> impl Iterator<Item = Implicant> {
+    let mut start = range.start;
+
+    std::iter::from_fn(move || {
+        if start >= range.end {
+            return None;
+        }
+
+        let mut size = 1u64 << start.trailing_zeros();
+
+        while u64::from(start) + size > u64::from(range.end) {
+            size >>= 1;
+        }
+
+        let cube = Implicant::from_parts(start, (size - 1) as u32);
+        start += size as u32;
+
+        Some(cube)
+    })
+}
+
+/// Minimizes the boolean function whose on-set is covered by the given `cover` of cubes, such as a cover
+/// produced by another tool, with the terms in the `dont_cares` cubes as don't cares.
+///
+/// The rest of the terms are maxterms. See [`Cube`] for the format of the cubes
+/// and [`minimize`] for the meaning of the rest of the arguments.
+///
+/// Returns [`Error::InvalidCube`] if a cube is malformed or doesn't have a character for each variable,
+/// [`Error::TermConflict`] if a term is in both `cover` and `dont_cares`, and the same errors as [`minimize`] otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let mut solutions = qmc::minimize_cover(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &["11-", "1-1", "-11", "0-1"],
+///     &[] as &[&str],
+///     qmc::SOP,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "C ∨ (A ∧ B)");
+/// ```
+pub fn minimize_cover<T: AsRef<str>, U: AsRef<str>, V: AsRef<str>>(
+    variables: &[T],
+    cover: &[U],
+    dont_cares: &[V],
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let variables = own_variables(variables);
+
+    validate_variables(&variables)?;
+
+    let minterms = Cube::expand(variables.len(), cover)?.into_iter().collect();
+    let dont_care_cubes = Cube::parse_all(variables.len(), dont_cares)?;
+
+    validate_dont_care_cubes(&minterms, &dont_care_cubes)?;
+
+    let terms = if form == SOP {
+        minterms
+    } else {
+        (0..1 << variables.len())
+            .filter(|term| {
+                !minterms.contains(term) && !dont_care_cubes.iter().any(|cube| cube.contains(*term))
+            })
+            .collect()
+    };
+
+    minimize_with_dont_care_cubes(
+        variables,
+        terms,
+        dont_cares,
+        form,
+        find_all_solutions,
+        timeout,
+    )
+}
+
+/// Minimizes the function specified jointly by the given partial specifications of the same variables.
+///
+/// See [`BooleanFunction::union`] for how the specifications are combined and
+/// [`minimize`] for the meaning of the rest of the arguments.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let specifications = [
+///     qmc::BooleanFunction::new(&["A", "B"], &[3], &[0]).unwrap(),
+///     qmc::BooleanFunction::new(&["A", "B"], &[2], &[0]).unwrap(),
+///     qmc::BooleanFunction::new(&["A", "B"], &[], &[1]).unwrap(),
+/// ];
+///
+/// let mut solutions = qmc::minimize_union(&specifications, qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "A");
+/// ```
+pub fn minimize_union(
+    specifications: &[BooleanFunction],
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    BooleanFunction::union(specifications)?.minimize(form, find_all_solutions, timeout)
+}
+
+/// Minimizes both the boolean function represented by the given `minterms` and `maxterms` and its complement
+/// in [`SOP`] form, as needed for differential logic, returning the solutions of the function and of the complement.
+///
+/// The don't cares are the same for both, so they are found once and so are the combinations of don't cares
+/// with each other when finding the prime implicants, which is the part of the work the two minimizations share.
+/// The solutions are the same as the ones of [`minimize`] in [`SOP`] form and, up to the order of the products,
+/// the [`complement`](Cover::complement)s of the ones in [`POS`] form.
+///
+/// See [`minimize`] for the meaning of the rest of the arguments. The `timeout` is for both minimizations together.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let (mut solutions, mut complement_solutions) = qmc::minimize_with_complement(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0, 5],
+///     &[1, 3, 4, 6],
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+/// assert_eq!(complement_solutions.pop().unwrap().to_string(), "(A ∧ ~C) ∨ (~A ∧ C)");
+/// ```
+pub fn minimize_with_complement<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<(Vec<Cover>, Vec<Cover>), Error> {
+    let variables = own_variables(variables);
+    let minterms = HashSet::from_iter(minterms.iter().copied());
+    let maxterms = HashSet::from_iter(maxterms.iter().copied());
+
+    validate_input(&variables, &minterms, &maxterms)?;
+
+    let variable_count = variables.len() as u32;
+    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+    let conversion = Conversion::new(&variables, SOP, &dont_cares, find_all_solutions);
+    let minimize_both = move |timeout_signal: &_| {
+        minimize_with_complement_internal(
+            variable_count,
+            &minterms,
+            &maxterms,
+            &dont_cares,
+            find_all_solutions,
+            timeout_signal,
+        )
+    };
+    let (internal_solutions, internal_complement_solutions) = match timeout {
+        Some(timeout) => run_with_timeout(timeout, minimize_both)?,
+        None => minimize_both(&TimeoutSignalAtomicBool::default())?,
+    };
+    Ok((
+        conversion.to_covers(&internal_solutions),
+        conversion.to_covers(&internal_complement_solutions),
+    ))
+}
+
+/// Minimizes the boolean function represented by the given `minterms` and `maxterms` within the given time `budget`,
+/// providing a usable solution as early as possible.
+///
+/// `callback` is called with a greedy solution as soon as it is found, and then with the exact solution
+/// if it is found within the budget and is better than the greedy one. This lets interactive tools
+/// show a result instantly and refine it. The greedy solution is found by expanding the terms into prime
+/// implicants one by one, before the exact search starts to find all of them.
+///
+/// Returns the best solution found, which is one of the solutions returned by [`minimize`]
+/// if the budget allows. Its [`Guarantees`] tell whether it is known to be minimum.
+/// Returns [`Error::Timeout`] if not even the greedy solution was found within the budget.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use quine_mccluskey as qmc;
+///
+/// let mut solutions = vec![];
+///
+/// let solution = qmc::minimize_anytime(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0, 5],
+///     &[1, 3, 4, 6],
+///     qmc::SOP,
+///     Duration::from_secs(10),
+///     |solution| solutions.push(solution.to_string()),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(solution.to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+/// assert_eq!(solutions.last().unwrap(), &solution.to_string());
+/// ```
+pub fn minimize_anytime<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: Form,
+    budget: Duration,
+    mut callback: impl FnMut(&Cover),
+) -> Result<Cover, Error> {
+    let deadline = Instant::now() + budget;
+    let variables = own_variables(variables);
+
+    let variable_count = variables.len();
+    let variable_count =
+        u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
+
+    let minterms = minterms.iter().copied().collect();
+    let maxterms = maxterms.iter().copied().collect();
+
+    validate_input(&variables, &minterms, &maxterms)?;
+
+    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+    let terms = if form == SOP { minterms } else { maxterms };
+
+    let conversion = Conversion::new(&variables, form, &dont_cares, false);
+    let (sender, receiver) = mpsc::channel();
+
+    let outer_timeout_signal = Arc::new(TimeoutSignalAtomicBool::default());
+    let timeout_signal = outer_timeout_signal.clone();
+
+    let worker_thread = spawn_worker_thread(move || {
+        // Errors are only caused by the timeout, after which no more solutions are expected.
+        let _ = minimize_anytime_internal(
+            variable_count,
+            &terms,
+            &dont_cares,
+            form,
+            timeout_signal.as_ref(),
+            |solution, optimality| {
+                let _ = sender.send((solution, optimality));
+            },
+        );
+    });
+
+    let mut best_solution: Option<Cover> = None;
+
+    while let Ok((solution, optimality)) =
+        receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+    {
+        match &mut best_solution {
+            Some(best_solution) if !is_better_solution(&solution, best_solution.implicants()) => {
+                // The best solution is as good as the exact one, so it is minimum too.
+                if optimality == Optimality::Minimum {
+                    best_solution.set_optimality(optimality);
+                }
+            }
+            _ => {
+                let mut solution = conversion.to_covers(&[solution]).remove(0);
+                solution.set_optimality(optimality);
+
+                callback(&solution);
+                best_solution = Some(solution);
+            }
+        }
+    }
+
+    outer_timeout_signal.signal();
+    worker_thread
+        .join()
+        .expect("failed to join quine-mccluskey worker thread");
+
+    best_solution.ok_or(Error::Timeout)
+}
+
+/// Minimizes the boolean function represented by the given `minterms` and `maxterms`
+/// only if its estimated worst-case work doesn't exceed `max_work`.
+///
+/// This is an alternative to a timeout for tools that must not depend on wall-clock time:
+/// the decision is made after finding the prime implicants, before the prime implicant chart is solved,
+/// and only depends on the input.
+/// Returns [`Error::ExceedsStaticBudget`] if the estimate given by [`estimate_work`] exceeds `max_work`.
+///
+/// See [`minimize`] for the rest of the arguments.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let result = qmc::minimize_bounded(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0, 5],
+///     &[1, 3, 4, 6],
+///     qmc::SOP,
+///     false,
+///     10_000,
+/// );
+/// assert_eq!(result.unwrap().pop().unwrap().to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+///
+/// let result = qmc::minimize_bounded(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0, 5],
+///     &[1, 3, 4, 6],
+///     qmc::SOP,
+///     false,
+///     4,
+/// );
+/// assert!(matches!(result, Err(qmc::Error::ExceedsStaticBudget { estimated_work: 5, .. })));
+/// ```
+pub fn minimize_bounded<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: Form,
+    find_all_solutions: bool,
+    max_work: u64,
+) -> Result<Vec<Cover>, Error> {
+    let variables = own_variables(variables);
+
+    let variable_count = variables.len();
+    let variable_count =
+        u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
+
+    let minterms = minterms.iter().copied().collect();
+    let maxterms = maxterms.iter().copied().collect();
+
+    validate_input(&variables, &minterms, &maxterms)?;
+
+    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+    let terms = if form == SOP { minterms } else { maxterms };
+
+    let prime_implicants = find_prime_implicants(
+        variable_count,
+        &terms,
+        &dont_cares,
+        form,
+        &TimeoutSignalNoOp,
+    )?;
+    let estimated_work = chart_work(&prime_implicants, &terms);
+
+    if estimated_work > max_work {
+        return Err(Error::ExceedsStaticBudget {
+            estimated_work,
+            max_work,
+        });
+    }
+
+    let conversion = Conversion::new(&variables, form, &dont_cares, find_all_solutions);
+
+    let (internal_solutions, _) = solve_prime_implicant_chart(
+        prime_implicants,
+        &terms,
+        &dont_cares,
+        form,
+        find_all_solutions,
+        &TimeoutSignalNoOp,
+    )?;
+
+    Ok(conversion.to_covers(&internal_solutions))
+}
+
+/// Minimizes the boolean function for estimated dynamic power instead of size.
+///
+/// `signal_probabilities` are the probabilities of each of the `variables` being 1, assumed to be independent.
+/// A product (or sum) is active when the input is in its cube, i.e. when the product outputs 1 or the sum outputs 0,
+/// and its switching activity is `2 * p * (1 - p)` where `p` is its activation probability. The returned solutions
+/// minimize the total switching activity of their products (or sums), with ties broken by the number of terms
+/// and literals, so they may be larger than the ones returned by [`minimize`].
+///
+/// Row and column dominance assume that all implicants cost the same, so prime implicant chart simplification
+/// is always skipped, which makes this as expensive as [`minimize`] with `find_all_solutions`.
+///
+/// Returns [`Error::InvalidProbabilities`] if there is not exactly one probability between 0 and 1 for each variable,
+/// and the same errors as [`minimize`] otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = &qmc::DEFAULT_VARIABLES[..3];
+/// let minterms = [0, 1, 2, 5, 6, 7];
+/// let maxterms = [3, 4];
+///
+/// let mut solutions =
+///     qmc::minimize_power_aware(variables, &minterms, &maxterms, qmc::SOP, &[0.9, 0.8, 0.5], None)
+///         .unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(A ∧ B) ∨ (~A ∧ ~C) ∨ (~B ∧ C)");
+/// ```
+pub fn minimize_power_aware<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: Form,
+    signal_probabilities: &[f64],
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let variables = own_variables(variables);
+
+    let variable_count = variables.len();
+    let variable_count =
+        u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
+
+    let minterms = minterms.iter().copied().collect();
+    let maxterms = maxterms.iter().copied().collect();
+
+    validate_input(&variables, &minterms, &maxterms)?;
+    validate_probabilities(&variables, signal_probabilities)?;
+
+    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+    let terms = if form == SOP { minterms } else { maxterms };
+    let signal_probabilities = signal_probabilities.to_vec();
+
+    let conversion = Conversion::new(&variables, form, &dont_cares, false);
+
+    let internal_solutions = run_with_optional_timeout(timeout, move |timeout_signal| {
+        minimize_power_aware_internal(
+            variable_count,
+            &terms,
+            &dont_cares,
+            form,
+            &signal_probabilities,
+            timeout_signal,
+        )
+    })?;
+
+    Ok(conversion.to_covers(&internal_solutions))
+}
+
+/// Minimizes the boolean function represented by the given `minterms` and `maxterms` like [`minimize`],
+/// also returning the decisions made while simplifying the prime implicant chart.
+///
+/// The log shows which implicants were extracted as essential and for which term,
+/// and which terms and implicants were removed by dominance, as evidence for how the solutions were derived.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let (mut solutions, audit_log) = qmc::minimize_audited(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0, 5],
+///     &[1, 3, 4, 6],
+///     qmc::SOP,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+/// assert_eq!(
+///     audit_log.events[0],
+///     qmc::AuditEvent::EssentialPrimeImplicant {
+///         implicant: "0-0".parse().unwrap(),
+///         term: 0,
+///     }
+/// );
+/// ```
+#[cfg(feature = "audit")]
+pub fn minimize_audited<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<(Vec<Cover>, AuditLog), Error> {
+    let variables = own_variables(variables);
+
+    let minterms = minterms.iter().copied().collect();
+    let maxterms = maxterms.iter().copied().collect();
+
+    validate_input(&variables, &minterms, &maxterms)?;
+
+    let variable_count = variables.len() as u32;
+    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+    let terms = if form == SOP { minterms } else { maxterms };
+
+    let conversion = Conversion::new(&variables, form, &dont_cares, find_all_solutions);
+
+    let (internal_solutions, reductions) = match timeout {
+        Some(timeout) => run_with_timeout(timeout, move |timeout_signal| {
+            minimize_audited_internal(
+                variable_count,
+                &terms,
+                &dont_cares,
+                form,
+                find_all_solutions,
+                timeout_signal,
+            )
+        })?,
+        None => minimize_audited_internal(
+            variable_count,
+            &terms,
+            &dont_cares,
+            form,
+            find_all_solutions,
+            &TimeoutSignalNoOp,
+        )?,
+    };
+
+    Ok((
+        conversion.to_covers(&internal_solutions),
+        AuditLog::new(&reductions, variable_count),
+    ))
+}
+
+/// Minimizes the `new_specification` of a function, reusing as many products (or sums) of the `old_solution`
+/// as possible, e.g. to keep an engineering change to a deployed cover small.
+///
+/// The cost of a cover is its number of literals plus `change_penalty` for each product (or sum) that is not
+/// in the old solution, with ties broken by the number of terms. Products of the old solution that are still
+/// implicants of the new specification are candidates even if they are no longer prime. The returned solutions
+/// are in the form of the old solution and minimize the cost, so they may be larger than the ones returned by [`minimize`].
+/// With a `change_penalty` of 0, they have the fewest literals.
+///
+/// As with [`minimize_power_aware`], prime implicant chart simplification is always skipped.
+///
+/// Returns [`Error::MismatchedVariables`] if the old solution and the new specification don't have the same variables.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = &qmc::DEFAULT_VARIABLES[..3];
+/// let mut old_solutions = qmc::BooleanFunction::new(variables, &[1, 3, 4, 5], &[0, 2, 6, 7])
+///     .unwrap()
+///     .minimize(qmc::SOP, false, None).unwrap();
+/// let old_solution = old_solutions.pop().unwrap();
+///
+/// assert_eq!(old_solution.to_string(), "(A ∧ ~B) ∨ (~A ∧ C)");
+///
+/// // Term 7 becomes a don't care.
+/// let new_specification = qmc::BooleanFunction::new(variables, &[1, 3, 4, 5], &[0, 2, 6]).unwrap();
+///
+/// let mut solutions = qmc::reminimize_preserving(&old_solution, &new_specification, 0, None).unwrap();
+/// assert_eq!(solutions.pop().unwrap().to_string(), "C ∨ (A ∧ ~B)");
+///
+/// let mut solutions = qmc::reminimize_preserving(&old_solution, &new_specification, 10, None).unwrap();
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(A ∧ ~B) ∨ (~A ∧ C)");
+/// ```
+pub fn reminimize_preserving(
+    old_solution: &Cover,
+    new_specification: &BooleanFunction,
+    change_penalty: u32,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    if old_solution.variables() != new_specification.variables() {
+        return Err(Error::MismatchedVariables {
+            expected: old_solution.variables().to_vec(),
+            found: new_specification.variables().to_vec(),
+        });
+    }
+
+    let variables = old_solution.variables().to_vec();
+    let variable_count = variables.len() as u32;
+    let form = old_solution.form();
+    let old_implicants = old_solution.implicants().to_vec();
+    let dont_cares = new_specification.dont_cares();
+    let terms = if form == SOP {
+        new_specification.minterms().clone()
+    } else {
+        new_specification.maxterms().clone()
+    };
+
+    let conversion = Conversion::new(&variables, form, &dont_cares, false);
+
+    let internal_solutions = run_with_optional_timeout(timeout, move |timeout_signal| {
+        reminimize_preserving_internal(
+            variable_count,
+            &terms,
+            &dont_cares,
+            form,
+            &old_implicants,
+            change_penalty,
+            timeout_signal,
+        )
+    })?;
+
+    Ok(conversion.to_covers(&internal_solutions))
+}
+
+/// Returns the estimated worst-case work of selecting the solutions of the `function` in the given `form`
+/// from its prime implicant chart.
+///
+/// The chart has a row for each prime implicant and a column for each term to cover (minterm in [`SOP`] form
+/// and maxterm in [`POS`] form). The estimate is the number of cells of the chart, which bounds the work of
+/// reducing it, plus the product of the number of prime implicants covering each column, which bounds the number
+/// of combinations Petrick's method can expand. Columns covered by a single prime implicant don't add to the product.
+/// Operations saturate at [`u64::MAX`].
+///
+/// The prime implicants are found to build the chart, which is not counted. The estimate only depends on the
+/// function, so limits can be checked without relying on wall-clock time. It is used by [`minimize_bounded`].
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // A single prime implicant ~B covers all 128 minterms.
+/// let minterms = Vec::from_iter((0..64).chain(128..192));
+/// let maxterms = Vec::from_iter((64..128).chain(192..256));
+/// let function = qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..8], &minterms, &maxterms).unwrap();
+///
+/// assert_eq!(qmc::estimate_work(&function, qmc::SOP), 128 + 1);
+/// ```
+pub fn estimate_work(function: &BooleanFunction, form: Form) -> u64 {
+    let variable_count = function.variables().len() as u32;
+    let terms = if form == SOP {
+        function.minterms()
+    } else {
+        function.maxterms()
+    };
+    let prime_implicants = find_prime_implicants(
+        variable_count,
+        terms,
+        &function.dont_cares(),
+        form,
+        &TimeoutSignalNoOp,
+    )
+    .expect("finding prime implicants without a timeout can't fail");
+
+    chart_work(&prime_implicants, terms)
+}
+
+/// Returns the estimated work of the prime implicant chart of the `prime_implicants` and `terms`,
+/// see [`estimate_work`].
+fn chart_work(prime_implicants: &[Implicant], terms: &HashSet<u32>) -> u64 {
+    let cells = (prime_implicants.len() as u64).saturating_mul(terms.len() as u64);
+    let combinations = terms.iter().fold(1u64, |combinations, &term| {
+        let choices = prime_implicants
+            .iter()
+            .filter(|implicant| implicant.contains(term))
+            .count();
+
+        combinations.saturating_mul(choices as u64)
+    });
+
+    cells.saturating_add(combinations)
+}
+
+/// Returns the value of each of the `variables` in the given `term`.
+///
+/// This is the numbering convention used by all functions of the crate:
+/// the first variable is the most significant bit of a term and the last variable is the least significant bit.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// assert_eq!(
+///     qmc::term_assignment(&["A", "B", "C"], 6).unwrap(),
+///     [("A", true), ("B", true), ("C", false)]
+/// );
+/// ```
+pub fn term_assignment<T: AsRef<str>>(
+    variables: &[T],
+    term: u32,
+) -> Result<Vec<(&str, bool)>, Error> {
+    validate_input(
+        &own_variables(variables),
+        &HashSet::from([term]),
+        &HashSet::new(),
+    )?;
+
+    Ok(variables
+        .iter()
+        .enumerate()
+        .map(|(i, variable)| {
+            let bit = variables.len() - i - 1;
+            (variable.as_ref(), (term >> bit) & 1 == 1)
+        })
+        .collect())
+}
+
+/// The form of a boolean expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Form {
+    /// Sum of Products
+    SOP,
+    /// Product of Sums
+    POS,
+}
+
+/// The kind of expression a [`Cover`] represents.
+///
+/// Minimization currently only produces two-level expressions, but other kinds of expressions
+/// may be added without changing [`Form`], so matching on it should have a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum OutputKind {
+    /// A two-level expression of the given form.
+    TwoLevel(Form),
+}
+
+impl OutputKind {
+    /// Returns the form of the expression if it is a two-level expression.
+    pub fn form(&self) -> Option<Form> {
+        match self {
+            OutputKind::TwoLevel(form) => Some(*form),
+        }
+    }
+}
+
+impl From<Form> for OutputKind {
+    fn from(form: Form) -> Self {
+        OutputKind::TwoLevel(form)
+    }
+}
+
+/// All letters of the English alphabet in uppercase.
+pub static DEFAULT_VARIABLES: [&str; 26] = [
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S",
+    "T", "U", "V", "W", "X", "Y", "Z",
+];
+
+/// Error types for bad input and timeout.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Error {
+    /// The number of variables was less than 1 or greater than `DEFAULT_VARIABLES.len()`.
+    InvalidVariableCount(usize),
+    /// Variable was 0, 1, empty string or string with leading or trailing whitespace.
+    InvalidVariable,
+    /// There were duplicate variables.
+    ///
+    /// The positions at which each of them appears are returned by
+    /// [`VariableSet::duplicate_positions`].
+    DuplicateVariables(HashSet<String>),
+    /// There were terms out of bounds for the given number of variables.
+    TermOutOfBounds {
+        offending_terms: HashSet<u32>,
+        variable_count: usize,
+    },
+    /// There were conflicting terms between the given term sets.
+    TermConflict(HashSet<u32>),
+    /// A cube was not a string of `0`, `1` and `-` characters of the expected length.
+    InvalidCube(String),
+    /// A cube list could not be encoded in or decoded from the binary interchange format, given with the reason.
+    InvalidCubeList(String),
+    /// A certificate did not assign all don't cares of the function.
+    IncompleteCertificate,
+    /// A certificate was created for a different function.
+    CertificateMismatch,
+    /// An external minimizer failed.
+    ExternalMinimizer(String),
+    /// The number of outputs of a truth table was not 2 to the power of the number of variables.
+    InvalidOutputCount(usize),
+    /// A truth table column contained a character other than `1`, `0`, `X`, `x` and `-`.
+    InvalidTruthTableColumn(String),
+    /// A hexadecimal truth table was malformed or didn't have a bit for each term.
+    InvalidHexTruthTable(String),
+    /// A truth table exported by a circuit simulator was malformed, given with the reason.
+    InvalidTableExport(String),
+    /// Functions that were expected to have the same variables had different ones.
+    MismatchedVariables {
+        expected: Vec<String>,
+        found: Vec<String>,
+    },
+    /// A variable was not part of the function.
+    UnknownVariable(String),
+    /// A variable was not given a value.
+    MissingVariable(String),
+    /// A boolean expression was malformed, given with the reason.
+    InvalidExpression(String),
+    /// A PLA file was malformed or used unsupported features, given with the reason.
+    InvalidPla(String),
+    /// A BLIF file was malformed or used unsupported features, given with the reason.
+    InvalidBlif(String),
+    /// A DIMACS CNF file was malformed, given with the reason.
+    InvalidDimacs(String),
+    /// There was not exactly one probability between 0 and 1 for each variable.
+    InvalidProbabilities(Vec<f64>),
+    /// There was not exactly one next-state equation for each state variable.
+    InvalidNextStateCount { expected: usize, found: usize },
+    /// A name could not be written in an export format, given with the reason.
+    UnsupportedName(String),
+    /// An expression was not in the form an export format requires, given with its form.
+    UnsupportedForm(Form),
+    /// A JSON file was malformed or did not have the expected contents, given with the reason.
+    InvalidJson(String),
+    /// A file could not be read or written, given with the error, which is also the source of this error.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Io(Arc<io::Error>),
+    /// The estimated worst-case work exceeded the given limit.
+    ExceedsStaticBudget { estimated_work: u64, max_work: u64 },
+    /// A caller-provided buffer was too small, given with the required length.
+    BufferTooSmall(usize),
+    /// Could not find the solution in time.
+    Timeout,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidVariableCount(variable_count) => write!(
+                f,
+                "Invalid variable count: {} (expected 1 <= variables.len() <= {})",
+                variable_count,
+                DEFAULT_VARIABLES.len()
+            ),
+            Error::InvalidVariable => write!(
+                f,
+                "0, 1, empty string and strings with leading or trailing whitespace are not allowed as variables."
+            ),
+            Error::DuplicateVariables(duplicates) => {
+                write!(f, "Duplicate variables are not allowed: {:?}", duplicates)
+            }
+            Error::TermOutOfBounds {
+                offending_terms,
+                variable_count,
+            } => write!(
+                f,
+                "Terms out of bounds: {:?} (expected < {} for {} variables)",
+                offending_terms,
+                1 << variable_count,
+                variable_count
+            ),
+            Error::TermConflict(conflicts) => {
+                write!(f, "Conflicting terms between term sets: {:?}", conflicts)
+            }
+            Error::InvalidCube(cube) => write!(f, "Invalid cube: {:?}", cube),
+            Error::InvalidCubeList(reason) => write!(f, "Invalid cube list: {}", reason),
+            Error::IncompleteCertificate => {
+                write!(f, "Certificate does not assign all don't cares.")
+            }
+            Error::CertificateMismatch => {
+                write!(f, "Certificate was created for a different function.")
+            }
+            Error::ExternalMinimizer(reason) => write!(f, "External minimizer failed: {}", reason),
+            Error::InvalidOutputCount(output_count) => write!(
+                f,
+                "Invalid output count: {} (expected 2^variables.len())",
+                output_count
+            ),
+            Error::InvalidTruthTableColumn(column) => {
+                write!(f, "Invalid truth table column: {:?}", column)
+            }
+            Error::InvalidHexTruthTable(hex) => {
+                write!(f, "Invalid hexadecimal truth table: {:?}", hex)
+            }
+            Error::InvalidTableExport(reason) => write!(f, "Invalid truth table export: {}", reason),
+            Error::MismatchedVariables { expected, found } => write!(
+                f,
+                "Mismatched variables: {:?} (expected {:?})",
+                found, expected
+            ),
+            Error::UnknownVariable(variable) => write!(f, "Unknown variable: {:?}", variable),
+            Error::MissingVariable(variable) => write!(f, "Missing value of variable: {:?}", variable),
+            Error::InvalidExpression(reason) => write!(f, "Invalid expression: {}", reason),
+            Error::InvalidPla(reason) => write!(f, "Invalid PLA: {}", reason),
+            Error::InvalidBlif(reason) => write!(f, "Invalid BLIF: {}", reason),
+            Error::InvalidDimacs(reason) => write!(f, "Invalid DIMACS: {}", reason),
+            Error::InvalidProbabilities(probabilities) => write!(
+                f,
+                "Invalid probabilities: {:?} (expected one probability in [0, 1] per variable)",
+                probabilities
+            ),
+            Error::InvalidNextStateCount { expected, found } => write!(
+                f,
+                "Invalid number of next-state equations: {} (expected {}, one per state variable)",
+                found, expected
+            ),
+            Error::UnsupportedName(reason) => write!(f, "Unsupported name: {}", reason),
+            Error::UnsupportedForm(form) => write!(f, "Unsupported form: {:?}", form),
+            Error::InvalidJson(reason) => write!(f, "Invalid JSON: {}", reason),
+            Error::Io(error) => write!(f, "I/O error: {}", error),
+            Error::ExceedsStaticBudget {
+                estimated_work,
+                max_work,
+            } => write!(
+                f,
+                "Estimated work {} exceeds the static budget {}",
+                estimated_work, max_work
+            ),
+            Error::BufferTooSmall(required) => {
+                write!(f, "Buffer too small: {} elements required", required)
+            }
+            Error::Timeout => write!(f, "Could not find the solution in time."),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+fn minimize_term_sets(
+    variables: Vec<String>,
+    minterms: HashSet<u32>,
+    maxterms: HashSet<u32>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let variable_count = variables.len();
+    let variable_count =
+        u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
+
+    validate_input(&variables, &minterms, &maxterms)?;
+
+    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+    let terms = if form == SOP { minterms } else { maxterms };
+
+    let conversion = Conversion::new(&variables, form, &dont_cares, find_all_solutions);
+
+    let internal_solutions = minimize_internal_with_timeout(
+        variable_count,
+        terms,
+        dont_cares,
+        form,
+        find_all_solutions,
+        timeout,
+    )?;
+
+    Ok(conversion.to_covers(&internal_solutions))
+}
+
+fn minimize_with_dont_care_predicate(
+    variables: Vec<String>,
+    terms: &[u32],
+    is_dont_care: impl Fn(u32) -> bool,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    validate_variables(&variables)?;
+
+    let variable_count = variables.len() as u32;
+    let terms: HashSet<u32> = terms.iter().copied().collect();
+    let mut dont_cares = HashSet::new();
+    let mut other_terms = HashSet::new();
+
+    for term in 0..1 << variable_count {
+        if is_dont_care(term) {
+            dont_cares.insert(term);
+        } else if !terms.contains(&term) {
+            other_terms.insert(term);
+        }
+    }
+
+    validate_input(&variables, &terms, &dont_cares)?;
+
+    let (minterms, maxterms) = if form == SOP {
+        (terms, other_terms)
+    } else {
+        (other_terms, terms)
+    };
+
+    minimize_term_sets(
+        variables,
+        minterms,
+        maxterms,
+        form,
+        find_all_solutions,
+        timeout,
+    )
+}
+
+fn minimize_with_dont_care_cubes<T: AsRef<str>>(
+    variables: Vec<String>,
+    terms: HashSet<u32>,
+    dont_cares: &[T],
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    validate_variables(&variables)?;
+
+    let dont_care_cubes = Cube::parse_all(variables.len(), dont_cares)?;
+
+    validate_input(&variables, &terms, &HashSet::new())?;
+    validate_dont_care_cubes(&terms, &dont_care_cubes)?;
+
+    minimize_with_cube_implicants(
+        variables,
+        terms,
+        None,
+        Vec::from_iter(dont_care_cubes.iter().map(Cube::implicant)),
+        form,
+        find_all_solutions,
+        timeout,
+    )
+}
+
+/// Minimizes the function whose care terms are the `terms`, which are also the terms of the `care_cubes`
+/// if they are given, and whose don't cares are the terms of the `dont_care_cubes`.
+fn minimize_with_cube_implicants(
+    variables: Vec<String>,
+    terms: HashSet<u32>,
+    care_cubes: Option<Vec<Implicant>>,
+    dont_care_cubes: Vec<Implicant>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let variable_count = variables.len() as u32;
+    let conversion = Conversion {
+        variables: &variables,
+        form,
+        all_dont_cares: covers_all_terms(&dont_care_cubes, variable_count),
+        find_all_solutions,
+    };
+
+    let internal_solutions = match timeout {
+        Some(timeout) => run_with_timeout(timeout, move |timeout_signal| {
+            minimize_with_cubes_internal(
+                variable_count,
+                &terms,
+                care_cubes,
+                dont_care_cubes,
+                form,
+                find_all_solutions,
+                timeout_signal,
+            )
+        })?,
+        None => minimize_with_cubes_internal(
+            variable_count,
+            &terms,
+            care_cubes,
+            dont_care_cubes,
+            form,
+            find_all_solutions,
+            &TimeoutSignalNoOp,
+        )?,
+    };
+
+    Ok(conversion.to_covers(&internal_solutions))
+}
+
+fn minimize_with_cubes_internal(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    care_cubes: Option<Vec<Implicant>>,
+    dont_care_cubes: Vec<Implicant>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Vec<Implicant>>, Error> {
+    let prime_implicants = find_prime_implicants_with_cubes(
+        variable_count,
+        terms,
+        care_cubes,
+        dont_care_cubes,
+        form,
+        timeout_signal,
+    )?;
+
+    let (solutions, _) = select_covers(
+        prime_implicants,
+        terms,
+        form,
+        find_all_solutions,
+        timeout_signal,
+    )?;
+
+    for solution in &solutions {
+        assert!(terms
+            .iter()
+            .all(|&term| solution.iter().any(|implicant| implicant.contains(term))));
+    }
+
+    Ok(solutions)
+}
+
+fn minimize_internal_with_timeout(
+    variable_count: u32,
+    terms: HashSet<u32>,
+    dont_cares: HashSet<u32>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Vec<Implicant>>, Error> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => {
+            return minimize_internal(
+                variable_count,
+                &terms,
+                &dont_cares,
+                form,
+                find_all_solutions,
+                &TimeoutSignalNoOp,
+            )
+        }
+    };
+
+    run_with_timeout(timeout, move |timeout_signal| {
+        minimize_internal(
+            variable_count,
+            &terms,
+            &dont_cares,
+            form,
+            find_all_solutions,
+            timeout_signal,
+        )
+    })
+}
+
+fn run_with_timeout<R: Send + 'static>(
+    timeout: Duration,
+    job: impl FnOnce(&TimeoutSignalAtomicBool) -> Result<R, Error> + Send + 'static,
+) -> Result<R, Error> {
+    let (sender, receiver) = mpsc::channel();
+
+    let outer_timeout_signal = Arc::new(TimeoutSignalAtomicBool::default());
+    let timeout_signal = outer_timeout_signal.clone();
+
+    let worker_thread = spawn_worker_thread(move || {
+        sender.send(job(timeout_signal.as_ref())).unwrap();
+    });
+
+    let result = receiver.recv_timeout(timeout);
+
+    outer_timeout_signal.signal();
+    worker_thread
+        .join()
+        .expect("failed to join quine-mccluskey worker thread");
+
+    result.unwrap_or(Err(Error::Timeout))
+}
+
+/// Runs `job` with the given `timeout` like [`run_with_timeout`], or on the current thread if there is none.
+fn run_with_optional_timeout<R: Send + 'static>(
+    timeout: Option<Duration>,
+    job: impl FnOnce(&TimeoutSignalAtomicBool) -> Result<R, Error> + Send + 'static,
+) -> Result<R, Error> {
+    match timeout {
+        Some(timeout) => run_with_timeout(timeout, job),
+        None => job(&TimeoutSignalAtomicBool::default()),
+    }
+}
+
+fn spawn_worker_thread(job: impl FnOnce() + Send + 'static) -> thread::JoinHandle<()> {
+    let mut _worker_thread_builder = thread::Builder::new();
+    #[cfg(debug_assertions)]
+    {
+        _worker_thread_builder =
+            _worker_thread_builder.name("quine-mccluskey worker thread".into());
+    }
+
+    _worker_thread_builder
+        .spawn(job)
+        .expect("failed to spawn quine-mccluskey worker thread")
+}
+
+fn minimize_anytime_internal(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    timeout_signal: &impl TTimeoutSignal,
+    mut report: impl FnMut(Vec<Implicant>, Optimality),
+) -> Result<(), Error> {
+    let mut greedy_solution = find_greedy_cover(variable_count, terms, dont_cares, timeout_signal)?;
+    greedy_solution.variable_sort(form);
+    assert!(check_solution(terms, dont_cares, &greedy_solution));
+
+    report(greedy_solution, Optimality::Heuristic);
+
+    let prime_implicants =
+        find_prime_implicants(variable_count, terms, dont_cares, form, timeout_signal)?;
+    let mut prime_implicant_chart = PrimeImplicantChart::new(prime_implicants, terms);
+
+    let essential_prime_implicants = prime_implicant_chart.simplify(false, timeout_signal)?;
+    let petrick_solution = Petrick::solve(&prime_implicant_chart, timeout_signal)?.swap_remove(0);
+
+    let mut solution = [essential_prime_implicants.as_slice(), &petrick_solution].concat();
+    solution.variable_sort(form);
+    assert!(check_solution(terms, dont_cares, &solution));
+
+    report(solution, Optimality::Minimum);
+
+    Ok(())
+}
+
+/// Covers the `terms` without finding all prime implicants, by expanding each term that is not covered yet
+/// into a prime implicant, one variable at a time while the implicant only contains terms and don't cares.
+fn find_greedy_cover(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Implicant>, Error> {
+    let mut cover: Vec<Implicant> = vec![];
+
+    for term in column_terms(terms) {
+        if cover.iter().any(|implicant| implicant.contains(term)) {
+            continue;
+        }
+
+        let mut implicant = Implicant::new(term);
+
+        for bit in (0..variable_count).rev().map(|i| 1 << i) {
+            if timeout_signal.is_signaled() {
+                return Err(Error::Timeout);
+            }
+
+            // The terms the implicant gains by freeing the variable.
+            let mirror = Implicant::from_parts(implicant.value() ^ bit, implicant.mask());
+
+            if mirror
+                .get_terms()
+                .iter()
+                .all(|term| terms.contains(term) || dont_cares.contains(term))
+            {
+                implicant = Implicant::from_parts(implicant.value(), implicant.mask() | bit);
+            }
+        }
+
+        cover.push(implicant);
+    }
+
+    Ok(cover)
+}
+
+fn minimize_power_aware_internal(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    signal_probabilities: &[f64],
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Vec<Implicant>>, Error> {
+    let prime_implicants =
+        find_prime_implicants(variable_count, terms, dont_cares, form, timeout_signal)?;
+
+    let get_cost = |solution: &[Implicant]| {
+        let switching_activity: f64 = solution
+            .iter()
+            .map(|implicant| {
+                let probability = implicant.probability(signal_probabilities);
+                2.0 * probability * (1.0 - probability)
+            })
+            .sum();
+        let wildcard_count: u32 = solution.iter().map(Implicant::wildcard_count).sum();
+
+        (switching_activity, solution.len(), Reverse(wildcard_count))
+    };
+
+    select_cheapest_covers(
+        prime_implicants,
+        terms,
+        dont_cares,
+        form,
+        get_cost,
+        timeout_signal,
+    )
+}
+
+fn reminimize_preserving_internal(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    old_implicants: &[Implicant],
+    change_penalty: u32,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Vec<Implicant>>, Error> {
+    let mut implicants =
+        find_prime_implicants(variable_count, terms, dont_cares, form, timeout_signal)?;
+
+    for old_implicant in old_implicants {
+        let old_terms = old_implicant.get_terms();
+        let is_implicant = old_terms
+            .iter()
+            .all(|term| terms.contains(term) || dont_cares.contains(term));
+        let covers_any_term = old_terms.iter().any(|term| terms.contains(term));
+
+        if is_implicant && covers_any_term && !implicants.contains(old_implicant) {
+            implicants.push(*old_implicant);
+        }
+    }
+
+    let get_cost = |solution: &[Implicant]| {
+        let cost: u64 = solution
+            .iter()
+            .map(|implicant| {
+                let literal_count = (variable_count - implicant.wildcard_count()) as u64;
+
+                if old_implicants.contains(implicant) {
+                    literal_count
+                } else {
+                    literal_count + change_penalty as u64
+                }
+            })
+            .sum();
+
+        (cost, solution.len())
+    };
+
+    select_cheapest_covers(
+        implicants,
+        terms,
+        dont_cares,
+        form,
+        get_cost,
+        timeout_signal,
+    )
+}
+
+/// Returns the covers of `terms` by the given `implicants` with the lowest cost as given by `get_cost`.
+fn select_cheapest_covers<C: PartialOrd>(
+    implicants: Vec<Implicant>,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    get_cost: impl Fn(&[Implicant]) -> C,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Vec<Implicant>>, Error> {
+    let mut prime_implicant_chart = PrimeImplicantChart::new(implicants, terms);
+    let essential_prime_implicants = prime_implicant_chart.simplify(true, timeout_signal)?;
+    let covers = Petrick::find_covers(&prime_implicant_chart, timeout_signal)?;
+
+    let mut solutions: Vec<Vec<Implicant>> = covers
+        .iter()
+        .map(|cover| [essential_prime_implicants.as_slice(), cover].concat())
+        .collect();
+    let min_cost = solutions
+        .iter()
+        .map(|solution| get_cost(solution))
+        .min_by(|cost1, cost2| cost1.partial_cmp(cost2).unwrap())
+        .unwrap();
+
+    solutions.retain(|solution| get_cost(solution) == min_cost);
+
+    for solution in &mut solutions {
+        if timeout_signal.is_signaled() {
+            return Err(Error::Timeout);
+        }
+
+        solution.variable_sort(form);
+        assert!(check_solution(terms, dont_cares, solution));
+    }
+
+    Ok(solutions)
+}
+
+fn is_better_solution(solution: &[Implicant], other_solution: &[Implicant]) -> bool {
+    let get_wildcard_count =
+        |solution: &[Implicant]| solution.iter().map(Implicant::wildcard_count).sum::<u32>();
+
+    solution.len() < other_solution.len()
+        || solution.len() == other_solution.len()
+            && get_wildcard_count(solution) > get_wildcard_count(other_solution)
+}
+
+fn minimize_internal(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Vec<Implicant>>, Error> {
+    minimize_internal_with_chart(
+        variable_count,
+        terms,
+        dont_cares,
+        form,
+        find_all_solutions,
+        timeout_signal,
+    )
+    .map(|(solutions, _)| solutions)
+}
+
+#[cfg(feature = "audit")]
+fn minimize_audited_internal(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<(Vec<Vec<Implicant>>, Vec<audit::Reduction>), Error> {
+    minimize_internal_with_chart(
+        variable_count,
+        terms,
+        dont_cares,
+        form,
+        find_all_solutions,
+        timeout_signal,
+    )
+    .map(|(solutions, chart)| (solutions, chart.reductions().to_vec()))
+}
+
+fn minimize_internal_with_chart(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<(Vec<Vec<Implicant>>, PrimeImplicantChart), Error> {
+    let prime_implicants =
         find_prime_implicants(variable_count, terms, dont_cares, form, timeout_signal)?;
-    let mut prime_implicant_chart = PrimeImplicantChart::new(prime_implicants, dont_cares);
+
+    solve_prime_implicant_chart(
+        prime_implicants,
+        terms,
+        dont_cares,
+        form,
+        find_all_solutions,
+        timeout_signal,
+    )
+}
+
+fn solve_prime_implicant_chart(
+    prime_implicants: Vec<Implicant>,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<(Vec<Vec<Implicant>>, PrimeImplicantChart), Error> {
+    let (solutions, prime_implicant_chart) = select_covers(
+        prime_implicants,
+        terms,
+        form,
+        find_all_solutions,
+        timeout_signal,
+    )?;
+
+    for solution in &solutions {
+        assert!(check_solution(terms, dont_cares, solution));
+    }
+
+    Ok((solutions, prime_implicant_chart))
+}
+
+/// Selects the covers of the `terms` by the `prime_implicants` like [`solve_prime_implicant_chart`]
+/// without checking them.
+fn select_covers(
+    prime_implicants: Vec<Implicant>,
+    terms: &HashSet<u32>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<(Vec<Vec<Implicant>>, PrimeImplicantChart), Error> {
+    let mut prime_implicant_chart = PrimeImplicantChart::new(prime_implicants, terms);
     let essential_prime_implicants =
         prime_implicant_chart.simplify(find_all_solutions, timeout_signal)?;
     let petrick_solutions = Petrick::solve(&prime_implicant_chart, timeout_signal)?;
@@ -432,10 +2410,30 @@ fn minimize_internal(
         }
 
         solution.variable_sort(form);
-        assert!(check_solution(terms, dont_cares, solution));
     }
 
-    Ok(solutions)
+    Ok((solutions, prime_implicant_chart))
+}
+
+fn find_prime_implicants_with_timeout(
+    variable_count: u32,
+    terms: HashSet<u32>,
+    dont_cares: HashSet<u32>,
+    form: Form,
+    timeout: Option<Duration>,
+) -> Result<Vec<Implicant>, Error> {
+    match timeout {
+        Some(timeout) => run_with_timeout(timeout, move |timeout_signal| {
+            find_prime_implicants(variable_count, &terms, &dont_cares, form, timeout_signal)
+        }),
+        None => find_prime_implicants(
+            variable_count,
+            &terms,
+            &dont_cares,
+            form,
+            &TimeoutSignalNoOp,
+        ),
+    }
 }
 
 fn find_prime_implicants(
@@ -450,9 +2448,7 @@ fn find_prime_implicants(
     let mut prime_implicants = vec![];
 
     while timeout_signal.is_not_signaled() {
-        let next_groups = (0..groups.len() - 1)
-            .map(|i| groups[i].combine(&groups[i + 1]))
-            .collect();
+        let next_groups = Group::combine_adjacent(&groups);
 
         let mut abort = false;
 
@@ -484,6 +2480,179 @@ fn find_prime_implicants(
     }
 }
 
+/// Finds the prime implicants of the function whose terms are the `terms` and the terms of the
+/// `dont_care_cubes`, keeping the ones that contain some of the `terms`.
+///
+/// The prime implicants of the `terms` alone, or the `care_cubes` containing exactly the `terms` if they are given,
+/// and the cubes together cover the function, so adding consensus implicants to them and removing the ones covered
+/// by others until there are no more to add gives all of its prime implicants, without listing the terms of the cubes.
+fn find_prime_implicants_with_cubes(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    care_cubes: Option<Vec<Implicant>>,
+    dont_care_cubes: Vec<Implicant>,
+    form: Form,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Implicant>, Error> {
+    let mut implicants = match care_cubes {
+        Some(care_cubes) => care_cubes,
+        None => {
+            find_prime_implicants(variable_count, terms, &HashSet::new(), form, timeout_signal)?
+        }
+    };
+    implicants.extend(dont_care_cubes);
+
+    let mut is_removed = vec![false; implicants.len()];
+
+    for y in 0..implicants.len() {
+        is_removed[y] = (0..implicants.len()).any(|other| {
+            other != y && !is_removed[other] && implicants[other].covers(implicants[y])
+        });
+    }
+
+    loop {
+        let mut any_added = false;
+        let mut y1 = 0;
+
+        while y1 < implicants.len() {
+            for y2 in 0..y1 {
+                if timeout_signal.is_signaled() {
+                    return Err(Error::Timeout);
+                }
+
+                if is_removed[y1] || is_removed[y2] {
+                    continue;
+                }
+
+                let consensus = match implicants[y1].consensus(implicants[y2]) {
+                    Some(consensus) => consensus,
+                    None => continue,
+                };
+
+                let is_covered = (0..implicants.len())
+                    .any(|y| !is_removed[y] && implicants[y].covers(consensus));
+
+                if !is_covered {
+                    for y in 0..implicants.len() {
+                        is_removed[y] |= consensus.covers(implicants[y]);
+                    }
+
+                    implicants.push(consensus);
+                    is_removed.push(false);
+                    any_added = true;
+                }
+            }
+
+            y1 += 1;
+        }
+
+        if !any_added {
+            break;
+        }
+    }
+
+    Ok(implicants
+        .into_iter()
+        .zip(is_removed)
+        .filter(|&(implicant, is_removed)| {
+            !is_removed && terms.iter().any(|&term| implicant.contains(term))
+        })
+        .map(|(implicant, _)| implicant)
+        .collect())
+}
+
+/// The implicants of each solution of a function.
+type InternalSolutions = Vec<Vec<Implicant>>;
+
+fn minimize_with_complement_internal(
+    variable_count: u32,
+    minterms: &HashSet<u32>,
+    maxterms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    find_all_solutions: bool,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<(InternalSolutions, InternalSolutions), Error> {
+    // The combinations of don't cares with each other are the same for both functions, so they are made once.
+    let mut shared_groups = vec![Group::group_terms(variable_count, dont_cares, SOP)];
+
+    while let Some(groups) = shared_groups.last().filter(|groups| groups.len() > 1) {
+        if timeout_signal.is_signaled() {
+            return Err(Error::Timeout);
+        }
+
+        let next_groups = Group::combine_adjacent(groups);
+
+        if next_groups.iter().all(Group::is_empty) {
+            break;
+        }
+
+        shared_groups.push(next_groups);
+    }
+
+    let solve = |terms| {
+        let prime_implicants = find_prime_implicants_with_shared(
+            variable_count,
+            terms,
+            &shared_groups,
+            dont_cares,
+            timeout_signal,
+        )?;
+
+        solve_prime_implicant_chart(
+            prime_implicants,
+            terms,
+            dont_cares,
+            SOP,
+            find_all_solutions,
+            timeout_signal,
+        )
+        .map(|(solutions, _)| solutions)
+    };
+
+    Ok((solve(minterms)?, solve(maxterms)?))
+}
+
+/// Finds the prime implicants of the `terms` and `dont_cares` in [`SOP`] form like [`find_prime_implicants`]
+/// given the groups of the combinations of the don't cares with each other in each round.
+fn find_prime_implicants_with_shared(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    shared_groups: &[Vec<Group>],
+    dont_cares: &HashSet<u32>,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Implicant>, Error> {
+    let mut groups = Group::group_terms(variable_count, terms, SOP);
+    let mut prime_implicants = vec![];
+
+    // Every implicant of `groups` contains one of the terms, and so do their combinations.
+    for round in 0.. {
+        if timeout_signal.is_signaled() {
+            return Err(Error::Timeout);
+        }
+
+        let shared = shared_groups.get(round).map_or(&[][..], Vec::as_slice);
+        let next_groups = if groups.len() > 1 {
+            Group::combine_adjacent_with_shared(&groups, shared)
+        } else {
+            vec![]
+        };
+
+        prime_implicants.extend(
+            groups
+                .iter()
+                .flat_map(|group| group.get_prime_implicants(dont_cares)),
+        );
+
+        if next_groups.iter().all(Group::is_empty) {
+            break;
+        }
+
+        groups = next_groups;
+    }
+
+    Ok(prime_implicants)
+}
+
 fn get_dont_cares(
     variable_count: u32,
     minterms: &HashSet<u32>,
@@ -495,6 +2664,63 @@ fn get_dont_cares(
     all_terms.difference(&cares).copied().collect()
 }
 
+/// Converts the internal solutions of a function into [`Cover`]s.
+///
+/// If every term is a don't care, the solutions get [`Warning::AllDontCares`], and if all solutions are requested,
+/// the other constant is added after the one without products (or sums).
+pub(crate) struct Conversion<'a> {
+    variables: &'a [String],
+    form: Form,
+    all_dont_cares: bool,
+    find_all_solutions: bool,
+}
+
+impl<'a> Conversion<'a> {
+    pub(crate) fn new(
+        variables: &'a [String],
+        form: Form,
+        dont_cares: &HashSet<u32>,
+        find_all_solutions: bool,
+    ) -> Self {
+        Conversion {
+            variables,
+            form,
+            all_dont_cares: dont_cares.len() == 1 << variables.len(),
+            find_all_solutions,
+        }
+    }
+
+    pub(crate) fn to_covers(&self, internal_solutions: &[Vec<Implicant>]) -> Vec<Cover> {
+        let mut solutions = Vec::from_iter(
+            internal_solutions
+                .iter()
+                .map(|solution| Cover::new(solution, self.variables, self.form)),
+        );
+
+        if self.all_dont_cares {
+            if self.find_all_solutions {
+                let mask = ((1u64 << self.variables.len()) - 1) as u32;
+
+                solutions.push(Cover::new(
+                    &[Implicant::from_parts(0, mask)],
+                    self.variables,
+                    self.form,
+                ));
+            }
+
+            for solution in &mut solutions {
+                solution.add_warning(Warning::AllDontCares);
+            }
+        }
+
+        solutions
+    }
+}
+
+fn to_enum_solutions(covers: Vec<Cover>) -> Vec<Solution> {
+    Vec::from_iter(covers.iter().map(Solution::from))
+}
+
 fn check_solution(terms: &HashSet<u32>, dont_cares: &HashSet<u32>, solution: &[Implicant]) -> bool {
     let covered_terms = solution.iter().flat_map(Implicant::get_terms).collect();
     let terms_with_dont_cares = terms.union(dont_cares).copied().collect();
@@ -502,6 +2728,23 @@ fn check_solution(terms: &HashSet<u32>, dont_cares: &HashSet<u32>, solution: &[I
     terms.is_subset(&covered_terms) && covered_terms.is_subset(&terms_with_dont_cares)
 }
 
+/// Returns the indices of the set bits of the packed `words` in ascending order.
+fn unpack_terms(words: impl IntoIterator<Item = u64>) -> impl Iterator<Item = usize> {
+    words.into_iter().enumerate().flat_map(|(i, mut word)| {
+        std::iter::from_fn(move || {
+            if word == 0 {
+                return None;
+            }
+
+            let bit = word.trailing_zeros() as usize;
+            word &= word - 1;
+
+            // Saturate so that bits past the end of the address space are still out of bounds.
+            Some(i.checked_mul(64).map_or(usize::MAX, |offset| offset + bit))
+        })
+    })
+}
+
 fn own_variables<T: AsRef<str>>(variables: &[T]) -> Vec<String> {
     variables
         .iter()
@@ -514,30 +2757,7 @@ fn validate_input(
     terms1: &HashSet<u32>,
     terms2: &HashSet<u32>,
 ) -> Result<(), Error> {
-    if variables.is_empty() || variables.len() > DEFAULT_VARIABLES.len() {
-        return Err(Error::InvalidVariableCount(variables.len()));
-    }
-
-    for variable in variables {
-        if variable == "0" || variable == "1" || variable.is_empty() || variable != variable.trim()
-        {
-            return Err(Error::InvalidVariable);
-        }
-    }
-
-    let mut duplicates = HashSet::new();
-
-    for i in 0..variables.len() {
-        for j in i + 1..variables.len() {
-            if variables[i] == variables[j] {
-                duplicates.insert(variables[i].clone());
-            }
-        }
-    }
-
-    if !duplicates.is_empty() {
-        return Err(Error::DuplicateVariables(duplicates));
-    }
+    validate_variables(variables)?;
 
     let all_terms: HashSet<u32> = terms1.union(terms2).copied().collect();
     let terms_out_of_bounds: HashSet<u32> = all_terms
@@ -561,6 +2781,67 @@ fn validate_input(
     Ok(())
 }
 
+fn validate_dont_care_cubes(terms: &HashSet<u32>, dont_care_cubes: &[Cube]) -> Result<(), Error> {
+    let conflicts: HashSet<u32> = terms
+        .iter()
+        .copied()
+        .filter(|&term| dont_care_cubes.iter().any(|cube| cube.contains(term)))
+        .collect();
+
+    if !conflicts.is_empty() {
+        return Err(Error::TermConflict(conflicts));
+    }
+
+    Ok(())
+}
+
+fn validate_probabilities(variables: &[String], probabilities: &[f64]) -> Result<(), Error> {
+    if probabilities.len() != variables.len()
+        || probabilities
+            .iter()
+            .any(|probability| !(0.0..=1.0).contains(probability))
+    {
+        return Err(Error::InvalidProbabilities(probabilities.to_vec()));
+    }
+
+    Ok(())
+}
+
+fn validate_variables(variables: &[String]) -> Result<(), Error> {
+    if variables.is_empty() || variables.len() > DEFAULT_VARIABLES.len() {
+        return Err(Error::InvalidVariableCount(variables.len()));
+    }
+
+    for variable in variables {
+        if variable == "0" || variable == "1" || variable.is_empty() || variable != variable.trim()
+        {
+            return Err(Error::InvalidVariable);
+        }
+    }
+
+    let duplicates = duplicate_positions(variables);
+
+    if !duplicates.is_empty() {
+        return Err(Error::DuplicateVariables(duplicates.into_keys().collect()));
+    }
+
+    Ok(())
+}
+
+fn duplicate_positions<T: AsRef<str>>(variables: &[T]) -> HashMap<String, Vec<usize>> {
+    let mut positions: HashMap<&str, Vec<usize>> = HashMap::new();
+
+    for (i, variable) in variables.iter().enumerate() {
+        positions.entry(variable.as_ref()).or_default().push(i);
+    }
+
+    positions
+        .into_iter()
+        .filter(|(_, positions)| positions.len() > 1)
+        .map(|(variable, positions)| (variable.to_owned(), positions))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
@@ -699,6 +2980,267 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_greedy_cover() {
+        for variable_count in 1..=5 {
+            let term_combinations = if variable_count <= 3 {
+                generate_terms_exhaustive(variable_count)
+            } else {
+                generate_terms_random(variable_count, 200)
+            };
+
+            for (minterms, maxterms) in &term_combinations {
+                let minterms = minterms.iter().copied().collect();
+                let maxterms = maxterms.iter().copied().collect();
+                let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+
+                for (terms, form) in [(&minterms, SOP), (&maxterms, POS)] {
+                    let prime_implicants = find_prime_implicants(
+                        variable_count,
+                        terms,
+                        &dont_cares,
+                        form,
+                        &TimeoutSignalNoOp,
+                    )
+                    .unwrap();
+
+                    let cover =
+                        find_greedy_cover(variable_count, terms, &dont_cares, &TimeoutSignalNoOp)
+                            .unwrap();
+
+                    assert!(check_solution(terms, &dont_cares, &cover));
+                    assert!(cover
+                        .iter()
+                        .all(|implicant| prime_implicants.contains(implicant)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_prime_implicants_with_shared() {
+        for variable_count in 1..=5 {
+            let term_combinations = if variable_count <= 3 {
+                generate_terms_exhaustive(variable_count)
+            } else {
+                generate_terms_random(variable_count, 200)
+            };
+
+            for (minterms, maxterms) in &term_combinations {
+                let minterms = minterms.iter().copied().collect();
+                let maxterms = maxterms.iter().copied().collect();
+                let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+                let shared_groups = (0..variable_count).fold(
+                    vec![Group::group_terms(variable_count, &dont_cares, SOP)],
+                    |mut shared_groups, _| {
+                        shared_groups.push(Group::combine_adjacent(shared_groups.last().unwrap()));
+                        shared_groups
+                    },
+                );
+
+                for terms in [&minterms, &maxterms] {
+                    let expected = find_prime_implicants(
+                        variable_count,
+                        terms,
+                        &dont_cares,
+                        SOP,
+                        &TimeoutSignalNoOp,
+                    )
+                    .unwrap();
+
+                    let result = find_prime_implicants_with_shared(
+                        variable_count,
+                        terms,
+                        &shared_groups,
+                        &dont_cares,
+                        &TimeoutSignalNoOp,
+                    )
+                    .unwrap();
+
+                    assert_eq!(
+                        result.into_iter().collect::<HashSet<_>>(),
+                        expected.into_iter().collect()
+                    );
+                }
+
+                let (solutions, complement_solutions) = minimize_with_complement_internal(
+                    variable_count,
+                    &minterms,
+                    &maxterms,
+                    &dont_cares,
+                    true,
+                    &TimeoutSignalNoOp,
+                )
+                .unwrap();
+
+                for (solutions, terms, form) in [
+                    (solutions, &minterms, SOP),
+                    (complement_solutions, &maxterms, POS),
+                ] {
+                    let expected = minimize_internal(
+                        variable_count,
+                        terms,
+                        &dont_cares,
+                        form,
+                        true,
+                        &TimeoutSignalNoOp,
+                    )
+                    .unwrap();
+
+                    assert_eq!(solutions.len(), expected.len());
+                    assert!(solutions.iter().all(|solution| check_solution(
+                        terms,
+                        &dont_cares,
+                        solution
+                    )));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let variables = own_variables(&["A", "B", "C"]);
+        let test = |implicants: &[&str], form, expected: &str, expected_irredundant| {
+            let implicants = Vec::from_iter(implicants.iter().map(|str| Implicant::from_str(str)));
+            let mut solution = Cover::new(&implicants, &variables, form);
+
+            assert_eq!(solution.normalize(), expected_irredundant);
+            assert_eq!(solution.to_string(), expected);
+            assert_eq!(
+                solution.guarantees().optimality,
+                if expected_irredundant {
+                    Optimality::Minimum
+                } else {
+                    Optimality::Heuristic
+                }
+            );
+        };
+
+        test(
+            &["11-", "11-", "111", "0-1"],
+            SOP,
+            "(A ∧ B) ∨ (~A ∧ C)",
+            true,
+        );
+        test(
+            &["11-", "0-1", "-11"],
+            SOP,
+            "(A ∧ B) ∨ (~A ∧ C) ∨ (B ∧ C)",
+            false,
+        );
+        test(&["1--", "11-", "---"], SOP, "1", true);
+        test(&["0--", "00-"], POS, "A", true);
+        test(&[], POS, "1", true);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_solution_record() {
+        use crate::solution::SolutionRecord;
+
+        let mut solution = BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+            .unwrap()
+            .minimize(SOP, false, None)
+            .unwrap()
+            .remove(0);
+        solution.annotate("source", "test");
+
+        let record = SolutionRecord::from(solution.clone());
+
+        assert_eq!(record.version, solution::SOLUTION_SCHEMA_VERSION);
+        assert_eq!(
+            Vec::from_iter(record.cubes.iter().map(ToString::to_string)),
+            ["11-", "0-1"]
+        );
+
+        let read = Cover::try_from(record).unwrap();
+
+        assert_eq!(read.to_string(), solution.to_string());
+        assert_eq!(read.annotation("source"), Some("test"));
+
+        let newer = SolutionRecord {
+            version: solution::SOLUTION_SCHEMA_VERSION + 1,
+            ..SolutionRecord::from(solution.clone())
+        };
+
+        assert!(Cover::try_from(newer).is_err());
+
+        let mismatched = SolutionRecord {
+            cubes: vec!["1-".parse().unwrap()],
+            ..SolutionRecord::from(solution.clone())
+        };
+
+        assert!(Cover::try_from(mismatched).is_err());
+
+        let variables = Vec::from_iter((0..27).map(|i| format!("x{}", i)));
+        let too_many = SolutionRecord {
+            variables: variables.clone(),
+            cubes: vec![],
+            ..SolutionRecord::from(solution.clone())
+        };
+
+        assert!(Cover::try_from(too_many).is_err());
+
+        let duplicate = SolutionRecord {
+            variables: own_variables(&["A", "B", "A"]),
+            ..SolutionRecord::from(solution.clone())
+        };
+
+        assert!(Cover::try_from(duplicate).is_err());
+
+        let invalid = SolutionRecord {
+            variables: own_variables(&["A", "1", "C"]),
+            ..SolutionRecord::from(solution)
+        };
+
+        assert!(Cover::try_from(invalid).is_err());
+
+        let too_many = Solution::SOP(vec![Vec::from_iter(
+            variables
+                .into_iter()
+                .map(|name| solution::Variable::new(name, false)),
+        )]);
+
+        assert!(Cover::try_from(too_many).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_solution_legacy_json() {
+        // Solutions as serialized by release 1.0.1.
+        let test = |json: &str, expected: &str, variables: &[&str]| {
+            let solution: Cover = serde_json::from_str(json).unwrap();
+
+            assert_eq!(solution.to_string(), expected);
+            assert_eq!(solution.variables(), variables);
+            assert_eq!(solution.guarantees(), Guarantees::unrecorded());
+
+            let round_trip: Cover =
+                serde_json::from_str(&serde_json::to_string(&solution).unwrap()).unwrap();
+
+            assert_eq!(round_trip.to_string(), expected);
+        };
+
+        test(
+            r#"{"SOP":[[{"name":"A","is_negated":false},{"name":"B","is_negated":false}],[{"name":"A","is_negated":true},{"name":"C","is_negated":false}]]}"#,
+            "(A ∧ B) ∨ (~A ∧ C)",
+            &["A", "B", "C"],
+        );
+        test(
+            r#"{"POS":[[{"name":"A","is_negated":false}],[{"name":"B","is_negated":true},{"name":"C","is_negated":false}]]}"#,
+            "A ∧ (~B ∨ C)",
+            &["A", "B", "C"],
+        );
+        test(r#""One""#, "1", &[]);
+        test(r#""Zero""#, "0", &[]);
+
+        assert!(serde_json::from_str::<Cover>(
+            r#"{"SOP":[[{"name":"A","is_negated":false},{"name":"A","is_negated":true}]]}"#
+        )
+        .is_err());
+    }
+
     fn minimize_and_print_solutions(
         variable_count: u32,
         minterms: &[u32],
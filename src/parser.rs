@@ -0,0 +1,367 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+use std::time::Duration;
+
+use crate::{own_variables, validate_variables, BooleanFunction, Cover, Error, Expr, Form};
+
+/// The maximum number of parentheses and negations an expression given to [`parse_expression`] can be nested in.
+pub const MAX_NESTING_DEPTH: usize = 256;
+
+/// Parses a boolean `expression` of the given `variables` and returns its minterms and maxterms in ascending order.
+///
+/// Variables are names of letters, digits and underscores, or any names in double quotes, where `"` and `\`
+/// are escaped with `\`. The expression can use the following operators from the highest to the lowest precedence,
+/// as well as parentheses and the constants `0` and `1`:
+///
+/// | Operator | Symbols         |
+/// |:--------:|:---------------:|
+/// | NOT      | `~`, `!`, `¬`   |
+/// | AND      | `&`, `*`, `∧`   |
+/// | XOR      | `^`, `⊕`        |
+/// | OR       | `\|`, `+`, `∨`  |
+///
+/// This accepts the output of [`Cover`](crate::Cover)'s `Display` implementation.
+///
+/// Returns [`Error::InvalidExpression`] if the expression is malformed or nested more than [`MAX_NESTING_DEPTH`]
+/// levels deep with parentheses and negations, [`Error::UnknownVariable`]
+/// if it uses a variable that is not one of the `variables`, and the same errors as [`minimize`](crate::minimize)
+/// for invalid variables.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["A", "B", "C"];
+/// let (minterms, maxterms) = qmc::parse_expression(&variables, "(A & ~B) | C").unwrap();
+///
+/// assert_eq!(minterms, [1, 3, 4, 5, 7]);
+/// assert_eq!(maxterms, [0, 2, 6]);
+///
+/// let mut solutions =
+///     qmc::minimize(&variables, &minterms, &maxterms, qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "C ∨ (A ∧ ~B)");
+/// ```
+pub fn parse_expression<T: AsRef<str>>(
+    variables: &[T],
+    expression: &str,
+) -> Result<(Vec<u32>, Vec<u32>), Error> {
+    let variables = own_variables(variables);
+
+    validate_variables(&variables)?;
+
+    let (node, variables) = parse(expression, variables, false)?;
+    let variable_count = variables.len() as u32;
+
+    Ok((0..1 << variable_count).partition(|&term| node.evaluate(term, variable_count)))
+}
+
+/// Parses a boolean `expression` of the given `variables` and minimizes it.
+///
+/// This is a shorthand for [`parse_expression`] followed by [`minimize`], see them for the meaning of the arguments
+/// and the errors returned.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let mut solutions = qmc::simplify_expression(
+///     &["A", "B", "C"],
+///     "A & B | A & ~B & C",
+///     qmc::SOP,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(A ∧ B) ∨ (A ∧ C)");
+/// ```
+pub fn simplify_expression<T: AsRef<str>>(
+    variables: &[T],
+    expression: &str,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Cover>, Error> {
+    let (minterms, maxterms) = parse_expression(variables, expression)?;
+
+    BooleanFunction::new(variables, &minterms, &maxterms)?.minimize(
+        form,
+        find_all_solutions,
+        timeout,
+    )
+}
+
+enum Node {
+    Constant(bool),
+    Variable(u32),
+    Not(Box<Node>),
+    And(Vec<Node>),
+    Xor(Vec<Node>),
+    Or(Vec<Node>),
+}
+
+impl Node {
+    fn evaluate(&self, term: u32, variable_count: u32) -> bool {
+        match self {
+            Node::Constant(value) => *value,
+            Node::Variable(index) => term >> (variable_count - 1 - index) & 1 == 1,
+            Node::Not(node) => !node.evaluate(term, variable_count),
+            Node::And(nodes) => nodes.iter().all(|node| node.evaluate(term, variable_count)),
+            Node::Xor(nodes) => nodes.iter().fold(false, |value, node| {
+                value != node.evaluate(term, variable_count)
+            }),
+            Node::Or(nodes) => nodes.iter().any(|node| node.evaluate(term, variable_count)),
+        }
+    }
+
+    fn to_expr(&self, variables: &[String]) -> Expr {
+        let to_exprs =
+            |nodes: &[Node]| Vec::from_iter(nodes.iter().map(|node| node.to_expr(variables)));
+
+        match self {
+            Node::Constant(value) => Expr::Const(*value),
+            Node::Variable(index) => Expr::Var(variables[*index as usize].clone()),
+            Node::Not(node) => Expr::Not(Box::new(node.to_expr(variables))),
+            Node::And(nodes) => Expr::And(to_exprs(nodes)),
+            Node::Xor(nodes) => Expr::Xor(to_exprs(nodes)),
+            Node::Or(nodes) => Expr::Or(to_exprs(nodes)),
+        }
+    }
+}
+
+/// Parses an `expression` of any variables as an [`Expr`].
+pub(crate) fn parse_expr(expression: &str) -> Result<Expr, Error> {
+    let (node, variables) = parse(expression, vec![], true)?;
+
+    Ok(node.to_expr(&variables))
+}
+
+/// Parses an `expression` of the given `variables`, adding the unknown ones to them if `add_variables` is set,
+/// and returns it with the variables.
+fn parse(
+    expression: &str,
+    variables: Vec<String>,
+    add_variables: bool,
+) -> Result<(Node, Vec<String>), Error> {
+    let mut parser = Parser {
+        variables,
+        add_variables,
+        chars: expression.char_indices().peekable(),
+        depth: 0,
+    };
+
+    let node = parser.parse_or()?;
+
+    if let Some(&(position, char)) = parser.peek() {
+        return Err(unexpected(char, position));
+    }
+
+    Ok((node, parser.variables))
+}
+
+struct Parser<'a> {
+    variables: Vec<String>,
+    /// Whether unknown variables are added to `variables` instead of being an error.
+    add_variables: bool,
+    chars: Peekable<CharIndices<'a>>,
+    /// The number of parentheses and negations the next node is nested in.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_or(&mut self) -> Result<Node, Error> {
+        let mut nodes = vec![self.parse_xor()?];
+
+        while self.take_if(&['|', '+', '∨']) {
+            nodes.push(self.parse_xor()?);
+        }
+
+        Ok(if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            Node::Or(nodes)
+        })
+    }
+
+    fn parse_xor(&mut self) -> Result<Node, Error> {
+        let mut nodes = vec![self.parse_and()?];
+
+        while self.take_if(&['^', '⊕']) {
+            nodes.push(self.parse_and()?);
+        }
+
+        Ok(if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            Node::Xor(nodes)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Node, Error> {
+        let mut nodes = vec![self.parse_not()?];
+
+        while self.take_if(&['&', '*', '∧']) {
+            nodes.push(self.parse_not()?);
+        }
+
+        Ok(if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            Node::And(nodes)
+        })
+    }
+
+    fn parse_not(&mut self) -> Result<Node, Error> {
+        if self.take_if(&['~', '!', '¬']) {
+            let node = self.nested(Parser::parse_not)?;
+
+            Ok(Node::Not(Box::new(node)))
+        } else {
+            self.parse_atom()
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, Error> {
+        let (position, char) = match self.peek() {
+            Some(&next) => next,
+            None => {
+                return Err(Error::InvalidExpression(
+                    "unexpected end of expression".to_owned(),
+                ))
+            }
+        };
+
+        if char == '(' {
+            self.chars.next();
+
+            let node = self.nested(Parser::parse_or)?;
+
+            return match self.peek() {
+                Some(&(_, ')')) => {
+                    self.chars.next();
+                    Ok(node)
+                }
+                Some(&(position, char)) => Err(unexpected(char, position)),
+                None => Err(Error::InvalidExpression(format!(
+                    "unclosed `(` at position {}",
+                    position
+                ))),
+            };
+        }
+
+        if char == '"' {
+            self.chars.next();
+
+            let name = self.parse_quoted_name(position)?;
+
+            return self.variable(name);
+        }
+
+        if !is_name_char(char) {
+            return Err(unexpected(char, position));
+        }
+
+        let mut name = String::new();
+
+        while let Some(&(_, char)) = self.chars.peek() {
+            if !is_name_char(char) {
+                break;
+            }
+
+            name.push(char);
+            self.chars.next();
+        }
+
+        match name.as_str() {
+            "0" => Ok(Node::Constant(false)),
+            "1" => Ok(Node::Constant(true)),
+            _ => self.variable(name),
+        }
+    }
+
+    /// Parses the rest of a name in double quotes that starts at `position`.
+    fn parse_quoted_name(&mut self, position: usize) -> Result<String, Error> {
+        let mut name = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(name),
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, char @ ('"' | '\\'))) => name.push(char),
+                    Some((position, char)) => return Err(unexpected(char, position)),
+                    None => break,
+                },
+                Some((_, char)) => name.push(char),
+                None => break,
+            }
+        }
+
+        Err(Error::InvalidExpression(format!(
+            "unclosed `\"` at position {}",
+            position
+        )))
+    }
+
+    /// Returns the node of the variable with the given `name`.
+    fn variable(&mut self, name: String) -> Result<Node, Error> {
+        match self.variables.iter().position(|variable| *variable == name) {
+            Some(index) => Ok(Node::Variable(index as u32)),
+            None if self.add_variables => {
+                self.variables.push(name);
+                Ok(Node::Variable(self.variables.len() as u32 - 1))
+            }
+            None => Err(Error::UnknownVariable(name)),
+        }
+    }
+
+    /// Parses a node with `parse` one level deeper.
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Node, Error>) -> Result<Node, Error> {
+        if self.depth == MAX_NESTING_DEPTH {
+            return Err(Error::InvalidExpression(format!(
+                "nested more than {} levels deep",
+                MAX_NESTING_DEPTH
+            )));
+        }
+
+        self.depth += 1;
+        let node = parse(self);
+        self.depth -= 1;
+
+        node
+    }
+
+    /// Returns the next character that is not whitespace without consuming it.
+    fn peek(&mut self) -> Option<&(usize, char)> {
+        while self
+            .chars
+            .peek()
+            .map_or(false, |(_, char)| char.is_whitespace())
+        {
+            self.chars.next();
+        }
+
+        self.chars.peek()
+    }
+
+    /// Consumes the next character that is not whitespace if it is one of the `chars`.
+    fn take_if(&mut self, chars: &[char]) -> bool {
+        match self.peek() {
+            Some((_, char)) if chars.contains(char) => {
+                self.chars.next();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+pub(crate) fn is_name_char(char: char) -> bool {
+    char.is_alphanumeric() || char == '_'
+}
+
+fn unexpected(char: char, position: usize) -> Error {
+    Error::InvalidExpression(format!("unexpected `{}` at position {}", char, position))
+}
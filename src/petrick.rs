@@ -8,10 +8,35 @@ impl Petrick {
         prime_implicant_chart: &PrimeImplicantChart,
         timeout_signal: &impl TTimeoutSignal,
     ) -> Result<Vec<Vec<Implicant>>, Error> {
-        let mut sums: Vec<SumOfProduct> = prime_implicant_chart
-            .get_column_covering_implicants()
-            .into_iter()
-            .map(SumOfProduct::new)
+        let candidates = Self::find_covers(prime_implicant_chart, timeout_signal)?;
+        let candidates = Self::filter_minimal_implicants(candidates);
+        Ok(Self::filter_minimal_literals(candidates))
+    }
+
+    /// Returns all irredundant covers of the prime implicant chart.
+    pub fn find_covers(
+        prime_implicant_chart: &PrimeImplicantChart,
+        timeout_signal: &impl TTimeoutSignal,
+    ) -> Result<Vec<Vec<Implicant>>, Error> {
+        let column_covering_implicants = prime_implicant_chart.get_column_covering_implicants();
+
+        // Products are bitsets over the indices of the implicants in ascending order.
+        let mut implicants = Vec::from_iter(column_covering_implicants.iter().flatten().copied());
+        implicants.sort_unstable();
+        implicants.dedup();
+
+        let word_count = (implicants.len() + 63) / 64;
+
+        let mut sums: Vec<SumOfProduct> = column_covering_implicants
+            .iter()
+            .map(|column| {
+                SumOfProduct::new(
+                    column
+                        .iter()
+                        .map(|implicant| implicants.binary_search(implicant).unwrap()),
+                    word_count,
+                )
+            })
             .collect();
 
         if sums.is_empty() {
@@ -39,9 +64,7 @@ impl Petrick {
         if timeout_signal.is_signaled() {
             Err(Error::Timeout)
         } else {
-            let candidates = sums.pop().unwrap().into();
-            let candidates = Self::filter_minimal_implicants(candidates);
-            Ok(Self::filter_minimal_literals(candidates))
+            Ok(sums.pop().unwrap().into_covers(&implicants))
         }
     }
 
@@ -119,9 +142,9 @@ struct SumOfProduct {
 }
 
 impl SumOfProduct {
-    pub fn new(implicants: Vec<Implicant>) -> Self {
+    pub fn new(ids: impl Iterator<Item = usize>, word_count: usize) -> Self {
         SumOfProduct {
-            products: implicants.into_iter().map(Product::new).collect(),
+            products: ids.map(|id| Product::new(id, word_count)).collect(),
         }
     }
 
@@ -173,37 +196,39 @@ impl SumOfProduct {
             Ok(())
         }
     }
-}
 
-impl From<SumOfProduct> for Vec<Vec<Implicant>> {
-    fn from(value: SumOfProduct) -> Self {
-        value
-            .products
+    /// Returns the implicants of each product, where `implicants` are indexed by the bits of the products.
+    fn into_covers(self, implicants: &[Implicant]) -> Vec<Vec<Implicant>> {
+        self.products
             .into_iter()
-            .map(|product| product.implicants)
+            .map(|product| product.ids().map(|id| implicants[id]).collect())
             .collect()
     }
 }
 
+/// A product of implicants as a bitset over their indices.
 #[derive(Clone)]
 struct Product {
-    implicants: Vec<Implicant>,
+    words: Vec<u64>,
 }
 
 impl Product {
-    pub fn new(implicant: Implicant) -> Self {
-        Product {
-            implicants: vec![implicant],
-        }
+    pub fn new(id: usize, word_count: usize) -> Self {
+        let mut words = vec![0; word_count];
+        words[id / 64] |= 1 << (id % 64);
+
+        Product { words }
     }
 
     pub fn and(&self, other: &Self) -> Self {
-        let mut implicants = [self.implicants.clone(), other.implicants.clone()].concat();
-
-        implicants.sort_unstable();
-        implicants.dedup();
-
-        Product { implicants }
+        Product {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(word, other_word)| word | other_word)
+                .collect(),
+        }
     }
 
     pub fn absorb(&self, other: &Self) -> Option<Self> {
@@ -217,21 +242,27 @@ impl Product {
     }
 
     fn is_subset(&self, other: &Self) -> bool {
-        for implicant in self.implicants.iter() {
-            let mut found = false;
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(word, other_word)| word & !other_word == 0)
+    }
 
-            for other_implicant in other.implicants.iter() {
-                if implicant == other_implicant {
-                    found = true;
-                    break;
+    /// Returns the indices of the implicants in ascending order.
+    fn ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
                 }
-            }
 
-            if !found {
-                return false;
-            }
-        }
+                let id = i * 64 + word.trailing_zeros() as usize;
+                word &= word - 1;
 
-        true
+                Some(id)
+            })
+        })
     }
 }
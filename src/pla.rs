@@ -0,0 +1,388 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    own_variables, validate_variables, BooleanFunction, Cover, Cube, Error, DEFAULT_VARIABLES, SOP,
+};
+
+/// A multi-output specification in the Berkeley PLA format used by espresso and two-level logic benchmarks.
+///
+/// The supported keywords are `.i`, `.o`, `.p`, `.ilb`, `.ob`, `.type` and `.e` (or `.end`), and comments start with `#`.
+/// Each of the other lines is a cube of the inputs followed by a character for each output,
+/// optionally separated by whitespace. The meaning of the output characters depends on the `.type`:
+///
+/// | Type         | `1`    | `0`     | `-` or `2`  | Rest of the terms |
+/// |:------------:|:------:|:-------:|:-----------:|:-----------------:|
+/// | `f`          | on-set | ignored | ignored     | off-set           |
+/// | `fd` (default) | on-set | ignored | don't care | off-set           |
+/// | `fr`         | on-set | off-set | ignored     | don't care        |
+/// | `fdr`        | on-set | off-set | don't care  | don't care        |
+/// | `r`          | ignored | off-set | ignored    | on-set            |
+///
+/// `~` and `3` are ignored for all types, and `4` is the same as `1`. Terms in the don't care set are don't cares
+/// even if they are also in the on-set or the off-set, as in espresso.
+/// Inputs without `.ilb` are named after [`DEFAULT_VARIABLES`] and outputs without `.ob` are named `F0`, `F1` and so on.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let pla: qmc::Pla = "\
+/// .i 3
+/// .o 2
+/// .ilb a b c
+/// .ob x y
+/// .p 3
+/// 1-1 10
+/// 0-0 10
+/// 11- 01
+/// .e
+/// "
+/// .parse()
+/// .unwrap();
+///
+/// assert_eq!(pla.inputs(), ["a", "b", "c"]);
+/// assert_eq!(pla.outputs(), ["x", "y"]);
+///
+/// let mut solutions = pla.functions()[0].minimize(qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(a ∧ c) ∨ (~a ∧ ~c)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Pla {
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    functions: Vec<BooleanFunction>,
+}
+
+impl Pla {
+    /// Returns the names of the inputs, which are the variables of the functions.
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// Returns the names of the outputs.
+    pub fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    /// Returns the function of each output.
+    pub fn functions(&self) -> &[BooleanFunction] {
+        &self.functions
+    }
+}
+
+impl FromStr for Pla {
+    type Err = Error;
+
+    /// Parses a PLA file.
+    ///
+    /// Returns [`Error::InvalidPla`] if the file is malformed or uses unsupported keywords,
+    /// [`Error::TermConflict`] if a term is in both the on-set and the off-set of an output and not a don't care,
+    /// and the same errors as [`minimize`](crate::minimize) for invalid input names.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut input_count = None;
+        let mut output_count = None;
+        let mut product_count = None;
+        let mut inputs = None;
+        let mut outputs = None;
+        let mut pla_type = PlaType::Fd;
+        let mut cubes = vec![];
+
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.split('#').next().unwrap().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if !line.starts_with('.') {
+                cubes.push((line_number, line));
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let keyword = words.next().unwrap();
+            let arguments = Vec::from_iter(words);
+            let count = || match arguments[..] {
+                [count] => count
+                    .parse::<usize>()
+                    .map_err(|_| invalid(line_number, format!("invalid count {:?}", arguments[0]))),
+                _ => Err(invalid(
+                    line_number,
+                    format!("expected one count after {}", keyword),
+                )),
+            };
+
+            match keyword {
+                ".i" => input_count = Some(count()?),
+                ".o" => output_count = Some(count()?),
+                ".p" => product_count = Some(count()?),
+                ".ilb" => inputs = Some(own_variables(&arguments)),
+                ".ob" => outputs = Some(own_variables(&arguments)),
+                ".type" => {
+                    pla_type = match arguments[..] {
+                        ["f"] => PlaType::F,
+                        ["fd"] => PlaType::Fd,
+                        ["fr"] => PlaType::Fr,
+                        ["fdr"] => PlaType::Fdr,
+                        ["r"] => PlaType::R,
+                        _ => {
+                            return Err(invalid(
+                                line_number,
+                                format!("unsupported type {:?}", arguments.join(" ")),
+                            ))
+                        }
+                    }
+                }
+                ".e" | ".end" => break,
+                _ => {
+                    return Err(invalid(
+                        line_number,
+                        format!("unsupported keyword {}", keyword),
+                    ))
+                }
+            }
+        }
+
+        let input_count = input_count.ok_or_else(|| invalid_pla("missing .i"))?;
+        let output_count = output_count.ok_or_else(|| invalid_pla("missing .o"))?;
+
+        let inputs = match inputs {
+            Some(inputs) if inputs.len() != input_count => {
+                return Err(invalid_pla(format!(
+                    ".ilb has {} names for {} inputs",
+                    inputs.len(),
+                    input_count
+                )))
+            }
+            Some(inputs) => inputs,
+            None if input_count > DEFAULT_VARIABLES.len() => {
+                return Err(Error::InvalidVariableCount(input_count))
+            }
+            None => own_variables(&DEFAULT_VARIABLES[..input_count]),
+        };
+
+        validate_variables(&inputs)?;
+
+        let outputs = match outputs {
+            Some(outputs) if outputs.len() != output_count => {
+                return Err(invalid_pla(format!(
+                    ".ob has {} names for {} outputs",
+                    outputs.len(),
+                    output_count
+                )))
+            }
+            Some(outputs) => outputs,
+            None => Vec::from_iter((0..output_count).map(|i| format!("F{}", i))),
+        };
+
+        if let Some(product_count) = product_count {
+            if product_count != cubes.len() {
+                return Err(invalid_pla(format!(
+                    ".p is {} but there are {} cubes",
+                    product_count,
+                    cubes.len()
+                )));
+            }
+        }
+
+        // The on-set, off-set and don't care set of each output.
+        let mut sets = vec![[HashSet::new(), HashSet::new(), HashSet::new()]; output_count];
+
+        for (line_number, line) in cubes {
+            let line = line.replace(char::is_whitespace, "");
+
+            if line.len() != input_count + output_count || !line.is_ascii() {
+                return Err(invalid(
+                    line_number,
+                    format!(
+                        "expected {} input and {} output characters",
+                        input_count, output_count
+                    ),
+                ));
+            }
+
+            let (cube, output_values) = line.split_at(input_count);
+            let terms = cube
+                .parse::<Cube>()
+                .map_err(|_| invalid(line_number, format!("invalid cube {:?}", cube)))?
+                .terms();
+
+            for (output_value, sets) in output_values.chars().zip(&mut sets) {
+                let set = match (output_value, pla_type) {
+                    ('1' | '4', PlaType::R) => None,
+                    ('1' | '4', _) => Some(0),
+                    ('0', PlaType::Fr | PlaType::Fdr | PlaType::R) => Some(1),
+                    ('-' | '2', PlaType::Fd | PlaType::Fdr) => Some(2),
+                    ('0' | '3' | '-' | '2' | '~', _) => None,
+                    _ => {
+                        return Err(invalid(
+                            line_number,
+                            format!("invalid output character {:?}", output_value),
+                        ))
+                    }
+                };
+
+                if let Some(set) = set {
+                    sets[set].extend(&terms);
+                }
+            }
+        }
+
+        let mut functions = Vec::with_capacity(output_count);
+
+        for [on_set, off_set, dc_set] in sets {
+            let minterms = match pla_type {
+                PlaType::R => {
+                    Vec::from_iter((0..1 << input_count).filter(|term| !off_set.contains(term)))
+                }
+                _ => Vec::from_iter(on_set.difference(&dc_set).copied()),
+            };
+            let maxterms = match pla_type {
+                PlaType::F | PlaType::Fd => Vec::from_iter(
+                    (0..1 << input_count)
+                        .filter(|term| !on_set.contains(term) && !dc_set.contains(term)),
+                ),
+                PlaType::Fr | PlaType::Fdr | PlaType::R => {
+                    Vec::from_iter(off_set.difference(&dc_set).copied())
+                }
+            };
+
+            functions.push(BooleanFunction::new(&inputs, &minterms, &maxterms)?);
+        }
+
+        Ok(Pla {
+            inputs,
+            outputs,
+            functions,
+        })
+    }
+}
+
+/// Returns the `solutions` as a multi-output PLA file, with the output of each solution named after `outputs`.
+///
+/// Cubes shared by several outputs are written once. Solutions in [`SOP`] form are written as the cubes of their
+/// on-sets, and solutions in [`POS`](crate::POS) form as the cubes of their off-sets with `.type r`,
+/// which [`Pla`] and espresso read back as the same functions.
+///
+/// Returns [`Error::InvalidPla`] if there are no solutions, the number of `outputs` is different from
+/// the number of solutions, or the solutions are not all in the same form,
+/// and [`Error::MismatchedVariables`] if the solutions don't all have the same variables.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["A", "B"];
+/// let solutions = [
+///     qmc::BooleanFunction::new(&variables, &[1, 3], &[0, 2])
+///         .unwrap()
+///         .minimize(qmc::SOP, false, None).unwrap().remove(0),
+///     qmc::BooleanFunction::new(&variables, &[1, 2, 3], &[0])
+///         .unwrap()
+///         .minimize(qmc::SOP, false, None).unwrap().remove(0),
+/// ];
+///
+/// assert_eq!(
+///     qmc::solutions_to_pla(&solutions, &["x", "y"]).unwrap(),
+///     ".i 2\n.o 2\n.ilb A B\n.ob x y\n.p 2\n-1 11\n1- 01\n.e\n"
+/// );
+/// ```
+pub fn solutions_to_pla<T: AsRef<str>>(
+    solutions: &[Cover],
+    outputs: &[T],
+) -> Result<String, Error> {
+    let first_solution = solutions
+        .first()
+        .ok_or_else(|| invalid_pla("no solutions"))?;
+
+    if outputs.len() != solutions.len() {
+        return Err(invalid_pla(format!(
+            "{} output names for {} solutions",
+            outputs.len(),
+            solutions.len()
+        )));
+    }
+
+    for solution in solutions {
+        if solution.variables() != first_solution.variables() {
+            return Err(Error::MismatchedVariables {
+                expected: first_solution.variables().to_vec(),
+                found: solution.variables().to_vec(),
+            });
+        }
+
+        if solution.form() != first_solution.form() {
+            return Err(invalid_pla("solutions are not all in the same form"));
+        }
+    }
+
+    Ok(write_pla(solutions, &own_variables(outputs)))
+}
+
+pub(crate) fn write_pla(solutions: &[Cover], outputs: &[String]) -> String {
+    let variables = solutions[0].variables();
+    let form = solutions[0].form();
+    let (is_in_output, is_not_in_output) = if form == SOP { ('1', '0') } else { ('0', '~') };
+
+    // The cubes in the order they first appear, with the output characters of each.
+    let mut cubes: Vec<(Cube, Vec<char>)> = vec![];
+    let mut cube_indices = HashMap::new();
+
+    for (i, solution) in solutions.iter().enumerate() {
+        for cube in solution.cubes() {
+            let index = *cube_indices.entry(cube).or_insert_with(|| {
+                cubes.push((cube, vec![is_not_in_output; solutions.len()]));
+                cubes.len() - 1
+            });
+
+            cubes[index].1[i] = is_in_output;
+        }
+    }
+
+    let mut pla = format!(
+        ".i {}\n.o {}\n.ilb {}\n.ob {}\n",
+        variables.len(),
+        outputs.len(),
+        variables.join(" "),
+        outputs.join(" ")
+    );
+
+    if form != SOP {
+        pla.push_str(".type r\n");
+    }
+
+    pla.push_str(&format!(".p {}\n", cubes.len()));
+
+    for (cube, output_values) in cubes {
+        pla.push_str(&format!("{} {}\n", cube, String::from_iter(output_values)));
+    }
+
+    pla.push_str(".e\n");
+    pla
+}
+
+#[derive(Clone, Copy)]
+enum PlaType {
+    F,
+    Fd,
+    Fr,
+    Fdr,
+    R,
+}
+
+fn invalid(line_number: usize, reason: String) -> Error {
+    Error::InvalidPla(format!("line {}: {}", line_number, reason))
+}
+
+fn invalid_pla(reason: impl Into<String>) -> Error {
+    Error::InvalidPla(reason.into())
+}
@@ -1,9 +1,11 @@
 // See the paper "Minimization of Boolean expressions using matrix algebra"
 
+#[cfg(feature = "audit")]
+use crate::audit::Reduction;
 use crate::implicant::Implicant;
 use crate::timeout_signal::TTimeoutSignal;
 use crate::Error;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 pub struct PrimeImplicantChart {
     implicants: Vec<Implicant>,
@@ -11,44 +13,39 @@ pub struct PrimeImplicantChart {
     terms: Vec<u32>,
     cols: Vec<Vec<bool>>,
     essential_prime_implicants: Vec<Implicant>,
+    #[cfg(feature = "audit")]
+    reductions: Vec<Reduction>,
 }
 
 impl PrimeImplicantChart {
-    pub fn new(implicants: Vec<Implicant>, dont_cares: &HashSet<u32>) -> Self {
-        let mut terms = HashSet::new();
-
-        for implicant in &implicants {
-            terms.extend(implicant.get_terms());
-        }
-
-        terms = terms.difference(dont_cares).copied().collect();
+    /// Creates the chart of the `implicants` over the care `terms`.
+    ///
+    /// The columns are the care terms renumbered densely as given by [`column_terms`], so building the chart
+    /// takes time proportional to the number of implicants and care terms regardless of the number of
+    /// don't cares the implicants contain.
+    pub fn new(implicants: Vec<Implicant>, terms: &HashSet<u32>) -> Self {
+        let terms = column_terms(terms);
 
         let mut rows = vec![vec![false; terms.len()]; implicants.len()];
         let mut cols = vec![vec![false; implicants.len()]; terms.len()];
 
-        let term_indices: HashMap<u32, usize> = terms
-            .iter()
-            .enumerate()
-            .map(|(i, &term)| (term, i))
-            .collect();
-
         for (y, implicant) in implicants.iter().enumerate() {
-            let row_terms = implicant.get_terms();
-            let row_terms = row_terms.difference(dont_cares);
-
-            for term in row_terms {
-                let x = *term_indices.get(term).unwrap();
-                rows[y][x] = true;
-                cols[x][y] = true;
+            for (x, &term) in terms.iter().enumerate() {
+                if implicant.contains(term) {
+                    rows[y][x] = true;
+                    cols[x][y] = true;
+                }
             }
         }
 
         PrimeImplicantChart {
             implicants,
             rows,
-            terms: Vec::from_iter(terms),
+            terms,
             cols,
             essential_prime_implicants: vec![],
+            #[cfg(feature = "audit")]
+            reductions: vec![],
         }
     }
 
@@ -88,6 +85,12 @@ impl PrimeImplicantChart {
         }
     }
 
+    /// Returns the decisions made by [`simplify`](Self::simplify) in the order they were made.
+    #[cfg(feature = "audit")]
+    pub fn reductions(&self) -> &[Reduction] {
+        &self.reductions
+    }
+
     pub fn get_column_covering_implicants(&self) -> Vec<Vec<Implicant>> {
         let mut column_covering_implicants = Vec::with_capacity(self.terms.len());
 
@@ -144,6 +147,21 @@ impl PrimeImplicantChart {
         let mut rows_to_extract = Vec::from_iter(rows_to_extract);
         rows_to_extract.sort_unstable();
 
+        #[cfg(feature = "audit")]
+        for &y in &rows_to_extract {
+            let x = (0..self.terms.len())
+                .find(|&x| {
+                    self.cols[x][y]
+                        && self.cols[x].iter().filter(|&&is_marked| is_marked).count() == 1
+                })
+                .unwrap();
+
+            self.reductions.push(Reduction::Essential {
+                implicant: self.implicants[y],
+                term: self.terms[x],
+            });
+        }
+
         let mut extracted_implicants = Vec::with_capacity(rows_to_extract.len());
         for y in rows_to_extract.into_iter().rev() {
             extracted_implicants.push(self.remove_row(y));
@@ -185,6 +203,12 @@ impl PrimeImplicantChart {
                 }
 
                 if is_dominating(&self.cols[x1], &self.cols[x2]) {
+                    #[cfg(feature = "audit")]
+                    self.reductions.push(Reduction::DominatingTerm {
+                        term: self.terms[x1],
+                        dominated_term: self.terms[x2],
+                    });
+
                     self.remove_col(x1);
                     removed = true;
                     #[cfg(test)]
@@ -224,6 +248,12 @@ impl PrimeImplicantChart {
                     // Only remove if it has more or an equal number of literals.
                     && self.implicants[y1].wildcard_count() <= self.implicants[y2].wildcard_count()
                 {
+                    #[cfg(feature = "audit")]
+                    self.reductions.push(Reduction::DominatedImplicant {
+                        implicant: self.implicants[y1],
+                        dominating_implicant: self.implicants[y2],
+                    });
+
                     self.remove_row(y1);
                     removed = true;
                     #[cfg(test)]
@@ -245,6 +275,7 @@ impl PrimeImplicantChart {
         }
     }
 
+    #[allow(clippy::unnecessary_sort_by)]
     fn sort(&mut self) {
         // Sort implicants to make the simplification deterministic.
         let mut sorted_implicants: Vec<_> = self.implicants.iter().zip(self.rows.clone()).collect();
@@ -302,3 +333,11 @@ fn is_dominating(marks: &[bool], other_marks: &[bool]) -> bool {
         .zip(other_marks)
         .all(|(&mark, other_mark)| !other_mark || mark)
 }
+
+/// Returns the terms of the columns of a [`PrimeImplicantChart`] over the given care `terms`,
+/// where column `i` is the `i`th term in ascending order.
+pub fn column_terms(terms: &HashSet<u32>) -> Vec<u32> {
+    let mut terms = Vec::from_iter(terms.iter().copied());
+    terms.sort_unstable();
+    terms
+}
@@ -0,0 +1,149 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BooleanFunction, CostModel, Cover, Error, SettingsProfile};
+
+/// A minimization problem that can be stored in a JSON file and shared between the Rust API and other tools.
+///
+/// Problems are read and written with their `serde` derive, so they can also be stored in any other
+/// `serde` data format. In JSON, a problem looks like this:
+///
+/// ```json
+/// {
+///   "variables": ["A", "B", "C"],
+///   "minterms": [0, 5],
+///   "maxterms": [1, 3, 4, 6],
+///   "options": {
+///     "form": "SOP",
+///     "find_all_solutions": false
+///   }
+/// }
+/// ```
+///
+/// The rest of the terms are don't cares, and missing `options` take their [`Default`] values
+/// as in [`SettingsProfile`].
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let problem: qmc::Problem = r#"{
+///     "variables": ["A", "B", "C"],
+///     "minterms": [0, 5],
+///     "maxterms": [1, 3, 4, 6]
+/// }"#
+/// .parse()
+/// .unwrap();
+///
+/// let mut solutions = problem.minimize().unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+/// assert_eq!(problem.to_json().unwrap().parse::<qmc::Problem>().unwrap(), problem);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Problem {
+    /// The variables of the function.
+    pub variables: Vec<String>,
+    /// The terms whose output is 1.
+    pub minterms: Vec<u32>,
+    /// The terms whose output is 0.
+    pub maxterms: Vec<u32>,
+    /// The settings of the minimization.
+    #[serde(default)]
+    pub options: SettingsProfile,
+}
+
+impl Problem {
+    /// Creates a problem of minimizing the `function` with the given `options`.
+    ///
+    /// The terms are in ascending order.
+    pub fn new(function: &BooleanFunction, options: SettingsProfile) -> Self {
+        let mut minterms = Vec::from_iter(function.minterms().iter().copied());
+        let mut maxterms = Vec::from_iter(function.maxterms().iter().copied());
+
+        minterms.sort_unstable();
+        maxterms.sort_unstable();
+
+        Problem {
+            variables: function.variables().to_vec(),
+            minterms,
+            maxterms,
+            options,
+        }
+    }
+
+    /// Returns the function of the problem.
+    ///
+    /// Returns the same errors as [`minimize`](crate::minimize) for invalid input.
+    pub fn function(&self) -> Result<BooleanFunction, Error> {
+        BooleanFunction::new(&self.variables, &self.minterms, &self.maxterms)
+    }
+
+    /// Minimizes the function of the problem with its options.
+    ///
+    /// Returns the same errors as [`SettingsProfile::minimize`].
+    pub fn minimize(&self) -> Result<Vec<Cover>, Error> {
+        self.options.minimize(&self.function()?)
+    }
+
+    /// Returns the problem as JSON.
+    ///
+    /// Returns [`Error::InvalidProbabilities`] if a signal probability of the options is not a finite number,
+    /// which JSON can't represent.
+    pub fn to_json(&self) -> Result<String, Error> {
+        if let CostModel::Power {
+            signal_probabilities,
+        } = &self.options.cost_model
+        {
+            if !signal_probabilities
+                .iter()
+                .all(|probability| probability.is_finite())
+            {
+                return Err(Error::InvalidProbabilities(signal_probabilities.clone()));
+            }
+        }
+
+        let json = serde_json::to_string_pretty(self).map_err(json_error)?;
+
+        Ok(format!("{}\n", json))
+    }
+
+    /// Reads a problem from the JSON file at `path`.
+    ///
+    /// Returns [`Error::Io`] if the file can't be read and [`Error::InvalidJson`] if it is not a valid problem.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        fs::read_to_string(path).map_err(io_error)?.parse()
+    }
+
+    /// Writes the problem to the JSON file at `path`.
+    ///
+    /// Returns [`Error::Io`] if the file can't be written and the same errors as [`to_json`](Self::to_json).
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        fs::write(path, self.to_json()?).map_err(io_error)
+    }
+}
+
+impl FromStr for Problem {
+    type Err = Error;
+
+    /// Parses a problem from JSON.
+    ///
+    /// Returns [`Error::InvalidJson`] if the JSON is malformed or is not a problem.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(json_error)
+    }
+}
+
+pub(crate) fn io_error(error: io::Error) -> Error {
+    Error::Io(Arc::new(error))
+}
+
+pub(crate) fn json_error(error: serde_json::Error) -> Error {
+    Error::InvalidJson(error.to_string())
+}
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{estimate_work, minimize_power_aware, BooleanFunction, Cover, Error, Form, SOP};
+
+/// What the solutions of a [`SettingsProfile`] minimize.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CostModel {
+    /// The number of products (or sums) and then the number of literals, as in [`minimize`](crate::minimize).
+    Size,
+    /// The estimated dynamic power for the given probabilities of each variable being 1,
+    /// as in [`minimize_power_aware`].
+    Power { signal_probabilities: Vec<f64> },
+}
+
+/// A named set of minimization settings that can be serialized and shared, e.g. as a file checked into a repository,
+/// so that related runs use the same vetted configuration.
+///
+/// Settings missing from a serialized profile take their [`Default`] values.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use quine_mccluskey as qmc;
+///
+/// let profile = qmc::SettingsProfile {
+///     name: "ci".to_owned(),
+///     timeout: Some(Duration::from_secs(5)),
+///     max_work: Some(10_000),
+///     ..Default::default()
+/// };
+///
+/// let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 5], &[1, 3, 4, 6]).unwrap();
+/// let mut solutions = profile.minimize(&function).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct SettingsProfile {
+    /// The name of the profile.
+    pub name: String,
+    /// The form of the solutions.
+    pub form: Form,
+    /// Whether to return all minimum solutions instead of one. Ignored by [`CostModel::Power`].
+    pub find_all_solutions: bool,
+    /// What the solutions minimize.
+    pub cost_model: CostModel,
+    /// The time limit of the minimization, see [`minimize`](crate::minimize).
+    pub timeout: Option<Duration>,
+    /// The limit of the estimated worst-case work, see [`minimize_bounded`](crate::minimize_bounded).
+    pub max_work: Option<u64>,
+}
+
+impl Default for SettingsProfile {
+    /// Returns a profile without a name or limits that minimizes the size of a single [`SOP`] solution.
+    fn default() -> Self {
+        SettingsProfile {
+            name: String::new(),
+            form: SOP,
+            find_all_solutions: false,
+            cost_model: CostModel::Size,
+            timeout: None,
+            max_work: None,
+        }
+    }
+}
+
+impl SettingsProfile {
+    /// Minimizes the `function` with the settings of the profile.
+    ///
+    /// Returns [`Error::ExceedsStaticBudget`] if the estimated worst-case work exceeds `max_work`,
+    /// [`Error::InvalidProbabilities`] if the probabilities of [`CostModel::Power`] don't match the variables,
+    /// and the same errors as [`minimize`](crate::minimize) otherwise.
+    pub fn minimize(&self, function: &BooleanFunction) -> Result<Vec<Cover>, Error> {
+        if let Some(max_work) = self.max_work {
+            let estimated_work = estimate_work(function, self.form);
+
+            if estimated_work > max_work {
+                return Err(Error::ExceedsStaticBudget {
+                    estimated_work,
+                    max_work,
+                });
+            }
+        }
+
+        match &self.cost_model {
+            CostModel::Size => function.minimize(self.form, self.find_all_solutions, self.timeout),
+            CostModel::Power {
+                signal_probabilities,
+            } => minimize_power_aware(
+                function.variables(),
+                &Vec::from_iter(function.minterms().iter().copied()),
+                &Vec::from_iter(function.maxterms().iter().copied()),
+                self.form,
+                signal_probabilities,
+                self.timeout,
+            ),
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    find_prime_implicants_with_timeout,
+    implicant::{Implicant, VariableSort},
+    karnaugh::{self, Layout},
+    prime_implicant_chart::column_terms,
+    BooleanFunction, Cover, Cube, Error, Form, ALGORITHM_REVISION, SOP,
+};
+
+/// A function with its prime implicants and minimized solutions, which can be exported as an HTML page.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 5], &[1, 3, 4, 6]).unwrap();
+/// let report = qmc::MinimizeReport::new(&function, qmc::SOP, true, None).unwrap();
+///
+/// assert_eq!(report.prime_implicants().len(), 2);
+/// assert_eq!(report.solutions()[0].to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+///
+/// let html = report.to_html();
+///
+/// assert!(html.starts_with("<!DOCTYPE html>"));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinimizeReport {
+    function: BooleanFunction,
+    form: Form,
+    prime_implicants: Vec<Cube>,
+    solutions: Vec<Cover>,
+}
+
+impl MinimizeReport {
+    /// The maximum number of variables for which [`to_html`](Self::to_html) includes the truth table.
+    pub const MAX_TRUTH_TABLE_VARIABLES: usize = 10;
+
+    /// Finds the prime implicants of the `function` and minimizes it.
+    ///
+    /// See [`minimize`](crate::minimize) for the meaning of the arguments.
+    /// The `timeout` applies to finding the prime implicants and minimizing separately.
+    pub fn new(
+        function: &BooleanFunction,
+        form: Form,
+        find_all_solutions: bool,
+        timeout: Option<Duration>,
+    ) -> Result<Self, Error> {
+        let variable_count = function.variables().len() as u32;
+        let terms = if form == SOP {
+            function.minterms().clone()
+        } else {
+            function.maxterms().clone()
+        };
+
+        let mut prime_implicants = find_prime_implicants_with_timeout(
+            variable_count,
+            terms,
+            function.dont_cares(),
+            form,
+            timeout,
+        )?;
+        prime_implicants.variable_sort(form);
+
+        Ok(MinimizeReport {
+            function: function.clone(),
+            form,
+            prime_implicants: prime_implicants
+                .into_iter()
+                .map(|implicant| Cube::from_implicant(implicant, variable_count))
+                .collect(),
+            solutions: function.minimize(form, find_all_solutions, timeout)?,
+        })
+    }
+
+    /// Returns the minimized function.
+    pub fn function(&self) -> &BooleanFunction {
+        &self.function
+    }
+
+    /// Returns the form of the solutions.
+    pub fn form(&self) -> Form {
+        self.form
+    }
+
+    /// Returns the cubes of the prime implicants of the function.
+    ///
+    /// These are the cubes of minterms in [`SOP`] form and maxterms in [`POS`](crate::POS) form,
+    /// as in [`Cover::cubes`].
+    pub fn prime_implicants(&self) -> &[Cube] {
+        &self.prime_implicants
+    }
+
+    /// Returns the terms of the columns of the prime implicant chart, where column `i` is the `i`th term.
+    ///
+    /// The chart only has columns for the care terms, i.e. the minterms in [`SOP`] form and the maxterms
+    /// in [`POS`](crate::POS) form, renumbered densely in ascending order, so its size doesn't depend on
+    /// the number of don't cares.
+    pub fn chart_terms(&self) -> Vec<u32> {
+        let terms = if self.form == SOP {
+            self.function.minterms()
+        } else {
+            self.function.maxterms()
+        };
+
+        column_terms(terms)
+    }
+
+    /// Returns the minimized solutions of the function.
+    pub fn solutions(&self) -> &[Cover] {
+        &self.solutions
+    }
+
+    /// Returns a self-contained HTML page with the truth table (for up to [`MAX_TRUTH_TABLE_VARIABLES`](Self::MAX_TRUTH_TABLE_VARIABLES)
+    /// variables), the Karnaugh map (for up to 6 variables), the prime implicant chart and the solutions
+    /// with their [`Guarantees`](crate::Guarantees).
+    ///
+    /// Hovering over a prime implicant in the chart or a product (or sum) of a solution highlights the terms
+    /// it covers in the truth table, the Karnaugh map and the chart. Without the truth table, only the
+    /// terms of the chart are highlighted, so the size of the page is bounded by the size of the chart.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+
+        html.push_str(concat!(
+            "<!DOCTYPE html>\n",
+            "<html>\n",
+            "<head>\n",
+            "<meta charset=\"utf-8\">\n",
+            "<title>Minimization report</title>\n",
+            "<style>\n",
+            "body { font-family: sans-serif; }\n",
+            "table { border-collapse: collapse; margin-bottom: 1em; }\n",
+            "th, td { border: 1px solid #999; padding: 0.2em 0.5em; text-align: center; }\n",
+            "[data-terms] { cursor: pointer; }\n",
+            "[data-terms]:hover { background: #cde; }\n",
+            ".highlight { background: #fd8; }\n",
+            "</style>\n",
+            "</head>\n",
+            "<body>\n",
+            "<h1>Minimization report</h1>\n",
+        ));
+
+        writeln!(html, "<p>Algorithm revision {}</p>", ALGORITHM_REVISION).unwrap();
+
+        let has_truth_table = self.variables().len() <= Self::MAX_TRUTH_TABLE_VARIABLES;
+        let chart_terms = self.chart_terms();
+
+        // The terms to highlight for each prime implicant and product (or sum) of a solution.
+        let mut highlighted_terms = HashMap::new();
+
+        for cube in self
+            .prime_implicants
+            .iter()
+            .copied()
+            .chain(self.solutions.iter().flat_map(Cover::cubes))
+        {
+            highlighted_terms.entry(cube).or_insert_with(|| {
+                let terms = if has_truth_table {
+                    cube.terms()
+                } else {
+                    Vec::from_iter(
+                        chart_terms
+                            .iter()
+                            .copied()
+                            .filter(|&term| cube.contains(term)),
+                    )
+                };
+
+                Vec::from_iter(terms.iter().map(u32::to_string)).join(" ")
+            });
+        }
+
+        if has_truth_table {
+            self.write_truth_table(&mut html);
+        }
+
+        if self.variables().len() <= karnaugh::MAX_VARIABLES {
+            self.write_karnaugh_map(&mut html);
+        }
+
+        self.write_prime_implicant_chart(&mut html, &chart_terms, &highlighted_terms);
+        self.write_solutions(&mut html, &highlighted_terms);
+
+        html.push_str(concat!(
+            "<script>\n",
+            "document.querySelectorAll(\"[data-terms]\").forEach(function (element) {\n",
+            "  var terms = element.dataset.terms.split(\" \");\n",
+            "  function highlight(isOn) {\n",
+            "    terms.forEach(function (term) {\n",
+            "      document.querySelectorAll('[data-term=\"' + term + '\"]').forEach(function (cell) {\n",
+            "        cell.classList.toggle(\"highlight\", isOn);\n",
+            "      });\n",
+            "    });\n",
+            "  }\n",
+            "  element.addEventListener(\"mouseenter\", function () { highlight(true); });\n",
+            "  element.addEventListener(\"mouseleave\", function () { highlight(false); });\n",
+            "});\n",
+            "</script>\n",
+            "</body>\n",
+            "</html>\n",
+        ));
+
+        html
+    }
+
+    fn write_truth_table(&self, html: &mut String) {
+        let variable_count = self.variables().len();
+
+        html.push_str("<h2>Truth table</h2>\n<table>\n<tr>");
+
+        for variable in self.variables() {
+            write!(html, "<th>{}</th>", escape(variable)).unwrap();
+        }
+
+        html.push_str("<th>Output</th></tr>\n");
+
+        for term in 0..1u32 << variable_count {
+            write!(html, "<tr data-term=\"{}\">", term).unwrap();
+
+            for i in (0..variable_count).rev() {
+                write!(html, "<td>{}</td>", term >> i & 1).unwrap();
+            }
+
+            writeln!(html, "<td>{}</td></tr>", self.output(term)).unwrap();
+        }
+
+        html.push_str("</table>\n");
+    }
+
+    fn write_karnaugh_map(&self, html: &mut String) {
+        let variables = self.variables();
+        let layout = Layout::new(variables.len());
+
+        write!(
+            html,
+            "<h2>Karnaugh map</h2>\n<table>\n<tr><th>{} \\ {}</th>",
+            escape(&variables[..layout.row_bits].join(" ")),
+            escape(&variables[layout.row_bits..].join(" "))
+        )
+        .unwrap();
+
+        for col in 0..layout.cols() {
+            write!(html, "<th>{}</th>", layout.col_label(col)).unwrap();
+        }
+
+        html.push_str("</tr>\n");
+
+        for row in 0..layout.rows() {
+            write!(html, "<tr><th>{}</th>", layout.row_label(row)).unwrap();
+
+            for col in 0..layout.cols() {
+                let term = layout.term(row, col);
+
+                write!(
+                    html,
+                    "<td data-term=\"{}\">{}</td>",
+                    term,
+                    self.output(term)
+                )
+                .unwrap();
+            }
+
+            html.push_str("</tr>\n");
+        }
+
+        html.push_str("</table>\n");
+    }
+
+    fn write_prime_implicant_chart(
+        &self,
+        html: &mut String,
+        terms: &[u32],
+        highlighted_terms: &HashMap<Cube, String>,
+    ) {
+        html.push_str("<h2>Prime implicant chart</h2>\n<table>\n<tr><th></th>");
+
+        for term in terms {
+            write!(html, "<th data-term=\"{}\">{}</th>", term, term).unwrap();
+        }
+
+        html.push_str("</tr>\n");
+
+        for cube in &self.prime_implicants {
+            write!(
+                html,
+                "<tr><th data-terms=\"{}\">{}</th>",
+                highlighted_terms[cube],
+                escape(&self.expression(cube.implicant()))
+            )
+            .unwrap();
+
+            for &term in terms {
+                let mark = if cube.contains(term) { "×" } else { "" };
+                write!(html, "<td data-term=\"{}\">{}</td>", term, mark).unwrap();
+            }
+
+            html.push_str("</tr>\n");
+        }
+
+        html.push_str("</table>\n");
+    }
+
+    fn write_solutions(&self, html: &mut String, highlighted_terms: &HashMap<Cube, String>) {
+        let (inner_operator, outer_operator) = if self.form == SOP {
+            ("∧", "∨")
+        } else {
+            ("∨", "∧")
+        };
+
+        html.push_str("<h2>Solutions</h2>\n<ol>\n");
+
+        for solution in &self.solutions {
+            html.push_str("<li>");
+
+            if solution.is_one() || solution.is_zero() {
+                write!(html, "{}", solution).unwrap();
+            } else {
+                let cubes = solution.cubes();
+
+                for (i, cube) in cubes.iter().enumerate() {
+                    if i > 0 {
+                        write!(html, " {} ", outer_operator).unwrap();
+                    }
+
+                    let expression = self.expression(cube.implicant());
+                    let expression = if cubes.len() > 1 && expression.contains(inner_operator) {
+                        format!("({})", expression)
+                    } else {
+                        expression
+                    };
+
+                    write!(
+                        html,
+                        "<span data-terms=\"{}\">{}</span>",
+                        highlighted_terms[cube],
+                        escape(&expression)
+                    )
+                    .unwrap();
+                }
+            }
+
+            writeln!(
+                html,
+                " <small>({})</small></li>",
+                solution.guarantees().optimality
+            )
+            .unwrap();
+        }
+
+        html.push_str("</ol>\n");
+    }
+
+    fn variables(&self) -> &[String] {
+        self.function.variables()
+    }
+
+    fn output(&self, term: u32) -> &'static str {
+        karnaugh::output(&self.function, term)
+    }
+
+    /// Returns the product (or sum) of the implicant, e.g. `A ∧ ~C`.
+    fn expression(&self, implicant: Implicant) -> String {
+        Cover::new(&[implicant], self.variables(), self.form).to_string()
+    }
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
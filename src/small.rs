@@ -0,0 +1,395 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+use crate::{implicant::Implicant, Cube, Error, Form, SOP};
+
+/// The maximum number of variables of [`minimize_const`].
+pub const MAX_CONST_VARIABLES: usize = 8;
+
+/// The number of cubes of [`MAX_CONST_VARIABLES`] variables, each of which is `0`, `1` or `-`.
+const MAX_CUBES: usize = 6561;
+
+/// Minimizes the boolean function of `N` variables whose minterms and maxterms are given as packed bits,
+/// without allocating on the heap.
+///
+/// Bit `i % 64` of `on_set[i / 64]` is set if term `i` is a minterm, and likewise for `off_set` and maxterms.
+/// The rest of the terms are don't cares. `form` determines whether the cubes are the products of a [`SOP`]
+/// expression or the sums of a [`POS`](crate::POS) expression, see [`Cube::literal`].
+///
+/// The cubes of a single minimal solution, with the fewest products (or sums) and then the fewest literals,
+/// are written to the start of `cubes` and their number is returned. No cubes means the constant 0 in [`SOP`] form
+/// and the constant 1 in [`POS`](crate::POS) form. The exact search has no timeout, so functions with many
+/// nearly equally good covers may take long.
+///
+/// The working storage is on the stack and takes about 30 KiB, plus about 1 KiB for each product (or sum)
+/// of the covers that are tried. The heap is only used to build an error.
+///
+/// Returns [`Error::InvalidVariableCount`] if `N` is 0 or greater than [`MAX_CONST_VARIABLES`],
+/// [`Error::TermOutOfBounds`] if a bit is set for a term out of bounds,
+/// [`Error::TermConflict`] if a bit is set in both `on_set` and `off_set`,
+/// and [`Error::BufferTooSmall`] if the solution has more cubes than `cubes` can hold.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let mut cubes = [qmc::Cube::default(); 8];
+/// let count = qmc::minimize_const::<3>(
+///     [0b0010_0001, 0, 0, 0],
+///     [0b0101_1010, 0, 0, 0],
+///     qmc::SOP,
+///     &mut cubes,
+/// )
+/// .unwrap();
+///
+/// // (A ∧ C) ∨ (~A ∧ ~C)
+/// assert_eq!(count, 2);
+/// assert_eq!(cubes[0].to_string(), "0-0");
+/// assert_eq!(cubes[1].to_string(), "1-1");
+/// ```
+pub fn minimize_const<const N: usize>(
+    on_set: [u64; 4],
+    off_set: [u64; 4],
+    form: Form,
+    cubes: &mut [Cube],
+) -> Result<usize, Error> {
+    if N == 0 || N > MAX_CONST_VARIABLES {
+        return Err(Error::InvalidVariableCount(N));
+    }
+
+    let on_set = Bits(on_set);
+    let off_set = Bits(off_set);
+    let term_count = 1 << N;
+
+    for set in [on_set, off_set] {
+        if set.iter().any(|term| term >= term_count) {
+            return Err(Error::TermOutOfBounds {
+                offending_terms: HashSet::from_iter(set.iter().filter(|&term| term >= term_count)),
+                variable_count: N,
+            });
+        }
+    }
+
+    let conflicts = on_set.intersection(off_set);
+
+    if !conflicts.is_empty() {
+        return Err(Error::TermConflict(HashSet::from_iter(conflicts.iter())));
+    }
+
+    let (terms, excluded) = if form == SOP {
+        (on_set, off_set)
+    } else {
+        (off_set, on_set)
+    };
+
+    let mut primes = [(0u8, 0u8); MAX_CUBES];
+    let prime_count = find_prime_implicants::<N>(terms, excluded, &mut primes);
+
+    let mut search = CoverSearch {
+        variable_count: N,
+        terms,
+        primes: &primes[..prime_count],
+        chosen: [0; 256],
+        best: [0; 256],
+        best_cost: (usize::MAX, usize::MAX),
+    };
+    search.search(Bits::default(), 0, 0);
+
+    let (best_count, _) = search.best_cost;
+
+    if best_count > cubes.len() {
+        return Err(Error::BufferTooSmall(best_count));
+    }
+
+    for (cube, &prime) in cubes.iter_mut().zip(&search.best[..best_count]) {
+        let (value, mask) = primes[prime as usize];
+
+        *cube = Cube::from_implicant(Implicant::from_parts(value as u32, mask as u32), N as u32);
+    }
+
+    Ok(best_count)
+}
+
+/// Writes the prime implicants that contain at least one of the `terms` and none of the `excluded` terms
+/// to `primes` as pairs of a value and a mask, and returns their number.
+///
+/// A cube is indexed by its digits in base 3, where digit `b` is 0 or 1 if bit `b` is fixed to that value and
+/// 2 if bit `b` is free, so the cubes that are split by freeing a bit precede it.
+fn find_prime_implicants<const N: usize>(
+    terms: Bits,
+    excluded: Bits,
+    primes: &mut [(u8, u8); MAX_CUBES],
+) -> usize {
+    let cube_count = 3usize.pow(N as u32);
+    let mut is_implicant = [false; MAX_CUBES];
+    let mut has_term = [false; MAX_CUBES];
+
+    for index in 0..cube_count {
+        let (value, mask) = cube(index, N);
+
+        if mask == 0 {
+            is_implicant[index] = !excluded.contains(value as u32);
+            has_term[index] = terms.contains(value as u32);
+        } else {
+            let bit = mask.trailing_zeros();
+            let fixed_to_one = index - 3usize.pow(bit);
+            let fixed_to_zero = fixed_to_one - 3usize.pow(bit);
+
+            is_implicant[index] = is_implicant[fixed_to_zero] && is_implicant[fixed_to_one];
+            has_term[index] = has_term[fixed_to_zero] || has_term[fixed_to_one];
+        }
+    }
+
+    let mut prime_count = 0;
+
+    for index in 0..cube_count {
+        if !is_implicant[index] || !has_term[index] {
+            continue;
+        }
+
+        let (value, mask) = cube(index, N);
+        let is_prime = (0..N as u32).filter(|bit| mask & 1 << bit == 0).all(|bit| {
+            let digit = if value & 1 << bit == 0 { 0 } else { 1 };
+
+            !is_implicant[index + (2 - digit) * 3usize.pow(bit)]
+        });
+
+        if is_prime {
+            primes[prime_count] = (value, mask);
+            prime_count += 1;
+        }
+    }
+
+    prime_count
+}
+
+/// Returns the value and the mask of the cube with the given index, see [`find_prime_implicants`].
+fn cube(mut index: usize, variable_count: usize) -> (u8, u8) {
+    let mut value = 0;
+    let mut mask = 0;
+
+    for bit in 0..variable_count {
+        match index % 3 {
+            0 => {}
+            1 => value |= 1 << bit,
+            _ => mask |= 1 << bit,
+        }
+
+        index /= 3;
+    }
+
+    (value, mask)
+}
+
+/// Calls `f` with each term of the cube with the given value and mask.
+fn for_each_term(value: u8, mask: u8, mut f: impl FnMut(u32)) {
+    let mut free_bits = mask;
+
+    loop {
+        f((value | free_bits) as u32);
+
+        if free_bits == 0 {
+            break;
+        }
+
+        free_bits = (free_bits - 1) & mask;
+    }
+}
+
+/// A branch and bound search for a minimum cover of the terms by the prime implicants.
+struct CoverSearch<'a> {
+    variable_count: usize,
+    terms: Bits,
+    primes: &'a [(u8, u8)],
+    chosen: [u16; 256],
+    best: [u16; 256],
+    best_cost: (usize, usize),
+}
+
+impl CoverSearch<'_> {
+    fn search(&mut self, covered: Bits, depth: usize, literal_count: usize) {
+        let uncovered = self.terms.difference(covered);
+
+        if uncovered.is_empty() {
+            if (depth, literal_count) < self.best_cost {
+                self.best[..depth].copy_from_slice(&self.chosen[..depth]);
+                self.best_cost = (depth, literal_count);
+            }
+
+            return;
+        }
+
+        let (term_bound, literal_bound) = self.lower_bound(uncovered);
+
+        if (depth + term_bound, literal_count + literal_bound) >= self.best_cost {
+            return;
+        }
+
+        // Branching on the term with the fewest prime implicants keeps the search narrow,
+        // and takes essential prime implicants without branching.
+        let term = uncovered
+            .iter()
+            .min_by_key(|&term| self.covering_prime_count(term))
+            .unwrap();
+
+        // Trying the prime implicants that cover the most terms first finds a good bound early.
+        let mut candidates = [(0u16, 0u16); 256];
+        let mut candidate_count = 0;
+
+        for (prime, &(value, mask)) in self.primes.iter().enumerate() {
+            if term as u8 & !mask == value && !self.is_dominated(prime, term, uncovered) {
+                let new_terms = self.uncovered_terms(value, mask, uncovered);
+
+                candidates[candidate_count] = (new_terms.len() as u16, prime as u16);
+                candidate_count += 1;
+            }
+        }
+
+        let candidates = &mut candidates[..candidate_count];
+        candidates
+            .sort_unstable_by_key(|&(new_term_count, prime)| (Reverse(new_term_count), prime));
+
+        for &(_, prime) in candidates.iter() {
+            let (value, mask) = self.primes[prime as usize];
+            let mut covered = covered;
+            for_each_term(value, mask, |term| covered.insert(term));
+
+            self.chosen[depth] = prime;
+            self.search(
+                covered,
+                depth + 1,
+                literal_count + self.variable_count - mask.count_ones() as usize,
+            );
+        }
+    }
+
+    /// Returns whether another prime implicant that covers `term` makes choosing `prime` unnecessary,
+    /// because it covers the same `uncovered` terms or more with as few literals (or, if equal, comes first).
+    fn is_dominated(&self, prime: usize, term: u32, uncovered: Bits) -> bool {
+        let (value, mask) = self.primes[prime];
+        let covered = self.uncovered_terms(value, mask, uncovered);
+
+        self.primes
+            .iter()
+            .enumerate()
+            .any(|(other_prime, &(other_value, other_mask))| {
+                other_prime != prime
+                    && term as u8 & !other_mask == other_value
+                    && (other_mask.count_ones(), prime) >= (mask.count_ones(), other_prime)
+                    && covered
+                        .difference(self.uncovered_terms(other_value, other_mask, uncovered))
+                        .is_empty()
+            })
+    }
+
+    fn uncovered_terms(&self, value: u8, mask: u8, uncovered: Bits) -> Bits {
+        let mut terms = Bits::default();
+
+        for_each_term(value, mask, |term| {
+            if uncovered.contains(term) {
+                terms.insert(term);
+            }
+        });
+
+        terms
+    }
+
+    /// Returns lower bounds of the number of prime implicants and literals needed to cover the `uncovered` terms.
+    ///
+    /// No prime implicant covers two terms of an independent set, so each of them needs its own prime implicant
+    /// with at least as many literals as the cheapest one that covers it. The terms with the fewest prime implicants
+    /// are picked first, which tends to give a larger set.
+    fn lower_bound(&self, uncovered: Bits) -> (usize, usize) {
+        let mut terms = [(0u16, 0u16); 256];
+        let mut term_count = 0;
+
+        for term in uncovered.iter() {
+            terms[term_count] = (self.covering_prime_count(term) as u16, term as u16);
+            term_count += 1;
+        }
+
+        let terms = &mut terms[..term_count];
+        terms.sort_unstable();
+
+        let mut blocked = Bits::default();
+        let mut independent_count = 0;
+        let mut literal_count = 0;
+
+        for &(_, term) in terms.iter() {
+            let term = term as u32;
+
+            if blocked.contains(term) {
+                continue;
+            }
+
+            let mut min_literal_count = self.variable_count;
+
+            for &(value, mask) in self.primes {
+                if term as u8 & !mask == value {
+                    for_each_term(value, mask, |term| blocked.insert(term));
+                    min_literal_count =
+                        min_literal_count.min(self.variable_count - mask.count_ones() as usize);
+                }
+            }
+
+            independent_count += 1;
+            literal_count += min_literal_count;
+        }
+
+        (independent_count, literal_count)
+    }
+
+    fn covering_prime_count(&self, term: u32) -> usize {
+        self.primes
+            .iter()
+            .filter(|&&(value, mask)| term as u8 & !mask == value)
+            .count()
+    }
+}
+
+/// A set of the terms of up to [`MAX_CONST_VARIABLES`] variables.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Bits([u64; 4]);
+
+impl Bits {
+    fn contains(&self, term: u32) -> bool {
+        self.0[term as usize / 64] & 1 << (term % 64) != 0
+    }
+
+    fn insert(&mut self, term: u32) {
+        self.0[term as usize / 64] |= 1 << (term % 64);
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    fn intersection(&self, other: Bits) -> Bits {
+        let mut words = self.0;
+
+        for (word, other_word) in words.iter_mut().zip(other.0) {
+            *word &= other_word;
+        }
+
+        Bits(words)
+    }
+
+    fn difference(&self, other: Bits) -> Bits {
+        let mut words = self.0;
+
+        for (word, other_word) in words.iter_mut().zip(other.0) {
+            *word &= !other_word;
+        }
+
+        Bits(words)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..256).filter(move |&term| self.contains(term))
+    }
+}
@@ -1,11 +1,31 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Display;
+use std::hash::Hash;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{implicant::Implicant, Form};
+use crate::{
+    blif::write_names,
+    clause::{Clause, Literal, Product},
+    duplicate_positions,
+    format::{with_default_format, ExpressionStyle, Negation, Style},
+    function::{BooleanFunction, Certificate},
+    implicant::{Implicant, VariableSort},
+    karnaugh::{self, KarnaughGroup},
+    pla::write_pla,
+    report::escape,
+    validate_probabilities, Cube, Error, Expr, Form, Guarantees, Optimality, OutputKind, Warning,
+    DEFAULT_VARIABLES,
+};
 
 /// A minimized boolean expression.
+///
+/// This is what [`minimize`], [`minimize_minterms`](crate::minimize_minterms) and
+/// [`minimize_maxterms`](crate::minimize_maxterms) return. The rest of the minimization functions return a [`Cover`],
+/// which keeps the variables of the function and what is known about the solution and can be turned into
+/// a `Solution` with [`From`].
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Solution {
@@ -16,76 +36,3261 @@ pub enum Solution {
 }
 
 impl Solution {
+    /// Returns whether the expression is the constant 0 or 1.
+    pub fn is_constant(&self) -> bool {
+        matches!(self, Solution::One | Solution::Zero)
+    }
+}
+
+impl From<&Cover> for Solution {
+    fn from(cover: &Cover) -> Self {
+        if cover.is_one() {
+            Solution::One
+        } else if cover.is_zero() {
+            Solution::Zero
+        } else if cover.form == Form::SOP {
+            Solution::SOP(cover.terms())
+        } else {
+            Solution::POS(cover.terms())
+        }
+    }
+}
+
+impl From<Cover> for Solution {
+    fn from(cover: Cover) -> Self {
+        Solution::from(&cover)
+    }
+}
+
+impl Display for Solution {
+    /// Writes the expression in the style set with [`set_default_format`](crate::set_default_format).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        with_default_format(|style| {
+            let (expression, form) = match self {
+                Solution::One => return f.write_str(&style.one),
+                Solution::Zero => return f.write_str(&style.zero),
+                Solution::SOP(expression) => (expression, Form::SOP),
+                Solution::POS(expression) => (expression, Form::POS),
+            };
+
+            let mut names: Vec<&str> = vec![];
+
+            for variable in expression.iter().flatten() {
+                if !names.contains(&variable.name.as_str()) {
+                    names.push(&variable.name);
+                }
+            }
+
+            let identifiers = style.identifiers.identifiers(&names);
+
+            let and = style.operator(&style.and);
+            let or = style.operator(&style.or);
+            let (inner, outer) = if form == Form::SOP {
+                (and, or)
+            } else {
+                (or, and)
+            };
+
+            for (i, variables) in expression.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(&outer)?;
+                }
+
+                if expression.len() > 1 && variables.len() > 1 {
+                    f.write_str("(")?;
+                }
+
+                for (j, variable) in variables.iter().enumerate() {
+                    if j > 0 {
+                        f.write_str(&inner)?;
+                    }
+
+                    let index = names.iter().position(|name| *name == variable.name);
+                    let identifier = identifiers[index.unwrap()].clone();
+
+                    if variable.is_negated {
+                        f.write_str(&style.negation.negate(identifier))?;
+                    } else {
+                        f.write_str(&identifier)?;
+                    }
+                }
+
+                if expression.len() > 1 && variables.len() > 1 {
+                    f.write_str(")")?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// The version of the layout a [`Cover`] is serialized with.
+pub const SOLUTION_SCHEMA_VERSION: u32 = 1;
+
+/// A minimized boolean expression with the variables of the minimized function and what is known about it.
+///
+/// Unlike [`Solution`], a cover keeps the implicants it is made of, so it can be analyzed, transformed and
+/// exported. The minimization functions other than [`minimize`], [`minimize_minterms`](crate::minimize_minterms) and
+/// [`minimize_maxterms`](crate::minimize_maxterms) return covers, and so does [`BooleanFunction::minimize`].
+///
+/// # Serialization
+///
+/// With the `serde` feature, a solution is serialized as a map like this one in JSON:
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "variables": ["A", "B", "C"],
+///   "form": "SOP",
+///   "cubes": ["11-", "0-1"],
+///   "guarantees": { "algorithm_revision": 1, "optimality": "minimum" },
+///   "annotations": { "source": "adder.pla" }
+/// }
+/// ```
+///
+/// `version` is [`SOLUTION_SCHEMA_VERSION`] and `cubes` are the products (or sums) written like [`Cube`].
+/// A `certificate` (see [`BooleanFunction::certify`]) and `warnings` (see [`Warning`]) are only written if present,
+/// and `annotations` may be missing.
+///
+/// Solutions serialized by release 1.0.1 and earlier, which were a [`Solution`] like `{"SOP": [[...], ...]}`
+/// or `"One"`, are still read. Their variables are the ones appearing in the expression in order of their first
+/// appearance, so a constant has none, and nothing is known about their optimality. Newer versions are rejected.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "SolutionRecord", try_from = "SerializedCover")
+)]
+pub struct Cover {
+    variables: Vec<String>,
+    form: Form,
+    implicants: Vec<Implicant>,
+    certificate: Option<Certificate>,
+    guarantees: Guarantees,
+    annotations: BTreeMap<String, String>,
+    warnings: Vec<Warning>,
+}
+
+/// The serialized layout of a [`Cover`] in any version.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SolutionRecord {
+    pub(crate) version: u32,
+    pub(crate) variables: Vec<String>,
+    pub(crate) form: Form,
+    pub(crate) cubes: Vec<Cube>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) certificate: Option<Certificate>,
+    #[serde(default = "Guarantees::unrecorded")]
+    pub(crate) guarantees: Guarantees,
+    #[serde(default)]
+    pub(crate) annotations: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) warnings: Vec<Warning>,
+}
+
+/// A serialized [`Cover`], which is a [`Solution`] if it was written by release 1.0.1 or earlier.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum SerializedCover {
+    Current(SolutionRecord),
+    Legacy(Solution),
+}
+
+#[cfg(feature = "serde")]
+impl From<Cover> for SolutionRecord {
+    fn from(solution: Cover) -> Self {
+        let cubes = solution.cubes();
+
+        SolutionRecord {
+            version: SOLUTION_SCHEMA_VERSION,
+            variables: solution.variables,
+            form: solution.form,
+            cubes,
+            certificate: solution.certificate,
+            guarantees: solution.guarantees,
+            annotations: solution.annotations,
+            warnings: solution.warnings,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SerializedCover> for Cover {
+    type Error = String;
+
+    fn try_from(serialized: SerializedCover) -> Result<Self, Self::Error> {
+        match serialized {
+            SerializedCover::Current(record) => Cover::try_from(record),
+            SerializedCover::Legacy(solution) => Cover::try_from(solution),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SolutionRecord> for Cover {
+    type Error = String;
+
+    fn try_from(record: SolutionRecord) -> Result<Self, Self::Error> {
+        if record.version != SOLUTION_SCHEMA_VERSION {
+            return Err(format!(
+                "unsupported solution schema version {} (expected {})",
+                record.version, SOLUTION_SCHEMA_VERSION
+            ));
+        }
+
+        validate_serialized_variables(&record.variables)?;
+
+        if let Some(cube) = record
+            .cubes
+            .iter()
+            .find(|cube| cube.variable_count() as usize != record.variables.len())
+        {
+            return Err(format!(
+                "cube {} does not have {} variables",
+                cube,
+                record.variables.len()
+            ));
+        }
+
+        Ok(Cover {
+            implicants: Vec::from_iter(record.cubes.iter().map(Cube::implicant)),
+            variables: record.variables,
+            form: record.form,
+            certificate: record.certificate,
+            guarantees: record.guarantees,
+            annotations: record.annotations,
+            warnings: record.warnings,
+        })
+    }
+}
+
+/// Reads a solution serialized by release 1.0.1 or earlier.
+#[cfg(feature = "serde")]
+impl TryFrom<Solution> for Cover {
+    type Error = String;
+
+    fn try_from(solution: Solution) -> Result<Self, Self::Error> {
+        let (terms, form) = match solution {
+            Solution::One => (vec![vec![]], Form::SOP),
+            Solution::Zero => (vec![], Form::SOP),
+            Solution::SOP(terms) => (terms, Form::SOP),
+            Solution::POS(terms) => (terms, Form::POS),
+        };
+
+        let mut variables: Vec<String> = vec![];
+
+        for variable in terms.iter().flatten() {
+            if !variables.contains(&variable.name) {
+                variables.push(variable.name.clone());
+            }
+        }
+
+        validate_serialized_variables(&variables)?;
+
+        let variable_count = variables.len();
+        let mut implicants = vec![];
+
+        for term in &terms {
+            let mut value = 0;
+            let mut mask = ((1u64 << variable_count) - 1) as u32;
+
+            for variable in term {
+                let index = variables
+                    .iter()
+                    .position(|name| *name == variable.name)
+                    .unwrap();
+                let bit = 1 << (variable_count - index - 1);
+
+                if mask & bit == 0 {
+                    return Err(format!(
+                        "variable {} appears twice in a term",
+                        variable.name
+                    ));
+                }
+
+                mask &= !bit;
+
+                // Implicants cover maxterms in POS form, so a variable is negated when its bit is 1.
+                if variable.is_negated == (form == Form::POS) {
+                    value |= bit;
+                }
+            }
+
+            implicants.push(Implicant::from_parts(value, mask));
+        }
+
+        Ok(Cover {
+            variables,
+            form,
+            implicants,
+            certificate: None,
+            guarantees: Guarantees::unrecorded(),
+            annotations: BTreeMap::new(),
+            warnings: vec![],
+        })
+    }
+}
+
+/// Checks the variables of a serialized cover like the minimization functions do,
+/// except that a constant may have no variables.
+#[cfg(feature = "serde")]
+fn validate_serialized_variables(variables: &[String]) -> Result<(), String> {
+    if variables.is_empty() {
+        return Ok(());
+    }
+
+    crate::validate_variables(variables).map_err(|error| error.to_string())
+}
+
+impl Cover {
     pub(crate) fn new(internal_solution: &[Implicant], variables: &[String], form: Form) -> Self {
-        let expression = internal_solution
+        Cover {
+            variables: variables.to_vec(),
+            form,
+            implicants: internal_solution.to_vec(),
+            certificate: None,
+            guarantees: Guarantees::new(Optimality::Minimum),
+            annotations: BTreeMap::new(),
+            warnings: vec![],
+        }
+    }
+
+    /// Returns the variables of the minimized function, including the ones
+    /// that don't appear in the expression.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the index of the given `variable` in [`variables`](Self::variables), which is the position
+    /// of the variable in the input and the index of [`Literal`], or `None` if it is not part of the solution.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5, 7], &[0, 2, 4, 6])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.variable_index("C"), Some(2));
+    /// assert_eq!(solution.products().unwrap()[0].literals[0].index, 2);
+    /// assert_eq!(solution.variable_index("D"), None);
+    /// ```
+    pub fn variable_index(&self, variable: &str) -> Option<usize> {
+        self.variables.iter().position(|name| name == variable)
+    }
+
+    /// Returns the form of the expression.
+    pub fn form(&self) -> Form {
+        self.form
+    }
+
+    /// Returns the kind of the expression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 3], &[0, 2])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None).unwrap();
+    ///
+    /// assert_eq!(solution[0].kind(), qmc::OutputKind::TwoLevel(qmc::SOP));
+    /// assert_eq!(solution[0].kind().form(), Some(qmc::SOP));
+    /// ```
+    pub fn kind(&self) -> OutputKind {
+        OutputKind::TwoLevel(self.form)
+    }
+
+    /// Returns whether the expression is the constant 1.
+    pub fn is_one(&self) -> bool {
+        match self.form {
+            Form::SOP => self.has_empty_term(),
+            Form::POS => self.implicants.is_empty(),
+        }
+    }
+
+    /// Returns whether the expression is the constant 0.
+    pub fn is_zero(&self) -> bool {
+        match self.form {
+            Form::SOP => self.implicants.is_empty(),
+            Form::POS => self.has_empty_term(),
+        }
+    }
+
+    /// Returns whether the expression is the constant 0 or 1.
+    pub fn is_constant(&self) -> bool {
+        self.is_one() || self.is_zero()
+    }
+
+    /// Returns the products (in [`SOP`](Form::SOP) form) or sums (in [`POS`](Form::POS) form)
+    /// of the expression. Returns an empty list if the expression is a constant.
+    pub fn terms(&self) -> Vec<Vec<Variable>> {
+        if self.is_one() || self.is_zero() {
+            return vec![];
+        }
+
+        self.implicants
+            .iter()
+            .map(|implicant| implicant.to_variables(&self.variables, self.form))
+            .collect()
+    }
+
+    /// Returns the number of products (or sums) of the expression, which is 0 for a constant.
+    pub fn term_count(&self) -> usize {
+        self.term_literal_counts().count()
+    }
+
+    /// Returns the number of literals in all products (or sums) of the expression, counting a variable
+    /// once for each term it appears in.
+    pub fn literal_count(&self) -> usize {
+        self.term_literal_counts().sum()
+    }
+
+    /// Returns the number of gate inputs of a two-level circuit of the expression.
+    ///
+    /// These are the literals of the terms with more than one literal, which are the inputs of the AND (or OR) gates,
+    /// plus the number of terms if there is more than one, which are the inputs of the final OR (or AND) gate.
+    /// Inverters are not counted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// // (A ∧ B) ∨ (~A ∧ C)
+    /// assert_eq!(solution.term_count(), 2);
+    /// assert_eq!(solution.literal_count(), 4);
+    /// assert_eq!(solution.gate_input_count(), 6);
+    /// ```
+    pub fn gate_input_count(&self) -> usize {
+        let term_count = self.term_count();
+        let term_gate_inputs: usize = self
+            .term_literal_counts()
+            .filter(|&literal_count| literal_count > 1)
+            .sum();
+
+        if term_count > 1 {
+            term_gate_inputs + term_count
+        } else {
+            term_gate_inputs
+        }
+    }
+
+    /// Returns the clauses of an expression in [`POS`](Form::POS) form, whose conjunction is the expression.
+    ///
+    /// Unlike [`terms`](Self::terms), constants are represented too: the constant 1 has no clauses
+    /// and the constant 0 has a single clause without literals.
+    ///
+    /// Returns `None` if the expression is not in [`POS`](Form::POS) form.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B"], &[0, 1, 3], &[2])
+    ///     .unwrap()
+    ///     .minimize(qmc::POS, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    /// let clauses = solution.clauses().unwrap();
+    ///
+    /// // ~A ∨ B
+    /// assert_eq!(clauses.len(), 1);
+    /// assert_eq!(clauses[0].literals[0].variable, "A");
+    /// assert_eq!(clauses[0].literals[0].polarity, qmc::Polarity::Negative);
+    /// assert_eq!(clauses[0].literals[1].index, 1);
+    /// assert_eq!(clauses[0].literals[1].polarity, qmc::Polarity::Positive);
+    /// assert!(solution.products().is_none());
+    /// ```
+    pub fn clauses(&self) -> Option<Vec<Clause>> {
+        if self.form != Form::POS {
+            return None;
+        }
+
+        Some(
+            self.implicants
+                .iter()
+                .map(|&implicant| Clause {
+                    literals: self.literals(implicant),
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the products of an expression in [`SOP`](Form::SOP) form, whose disjunction is the expression.
+    ///
+    /// Unlike [`terms`](Self::terms), constants are represented too: the constant 0 has no products
+    /// and the constant 1 has a single product without literals.
+    ///
+    /// Returns `None` if the expression is not in [`SOP`](Form::SOP) form.
+    pub fn products(&self) -> Option<Vec<Product>> {
+        if self.form != Form::SOP {
+            return None;
+        }
+
+        Some(
+            self.implicants
+                .iter()
+                .map(|&implicant| Product {
+                    literals: self.literals(implicant),
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the cubes of the products (in [`SOP`](Form::SOP) form) or sums (in [`POS`](Form::POS) form)
+    /// of the expression.
+    ///
+    /// The cubes of the products contain the minterms they cover and the cubes of the sums contain the maxterms
+    /// they cover. Use [`Cube::literal`] to get the literals of a product or sum.
+    pub fn cubes(&self) -> Vec<Cube> {
+        self.implicants
+            .iter()
+            .map(|&implicant| Cube::from_implicant(implicant, self.variables.len() as u32))
+            .collect()
+    }
+
+    /// Returns the expression as a tree with the products (or sums) in the same order as `Display`.
+    ///
+    /// Constants are [`Expr::Const`], and products (or sums) of a single literal and expressions of a single
+    /// product (or sum) are not wrapped in [`Expr::And`] or [`Expr::Or`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    /// use qmc::Expr::{And, Not, Or, Var};
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_string(), "(A ∧ B) ∨ (~A ∧ C)");
+    /// assert_eq!(
+    ///     solution.to_ast(),
+    ///     Or(vec![
+    ///         And(vec![Var("A".to_owned()), Var("B".to_owned())]),
+    ///         And(vec![Not(Box::new(Var("A".to_owned()))), Var("C".to_owned())]),
+    ///     ])
+    /// );
+    /// ```
+    pub fn to_ast(&self) -> Expr {
+        if self.is_one() {
+            return Expr::Const(true);
+        } else if self.is_zero() {
+            return Expr::Const(false);
+        }
+
+        let mut terms = Vec::from_iter(self.terms().into_iter().map(|variables| {
+            let mut literals = Vec::from_iter(variables.into_iter().map(|variable| {
+                let var = Expr::Var(variable.name);
+
+                if variable.is_negated {
+                    Expr::Not(Box::new(var))
+                } else {
+                    var
+                }
+            }));
+
+            match (literals.len(), self.form) {
+                (1, _) => literals.remove(0),
+                (_, Form::SOP) => Expr::And(literals),
+                (_, Form::POS) => Expr::Or(literals),
+            }
+        }));
+
+        match (terms.len(), self.form) {
+            (1, _) => terms.remove(0),
+            (_, Form::SOP) => Expr::Or(terms),
+            (_, Form::POS) => Expr::And(terms),
+        }
+    }
+
+    /// Returns the cells of the Karnaugh map covered by each product (or sum) of the expression,
+    /// in the same order as [`cubes`](Self::cubes).
+    ///
+    /// The map is laid out like [`BooleanFunction::to_karnaugh_svg`]: the first half of the variables
+    /// (rounded down) select the row and the rest select the column, both in Gray code order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 6 variables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// // ~B ∧ ~D covers the four corners of the map.
+    /// let solution = qmc::BooleanFunction::new(
+    ///     &["A", "B", "C", "D"],
+    ///     &[0, 2, 8, 10],
+    ///     &[1, 3, 4, 5, 6, 7, 9, 11, 12, 13, 14, 15],
+    /// )
+    /// .unwrap()
+    /// .minimize(qmc::SOP, false, None)
+    /// .unwrap()
+    /// .remove(0);
+    ///
+    /// let groups = solution.karnaugh_groups();
+    /// let corners = Vec::from_iter(groups[0].cells.iter().map(|cell| (cell.row, cell.col, cell.term)));
+    ///
+    /// assert_eq!(corners, [(0, 0, 0), (0, 3, 2), (3, 0, 8), (3, 3, 10)]);
+    /// assert_eq!(groups[0].rectangles.len(), 4);
+    /// ```
+    pub fn karnaugh_groups(&self) -> Vec<KarnaughGroup> {
+        karnaugh::groups(self)
+    }
+
+    /// Returns the complement of the expression in the other form.
+    ///
+    /// The implicants are kept as is and only their interpretation changes: the sums of a [`POS`](Form::POS)
+    /// expression become the products of its complement in [`SOP`](Form::SOP) form by De Morgan's laws,
+    /// and vice versa. Converting twice gives back the original expression. A [`Certificate`] is not kept,
+    /// since it is bound to the function the expression was certified against.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let mut solutions = qmc::BooleanFunction::new(
+    ///     &qmc::DEFAULT_VARIABLES[..3],
+    ///     &[0, 5],
+    ///     &[1, 3, 4, 6],
+    /// )
+    /// .unwrap()
+    /// .minimize(qmc::POS, false, None)
+    /// .unwrap();
+    /// let solution = solutions.pop().unwrap();
+    ///
+    /// assert_eq!(solution.to_string(), "(A ∨ ~C) ∧ (~A ∨ C)");
+    /// assert_eq!(solution.complement().to_string(), "(~A ∧ C) ∨ (A ∧ ~C)");
+    /// assert_eq!(solution.complement().complement().to_string(), solution.to_string());
+    /// ```
+    pub fn complement(&self) -> Cover {
+        Cover {
+            variables: self.variables.clone(),
+            form: match self.form {
+                Form::SOP => Form::POS,
+                Form::POS => Form::SOP,
+            },
+            implicants: self.implicants.clone(),
+            certificate: None,
+            guarantees: self.guarantees,
+            annotations: self.annotations.clone(),
+            warnings: self.warnings.clone(),
+        }
+    }
+
+    /// Returns the expression written as the negation of its [`complement`](Self::complement),
+    /// e.g. `~((~A ∧ C) ∨ (A ∧ ~C))` for `(A ∨ ~C) ∧ (~A ∨ C)`.
+    ///
+    /// This is the "complement of SOP" notation of a [`POS`](Form::POS) expression used by some textbooks and tools.
+    /// Constants are written as is.
+    pub fn to_complement_string(&self) -> String {
+        if self.is_one() || self.is_zero() {
+            return self.to_string();
+        }
+
+        format!("~({})", self.complement())
+    }
+
+    /// Removes the products (or sums) that are duplicates of or contained in another one,
+    /// e.g. of a solution deserialized from a file rather than returned by a minimizer.
+    ///
+    /// Returns whether the result is irredundant, i.e. no product (or sum) can be removed without changing
+    /// the terms the expression covers. This is not the case if a product (or sum) is covered by a combination
+    /// of the others, as `B ∧ C` in `(A ∧ B) ∨ (~A ∧ C) ∨ (B ∧ C)`, which is kept. Since a minimum expression
+    /// is irredundant, the [`Optimality`] of a result that is not is lowered to [`Heuristic`](Optimality::Heuristic).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let mut solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 2], &[0, 3])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// // The products of a minimized expression are irredundant already.
+    /// assert!(solution.normalize());
+    /// assert_eq!(solution.to_string(), "(A ∧ ~B) ∨ (~A ∧ B)");
+    /// ```
+    pub fn normalize(&mut self) -> bool {
+        self.implicants = absorb(&self.implicants);
+
+        let is_irredundant = self.implicants.iter().enumerate().all(|(i, implicant)| {
+            let other_terms = HashSet::<u32>::from_iter(
+                self.implicants
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .flat_map(|(_, other)| other.get_terms()),
+            );
+
+            !implicant.get_terms().is_subset(&other_terms)
+        });
+
+        if !is_irredundant {
+            self.set_optimality(Optimality::Heuristic);
+        }
+
+        is_irredundant
+    }
+
+    /// Returns the residual expression obtained by fixing the given variables to the given values.
+    ///
+    /// The residual expression is over the remaining variables. Products (or sums) that contradict the fixed values
+    /// are removed and the ones contained in another are absorbed, but the result is not minimized again,
+    /// so it may not be minimal.
+    ///
+    /// Returns [`Error::UnknownVariable`] if a variable is not part of the solution
+    /// and [`Error::DuplicateVariables`] if a variable is fixed more than once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let mut solutions = qmc::BooleanFunction::new(
+    ///     &qmc::DEFAULT_VARIABLES[..3],
+    ///     &[0, 5],
+    ///     &[1, 3, 4, 6],
+    /// )
+    /// .unwrap()
+    /// .minimize(qmc::SOP, false, None)
+    /// .unwrap();
+    /// let solution = solutions.pop().unwrap();
+    ///
+    /// assert_eq!(solution.to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+    /// assert_eq!(solution.cofactor(&[("A", true)]).unwrap().to_string(), "C");
+    /// ```
+    pub fn cofactor<T: AsRef<str>>(&self, fixed: &[(T, bool)]) -> Result<Cover, Error> {
+        let variable_count = self.variables.len() as u32;
+        let mut fixed_mask = 0;
+        let mut fixed_value = 0;
+        let mut duplicates = HashSet::new();
+
+        for (variable, value) in fixed {
+            let variable = variable.as_ref();
+            let bit = self.variable_bit(variable)?;
+
+            if fixed_mask & bit != 0 {
+                duplicates.insert(variable.to_owned());
+            }
+
+            fixed_mask |= bit;
+
+            if *value {
+                fixed_value |= bit;
+            }
+        }
+
+        if !duplicates.is_empty() {
+            return Err(Error::DuplicateVariables(duplicates));
+        }
+
+        let implicants: Vec<Implicant> = self
+            .implicants
+            .iter()
+            .filter_map(|implicant| implicant.cofactor(fixed_mask, fixed_value, variable_count))
+            .collect();
+
+        let mut residual_implicants = absorb(&implicants);
+        residual_implicants.variable_sort(self.form);
+
+        let variables = Vec::from_iter(
+            self.variables
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| fixed_mask >> (variable_count - *index as u32 - 1) & 1 == 0)
+                .map(|(_, variable)| variable.clone()),
+        );
+
+        let mut residual_solution = Cover::new(&residual_implicants, &variables, self.form);
+        residual_solution.set_optimality(Optimality::Heuristic);
+
+        Ok(residual_solution)
+    }
+
+    /// Substitutes constants for the given variables, leaving the others unknown, and returns the residual expression
+    /// along with the output if it is determined by the constants alone.
+    ///
+    /// This is the three-valued evaluation of the expression where the unknown variables are `X`, except that
+    /// the output is also determined when the residual expression is a tautology or a contradiction,
+    /// such as `B ∨ ~B`. The residual expression is the same as the one of [`cofactor`](Self::cofactor)
+    /// if the output is not determined and the constant output otherwise.
+    ///
+    /// Returns the same errors as [`cofactor`](Self::cofactor).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_string(), "(A ∧ B) ∨ (~A ∧ C)");
+    ///
+    /// // What if A is tied low?
+    /// let substitution = solution.substitute_constants(&[("A", false)]).unwrap();
+    ///
+    /// assert_eq!(substitution.output, None);
+    /// assert_eq!(substitution.residual.to_string(), "C");
+    ///
+    /// // With B and C tied high, the output is 1 regardless of A.
+    /// let substitution = solution.substitute_constants(&[("B", true), ("C", true)]).unwrap();
+    ///
+    /// assert_eq!(substitution.output, Some(true));
+    /// assert!(substitution.residual.is_one());
+    /// ```
+    pub fn substitute_constants<T: AsRef<str>>(
+        &self,
+        fixed: &[(T, bool)],
+    ) -> Result<Substitution, Error> {
+        let residual = self.cofactor(fixed)?;
+        let variable_count = residual.variables.len() as u32;
+
+        // The implicants cover the terms the output is 1 for in SOP form and 0 for in POS form.
+        let covered_output = residual.form == Form::SOP;
+        let output = if residual.implicants.is_empty() {
+            Some(!covered_output)
+        } else if covers_all_terms(&residual.implicants, variable_count) {
+            Some(covered_output)
+        } else {
+            None
+        };
+
+        let residual = match output {
+            Some(output) => {
+                let full_implicant = Implicant::from_parts(0, (1 << variable_count) - 1);
+                let implicants = if output == covered_output {
+                    vec![full_implicant]
+                } else {
+                    vec![]
+                };
+
+                Cover::new(&implicants, &residual.variables, residual.form)
+            }
+            None => residual,
+        };
+
+        Ok(Substitution { residual, output })
+    }
+
+    /// Returns the residual expressions for all assignments of the given `variables`, see [`cofactor`](Self::cofactor).
+    ///
+    /// The assignments are in ascending order with the first variable as the most significant bit,
+    /// so the residual expression for `variables = [A, B]` with `A = 1` and `B = 0` is at index 2.
+    ///
+    /// Returns [`Error::UnknownVariable`] if a variable is not part of the solution
+    /// and [`Error::DuplicateVariables`] if a variable is given more than once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let mut solutions = qmc::BooleanFunction::new(
+    ///     &qmc::DEFAULT_VARIABLES[..3],
+    ///     &[0, 5],
+    ///     &[1, 3, 4, 6],
+    /// )
+    /// .unwrap()
+    /// .minimize(qmc::SOP, false, None)
+    /// .unwrap();
+    /// let specializations = solutions.pop().unwrap().specialize(&["A"]).unwrap();
+    ///
+    /// assert_eq!(specializations[0].to_string(), "~C");
+    /// assert_eq!(specializations[1].to_string(), "C");
+    /// ```
+    pub fn specialize<T: AsRef<str>>(&self, variables: &[T]) -> Result<Vec<Cover>, Error> {
+        for variable in variables {
+            self.variable_bit(variable.as_ref())?;
+        }
+
+        let duplicates = duplicate_positions(variables);
+
+        if !duplicates.is_empty() {
+            return Err(Error::DuplicateVariables(duplicates.into_keys().collect()));
+        }
+
+        (0..1u32 << variables.len())
+            .map(|assignment| {
+                let fixed = Vec::from_iter(variables.iter().enumerate().map(|(i, variable)| {
+                    let bit = assignment >> (variables.len() - i - 1) & 1;
+                    (variable.as_ref(), bit == 1)
+                }));
+
+                self.cofactor(&fixed)
+            })
+            .collect()
+    }
+
+    /// Returns the terms for which flipping the given `variable` changes the output of the expression
+    /// in ascending order, i.e. the terms for which the boolean difference with respect to the variable is 1.
+    ///
+    /// Returns [`Error::UnknownVariable`] if the variable is not part of the solution.
+    pub fn boolean_difference(&self, variable: &str) -> Result<Vec<u32>, Error> {
+        let bit = self.variable_bit(variable)?;
+
+        Ok((0..1 << self.variables.len())
+            .filter(|&term| self.evaluate_term(term) != self.evaluate_term(term ^ bit))
+            .collect())
+    }
+
+    /// Returns the sensitization of each literal of each product (in [`SOP`](Form::SOP) form)
+    /// or sum (in [`POS`](Form::POS) form) of the expression, in the same order as [`terms`](Self::terms).
+    ///
+    /// The sensitizing terms of a literal are the terms of the cube of its product (or sum) for which
+    /// flipping the variable of the literal changes the output of the expression. These are the test vectors
+    /// that detect the literal being stuck. A literal without sensitizing terms is redundant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let mut solutions = qmc::BooleanFunction::new(
+    ///     &qmc::DEFAULT_VARIABLES[..3],
+    ///     &[0, 5],
+    ///     &[1, 3, 4, 6],
+    /// )
+    /// .unwrap()
+    /// .minimize(qmc::SOP, false, None)
+    /// .unwrap();
+    /// let solution = solutions.pop().unwrap();
+    ///
+    /// // A ∧ C
+    /// let sensitization = &solution.sensitization()[0];
+    /// assert_eq!(sensitization[0].variable, "A");
+    /// assert_eq!(sensitization[0].terms, [5, 7]);
+    /// ```
+    pub fn sensitization(&self) -> Vec<Vec<LiteralSensitization>> {
+        if self.is_one() || self.is_zero() {
+            return vec![];
+        }
+
+        self.cubes()
             .iter()
-            .map(|implicant| implicant.to_variables(variables, form))
-            .collect::<Vec<_>>();
+            .map(|cube| {
+                (0..self.variables.len())
+                    .filter_map(|index| {
+                        let polarity = cube.literal(index, self.form)?;
+                        let bit = 1 << (self.variables.len() - index - 1);
+
+                        Some(LiteralSensitization {
+                            variable: self.variables[index].clone(),
+                            polarity,
+                            terms: cube
+                                .terms()
+                                .into_iter()
+                                .filter(|&term| {
+                                    self.evaluate_term(term) != self.evaluate_term(term ^ bit)
+                                })
+                                .collect(),
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the probability of the expression being 1, given the independent probabilities
+    /// of each variable being 1.
+    ///
+    /// The probability is computed exactly from the products (or sums) by Shannon expansion,
+    /// so overlapping products are not counted twice.
+    ///
+    /// Returns [`Error::InvalidProbabilities`] if there is not exactly one probability between 0 and 1 for each variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// // A ∨ B
+    /// let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 2, 3], &[0])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .pop()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(solution.probability(&[0.5, 0.5]).unwrap(), 0.75);
+    /// ```
+    pub fn probability(&self, one_probabilities: &[f64]) -> Result<f64, Error> {
+        validate_probabilities(&self.variables, one_probabilities)?;
+
+        let probability = cover_probability(&self.implicants, one_probabilities, 0);
+
+        Ok(match self.form {
+            Form::SOP => probability,
+            Form::POS => 1.0 - probability,
+        })
+    }
+
+    /// Returns the minimized disjunction of the two expressions over the union of their variables.
+    ///
+    /// The variables of `self` come first, followed by the variables of `other` that are not in `self`,
+    /// and the result is in the form of `self`.
+    ///
+    /// Constants may have no variables, e.g. the result of [`cofactor`](Self::cofactor) fixing all of them,
+    /// in which case the result has no variables either if `other` has none.
+    ///
+    /// Returns [`Error::InvalidVariableCount`] if there are more than `DEFAULT_VARIABLES.len()`
+    /// variables in the union.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let a_and_b = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1, 2])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .pop()
+    ///     .unwrap();
+    /// let a_and_not_b = qmc::BooleanFunction::new(&["A", "B"], &[2], &[0, 1, 3])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .pop()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(a_and_b.or(&a_and_not_b).unwrap().to_string(), "A");
+    /// ```
+    pub fn or(&self, other: &Cover) -> Result<Cover, Error> {
+        self.compose(other, |a, b| a || b)
+    }
+
+    /// Returns the minimized conjunction of the two expressions over the union of their variables,
+    /// see [`or`](Self::or).
+    pub fn and(&self, other: &Cover) -> Result<Cover, Error> {
+        self.compose(other, |a, b| a && b)
+    }
+
+    /// Returns the minimized exclusive disjunction of the two expressions over the union of their variables,
+    /// see [`or`](Self::or).
+    pub fn xor(&self, other: &Cover) -> Result<Cover, Error> {
+        self.compose(other, |a, b| a != b)
+    }
+
+    fn compose(
+        &self,
+        other: &Cover,
+        operator: impl Fn(bool, bool) -> bool,
+    ) -> Result<Cover, Error> {
+        let mut variables = self.variables.clone();
+
+        for variable in &other.variables {
+            if !variables.contains(variable) {
+                variables.push(variable.clone());
+            }
+        }
+
+        if variables.len() > DEFAULT_VARIABLES.len() {
+            return Err(Error::InvalidVariableCount(variables.len()));
+        }
+
+        // Constants without variables can't be minimized again.
+        if variables.is_empty() {
+            let output = operator(self.evaluate_term(0), other.evaluate_term(0));
+            let implicants = if output == (self.form == Form::SOP) {
+                vec![Implicant::new(0)]
+            } else {
+                vec![]
+            };
+
+            return Ok(Cover::new(&implicants, &[], self.form));
+        }
+
+        let variable_count = variables.len();
+        let other_positions = Vec::from_iter(other.variables.iter().map(|variable| {
+            variable_count - variables.iter().position(|name| name == variable).unwrap() - 1
+        }));
+
+        let (minterms, maxterms): (Vec<u32>, Vec<u32>) =
+            (0..1 << variable_count).partition(|&term| {
+                let self_term = term >> (variable_count - self.variables.len());
+                let other_term = other_positions
+                    .iter()
+                    .fold(0, |other_term, &bit| other_term << 1 | term >> bit & 1);
+
+                operator(
+                    self.evaluate_term(self_term),
+                    other.evaluate_term(other_term),
+                )
+            });
+
+        Ok(BooleanFunction::new(&variables, &minterms, &maxterms)?
+            .minimize(self.form, false, None)?
+            .remove(0))
+    }
+
+    fn variable_bit(&self, variable: &str) -> Result<u32, Error> {
+        let index = self
+            .variable_index(variable)
+            .ok_or_else(|| Error::UnknownVariable(variable.to_owned()))?;
+
+        Ok(1 << (self.variables.len() - index - 1))
+    }
+
+    /// Returns the output of the expression for the given value of each variable in order.
+    ///
+    /// Use [`compile`](Self::compile) to evaluate the same expression many times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one value for each variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert!(solution.evaluate(&[true, true, false]));
+    /// assert!(!solution.evaluate(&[true, false, true]));
+    /// ```
+    pub fn evaluate(&self, assignment: &[bool]) -> bool {
+        assert_eq!(
+            assignment.len(),
+            self.variables.len(),
+            "expected a value for each variable"
+        );
+
+        self.evaluate_term(
+            assignment
+                .iter()
+                .fold(0, |term, &value| term << 1 | value as u32),
+        )
+    }
+
+    /// Returns the output of the expression for the values of the variables given by name.
+    ///
+    /// Names that are not variables of the expression are ignored, so the same map can be used for
+    /// the solutions of several outputs.
+    ///
+    /// Returns [`Error::MissingVariable`] if a variable has no value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    /// let assignment = HashMap::from([("A", false), ("B", true), ("C", true), ("D", false)]);
+    ///
+    /// assert!(solution.evaluate_map(&assignment).unwrap());
+    /// ```
+    pub fn evaluate_map<S: Borrow<str> + Eq + Hash>(
+        &self,
+        assignment: &HashMap<S, bool>,
+    ) -> Result<bool, Error> {
+        let mut term = 0;
+
+        for variable in &self.variables {
+            let value = assignment
+                .get(variable.as_str())
+                .ok_or_else(|| Error::MissingVariable(variable.clone()))?;
+
+            term = term << 1 | *value as u32;
+        }
+
+        Ok(self.evaluate_term(term))
+    }
+
+    /// Returns a closure that evaluates the expression for the given value of each variable.
+    ///
+    /// The products (or sums) are precomputed as masks, so an evaluation packs the inputs into a term
+    /// and compares it with each mask, without walking the structure of the expression.
+    /// The closure panics if there is not exactly one input for each variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    /// let evaluate = solution.compile();
+    ///
+    /// assert!(evaluate(&[true, true, false]));
+    /// assert!(!evaluate(&[true, false, true]));
+    /// ```
+    pub fn compile(&self) -> impl Fn(&[bool]) -> bool {
+        let variable_count = self.variables.len();
+        let variable_mask = (1 << variable_count) - 1;
+        // The bits each implicant depends on and their values.
+        let cubes = Vec::from_iter(
+            self.implicants
+                .iter()
+                .map(|implicant| (!implicant.mask() & variable_mask, implicant.value())),
+        );
+        let covered_output = self.form == Form::SOP;
+
+        move |inputs: &[bool]| {
+            assert_eq!(
+                inputs.len(),
+                variable_count,
+                "expected an input for each variable"
+            );
+
+            let term = inputs
+                .iter()
+                .fold(0, |term, &input| term << 1 | input as u32);
+
+            cubes.iter().any(|&(care, value)| term & care == value) == covered_output
+        }
+    }
+
+    /// Evaluates the expression for 64 input vectors at once.
+    ///
+    /// `lanes` has a word for each variable whose bit `i` is the value of the variable in the `i`-th input vector,
+    /// and bit `i` of the returned word is the output for that vector. Each product (or sum) takes a word operation
+    /// per literal regardless of the number of vectors, which makes this suitable for simulating the expression
+    /// over large data sets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one lane for each variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 2], &[0, 3])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// // The four input vectors (A, B) = (0, 0), (1, 0), (0, 1) and (1, 1) in bits 0 to 3.
+    /// let a = 0b1010;
+    /// let b = 0b1100;
+    ///
+    /// assert_eq!(solution.evaluate_batch(&[a, b]) & 0b1111, 0b0110);
+    /// ```
+    pub fn evaluate_batch(&self, lanes: &[u64]) -> u64 {
+        assert_eq!(
+            lanes.len(),
+            self.variables.len(),
+            "expected a lane for each variable"
+        );
+
+        let variable_count = lanes.len();
+        let covered = self.implicants.iter().fold(0, |covered, implicant| {
+            let contained = lanes
+                .iter()
+                .enumerate()
+                .fold(!0, |contained, (index, &lane)| {
+                    let bit = 1 << (variable_count - index - 1);
+
+                    if implicant.mask() & bit != 0 {
+                        contained
+                    } else if implicant.value() & bit != 0 {
+                        contained & lane
+                    } else {
+                        contained & !lane
+                    }
+                });
+
+            covered | contained
+        });
+
+        match self.form {
+            Form::SOP => covered,
+            Form::POS => !covered,
+        }
+    }
+
+    /// Returns the truth table of the given `function` with the outputs of the solution side by side.
+    ///
+    /// Each column but the last is at least `width` characters wide and as wide as its header, so a `width` of 0
+    /// gives the most compact table. Care terms for which the solution's output differs from the function's
+    /// are marked with `✗`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the variables of the solution and the function are not the same.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1]).unwrap();
+    /// let solution = function.minimize(qmc::SOP, false, None).unwrap().pop().unwrap();
+    ///
+    /// assert_eq!(
+    ///     solution.to_truth_table_string(&function, 0),
+    ///     "\
+    /// A B | Function | Solution
+    /// 0 0 | 0        | 0
+    /// 0 1 | 0        | 0
+    /// 1 0 | X        | 1
+    /// 1 1 | 1        | 1
+    /// "
+    /// );
+    /// assert_eq!(
+    ///     solution.to_truth_table_string(&function, 3),
+    ///     "\
+    /// A   B   | Function | Solution
+    /// 0   0   | 0        | 0
+    /// 0   1   | 0        | 0
+    /// 1   0   | X        | 1
+    /// 1   1   | 1        | 1
+    /// "
+    /// );
+    /// ```
+    pub fn to_truth_table_string(&self, function: &BooleanFunction, width: usize) -> String {
+        assert_eq!(
+            self.variables,
+            function.variables(),
+            "solution and function have different variables"
+        );
+
+        let variable_widths = Vec::from_iter(
+            self.variables
+                .iter()
+                .map(|variable| variable.len().max(width)),
+        );
+        let function_width = "Function".len().max(width);
+
+        let mut string = String::new();
+
+        for (variable, &variable_width) in self.variables.iter().zip(&variable_widths) {
+            string.push_str(&format!("{:<width$} ", variable, width = variable_width));
+        }
+
+        string.push_str(&format!(
+            "| {:<width$} | Solution\n",
+            "Function",
+            width = function_width
+        ));
+
+        for term in 0..1 << self.variables.len() {
+            for (i, &variable_width) in variable_widths.iter().enumerate() {
+                let bit = (term >> (self.variables.len() - i - 1)) & 1;
+                string.push_str(&format!("{:<width$} ", bit, width = variable_width));
+            }
+
+            let output = self.evaluate_term(term);
+            let expected_output = function.evaluate(term);
+            let expected_output = match expected_output {
+                Some(expected_output) => (expected_output as u8).to_string(),
+                None => "X".to_owned(),
+            };
+
+            string.push_str(&format!(
+                "| {:<width$} | {}",
+                expected_output,
+                output as u8,
+                width = function_width
+            ));
+
+            if function
+                .evaluate(term)
+                .map_or(false, |expected| expected != output)
+            {
+                string.push_str(" ✗");
+            }
+
+            string.push('\n');
+        }
 
-        let is_one = if expression.is_empty() {
-            form == Form::POS
-        } else if expression[0].is_empty() {
-            form == Form::SOP
-        } else {
-            false
+        string
+    }
+
+    /// Returns the truth table of the expression as a Markdown table like the ones in the crate documentation,
+    /// with an `Output` column of 1 and 0 for all terms in ascending order.
+    ///
+    /// Since the expression is fully specified, the don't cares of the function it was minimized from
+    /// are shown with the outputs chosen by the products (or sums).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(
+    ///     solution.to_markdown_table(),
+    ///     "\
+    /// | A | B | Output |
+    /// |:-:|:-:|:------:|
+    /// | 0 | 0 | 0      |
+    /// | 0 | 1 | 0      |
+    /// | 1 | 0 | 1      |
+    /// | 1 | 1 | 1      |
+    /// "
+    /// );
+    /// ```
+    pub fn to_markdown_table(&self) -> String {
+        let mut headers =
+            Vec::from_iter(self.variables.iter().map(|name| name.replace('|', "\\|")));
+        headers.push("Output".to_owned());
+
+        let widths = Vec::from_iter(headers.iter().map(|header| header.chars().count()));
+        let row = |cells: &[String]| {
+            let cells = Vec::from_iter(
+                cells
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, &width)| format!(" {:<width$} ", cell, width = width)),
+            );
+
+            format!("|{}|\n", cells.join("|"))
         };
 
-        let is_zero = if expression.is_empty() {
-            form == Form::SOP
-        } else if expression[0].is_empty() {
-            form == Form::POS
+        let mut table = row(&headers);
+        let separators = Vec::from_iter(
+            widths
+                .iter()
+                .map(|&width| format!(":{}:", "-".repeat(width))),
+        );
+        table.push_str(&format!("|{}|\n", separators.join("|")));
+
+        let variable_count = self.variables.len();
+
+        for term in 0..1 << variable_count {
+            let mut cells = Vec::from_iter(
+                (0..variable_count).map(|i| (term >> (variable_count - i - 1) & 1).to_string()),
+            );
+            cells.push((self.evaluate_term(term) as u8).to_string());
+
+            table.push_str(&row(&cells));
+        }
+
+        table
+    }
+
+    /// Returns the [`Certificate`] attached by [`BooleanFunction::certify`](crate::BooleanFunction::certify), if any.
+    pub fn certificate(&self) -> Option<&Certificate> {
+        self.certificate.as_ref()
+    }
+
+    pub(crate) fn set_certificate(&mut self, certificate: Certificate) {
+        self.certificate = Some(certificate);
+    }
+
+    /// Returns the expression as a single-output PLA file with the output named `F0`.
+    ///
+    /// See [`solutions_to_pla`](crate::solutions_to_pla) for the format and for writing several solutions as one file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 2], &[0, 3])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(
+    ///     solution.to_pla(),
+    ///     ".i 2\n.o 1\n.ilb A B\n.ob F0\n.p 2\n10 1\n01 1\n.e\n"
+    /// );
+    /// ```
+    pub fn to_pla(&self) -> String {
+        write_pla(std::slice::from_ref(self), &["F0".to_owned()])
+    }
+
+    /// Returns the expression as a BLIF `.names` table with the variables as inputs and the given `output`,
+    /// which can be added to a model of a synthesis netlist.
+    ///
+    /// The rows are the products of an expression in [`SOP`](Form::SOP) form with the output value `1`
+    /// and the sums of an expression in [`POS`](Form::POS) form with the output value `0`,
+    /// and [`Blif`](crate::Blif) reads them back as the same function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_blif("f"), ".names A B C f\n11- 1\n0-1 1\n");
+    /// ```
+    pub fn to_blif(&self, output: &str) -> String {
+        write_names(self, output)
+    }
+
+    /// Returns the expression as a C expression like `(A && B) || (!A && C)`,
+    /// or like `(A & B) | (~A & C)` with the constant 1 written as `~0` if `bitwise` is `true`.
+    ///
+    /// Names that are not C identifiers are made ones by replacing invalid characters with `_`,
+    /// prefixing a leading digit with `_` and suffixing keywords with `_`. A name that would become the same
+    /// identifier as an earlier variable is suffixed with `_2`, `_3` and so on first, so `a b` and `a_b` are
+    /// written as `a_b` and `a_b_2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_c_expr(false), "(A && B) || (!A && C)");
+    /// assert_eq!(solution.to_c_expr(true), "(A & B) | (~A & C)");
+    /// ```
+    pub fn to_c_expr(&self, bitwise: bool) -> String {
+        if bitwise {
+            self.to_string_with(
+                ExpressionStyle::c_style()
+                    .with_and("&")
+                    .with_or("|")
+                    .with_negation(Negation::Prefix("~".to_owned()))
+                    .with_one("~0"),
+            )
         } else {
-            false
+            self.to_string_with(Style::CStyle)
+        }
+    }
+
+    /// Returns the expression written in the given `style` instead of the one used by [`Display`],
+    /// see [`ExpressionStyle`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 5], &[1, 3, 4, 6])
+    ///     .unwrap()
+    ///     .minimize(qmc::POS, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_string_with(qmc::ExpressionStyle::ascii()), "(A | ~C) & (~A | C)");
+    /// ```
+    pub fn to_string_with(&self, style: impl Into<ExpressionStyle>) -> String {
+        let style = style.into();
+        let identifiers = style.identifiers.identifiers(&self.variables);
+
+        self.to_infix_with(
+            |name| identifiers[self.variable_index(name).unwrap()].clone(),
+            |identifier| style.negation.negate(identifier),
+            [
+                &style.one,
+                &style.zero,
+                &style.operator(&style.and),
+                &style.operator(&style.or),
+                "(",
+                ")",
+            ],
+        )
+    }
+
+    /// Returns the expression as LaTeX math like `(A \land B) \lor (\overline{A} \land C)`.
+    ///
+    /// Names of more than one character are written upright with `\mathrm` and characters that are special
+    /// in LaTeX are escaped. The result is meant to be put in a math environment such as `$...$`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_latex(), r"(A \land B) \lor (\overline{A} \land C)");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        self.to_infix_with(
+            latex_identifier,
+            |identifier| format!("\\overline{{{}}}", identifier),
+            ["1", "0", " \\land ", " \\lor ", "(", ")"],
+        )
+    }
+
+    /// Returns the expression as a MathML `<math>` element, with negated variables overlined
+    /// and `∧` and `∨` as the operators.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 3], &[0, 2])
+    ///     .unwrap()
+    ///     .minimize(qmc::POS, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(
+    ///     solution.to_mathml(),
+    ///     r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mrow><mi>B</mi></mrow></math>"#
+    /// );
+    /// ```
+    pub fn to_mathml(&self) -> String {
+        let expression = self.to_infix_with(
+            |name| format!("<mi>{}</mi>", escape(name)),
+            |identifier| format!("<mover accent=\"true\">{}<mo>‾</mo></mover>", identifier),
+            [
+                "<mn>1</mn>",
+                "<mn>0</mn>",
+                "<mo>∧</mo>",
+                "<mo>∨</mo>",
+                "<mrow><mo>(</mo>",
+                "<mo>)</mo></mrow>",
+            ],
+        );
+
+        format!(
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mrow>{}</mrow></math>",
+            expression
+        )
+    }
+
+    /// Returns the two-level gate network of the expression as a Graphviz DOT graph with the given `output`.
+    ///
+    /// The inputs `x0`, `x1`, ... fan out to the gates that use them, negated variables go through
+    /// a shared `NOT` gate `n0`, `n1`, ..., each product (or sum) of more than one literal is a gate `t0`, `t1`, ...
+    /// and the gate `o` combines more than one of them into the output `y`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B"], &[2], &[0, 1, 3])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(
+    ///     solution.to_dot("F"),
+    ///     r#"digraph {
+    ///   rankdir=LR;
+    ///   x0 [label="A", shape=plaintext];
+    ///   x1 [label="B", shape=plaintext];
+    ///   n1 [label="NOT", shape=box];
+    ///   t0 [label="AND", shape=box];
+    ///   y [label="F", shape=plaintext];
+    ///   x1 -> n1;
+    ///   x0 -> t0;
+    ///   n1 -> t0;
+    ///   t0 -> y;
+    /// }
+    /// "#
+    /// );
+    /// ```
+    pub fn to_dot(&self, output: &str) -> String {
+        let (inner_gate, outer_gate) = match self.form {
+            Form::SOP => ("AND", "OR"),
+            Form::POS => ("OR", "AND"),
         };
+        let mut inverters = BTreeSet::new();
+        let mut gates = vec![];
+        let mut edges = vec![];
+        let mut sources = vec![];
 
-        if is_one {
-            Solution::One
-        } else if is_zero {
-            Solution::Zero
-        } else if form == Form::SOP {
-            Solution::SOP(expression)
+        for (i, literals) in self.literal_terms().iter().enumerate() {
+            let literals = Vec::from_iter(literals.iter().map(|literal| {
+                if literal.polarity == Polarity::Negative {
+                    inverters.insert(literal.index);
+                    format!("n{}", literal.index)
+                } else {
+                    format!("x{}", literal.index)
+                }
+            }));
+
+            if let [literal] = literals.as_slice() {
+                sources.push(literal.clone());
+            } else {
+                gates.push(format!("t{} [label=\"{}\", shape=box];", i, inner_gate));
+                edges.extend(
+                    literals
+                        .iter()
+                        .map(|literal| format!("{} -> t{};", literal, i)),
+                );
+                sources.push(format!("t{}", i));
+            }
+        }
+
+        if sources.is_empty() {
+            let value = if self.is_one() { 1 } else { 0 };
+            gates.push(format!("c [label=\"{}\", shape=plaintext];", value));
+            sources.push("c".to_owned());
+        }
+
+        if let [source] = sources.as_slice() {
+            edges.push(format!("{} -> y;", source));
         } else {
-            Solution::POS(expression)
+            gates.push(format!("o [label=\"{}\", shape=box];", outer_gate));
+            edges.extend(sources.iter().map(|source| format!("{} -> o;", source)));
+            edges.push("o -> y;".to_owned());
         }
+
+        let mut lines = vec!["digraph {".to_owned(), "  rankdir=LR;".to_owned()];
+        lines.extend(self.variables.iter().enumerate().map(|(i, variable)| {
+            format!(
+                "  x{} [label={}, shape=plaintext];",
+                i,
+                dot_string(variable)
+            )
+        }));
+        lines.extend(
+            inverters
+                .iter()
+                .map(|i| format!("  n{} [label=\"NOT\", shape=box];", i)),
+        );
+        lines.extend(gates.iter().map(|gate| format!("  {}", gate)));
+        lines.push(format!(
+            "  y [label={}, shape=plaintext];",
+            dot_string(output)
+        ));
+        lines.extend(inverters.iter().map(|i| format!("  x{} -> n{};", i, i)));
+        lines.extend(edges.iter().map(|edge| format!("  {}", edge)));
+        lines.push("}\n".to_owned());
+
+        lines.join("\n")
     }
-}
 
-impl Display for Solution {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (expression, form) = match self {
-            Solution::One => return write!(f, "1"),
-            Solution::Zero => return write!(f, "0"),
-            Solution::SOP(expression) => (expression, Form::SOP),
-            Solution::POS(expression) => (expression, Form::POS),
-        };
+    /// Returns the expression as a Python expression like `(A and B) or (not A and C)`.
+    ///
+    /// Names that are not Python identifiers are made ones as in [`to_c_expr`](Self::to_c_expr),
+    /// and keywords are suffixed with `_`. Names that would become the same identifier are told apart
+    /// as in [`to_c_expr`](Self::to_c_expr) too, so `if` and `if_` are written as `if_` and `if__2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_python_expr(), "(A and B) or (not A and C)");
+    /// ```
+    pub fn to_python_expr(&self) -> String {
+        self.to_string_with(Style::Python)
+    }
+
+    /// Returns the expression in the syntax of the expression dialogs of circuit simulators such as
+    /// the combinational analysis window of Logisim-evolution and the expression dialog of Digital,
+    /// like `(A & B) | (~A & C)`.
+    ///
+    /// Names are made identifiers as in [`to_c_expr`](Self::to_c_expr), except that a bit index like in `S[1]`
+    /// is kept as read by [`TableExport`](crate::TableExport), and the operator words like `and` are suffixed with `_`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_logisim_expr(), "(A & B) | (~A & C)");
+    /// ```
+    pub fn to_logisim_expr(&self) -> String {
+        self.to_infix(logisim_identifier, ["1", "0", "~", " & ", " | "])
+    }
+
+    /// Returns the expression as a Rust closure taking a `bool` for each variable,
+    /// e.g. `|a: bool, b: bool, c: bool| (a && b) || (!a && c)`.
+    ///
+    /// Names are made snake case identifiers by lowercasing them, replacing invalid characters with `_` and
+    /// prefixing a leading digit with `_`. Keywords are written as raw identifiers, and variables that don't appear
+    /// in the expression are prefixed with `_`.
+    ///
+    /// Returns [`Error::UnsupportedName`] if two variables would become the same identifier,
+    /// e.g. `A` and `a` or `a b` and `a_b`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5, 7], &[0, 2, 4, 6])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_rust_closure().unwrap(), "|_a: bool, _b: bool, c: bool| c");
+    /// ```
+    pub fn to_rust_closure(&self) -> Result<String, Error> {
+        Ok(format!(
+            "|{}| {}",
+            self.rust_parameters()?,
+            self.to_infix(rust_identifier, ["true", "false", "!", " && ", " || "])
+        ))
+    }
+
+    /// Returns the expression as a Rust `const fn` of the given `name` taking a `bool` for each variable.
+    ///
+    /// Identifiers are written and checked as in [`to_rust_closure`](Self::to_rust_closure).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(
+    ///     solution.to_rust_const_fn("select").unwrap(),
+    ///     "const fn select(a: bool, b: bool, c: bool) -> bool {\n    (a && b) || (!a && c)\n}\n"
+    /// );
+    /// ```
+    pub fn to_rust_const_fn(&self, name: &str) -> Result<String, Error> {
+        Ok(format!(
+            "const fn {}({}) -> bool {{\n    {}\n}}\n",
+            rust_identifier(name),
+            self.rust_parameters()?,
+            self.to_infix(rust_identifier, ["true", "false", "!", " && ", " || "])
+        ))
+    }
+
+    /// Returns the expression as a Verilog module of the given `name` with an input for each variable,
+    /// including the ones that don't appear in the expression, and an output `F0` driven by an `assign` statement.
+    ///
+    /// Names that are not simple Verilog identifiers, including keywords, are written as escaped identifiers
+    /// like `\a[0] ` with their whitespace replaced by `_`.
+    ///
+    /// Returns [`Error::UnsupportedName`] if a name has characters other than printable ASCII and whitespace,
+    /// which escaped identifiers can't contain, or if two ports would have the same identifier,
+    /// e.g. for the variables `a b` and `a_b` or a variable `F0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(
+    ///     solution.to_verilog("mux").unwrap(),
+    ///     "module mux(input A, input B, input C, output F0);\n  assign F0 = (A & B) | (~A & C);\nendmodule\n"
+    /// );
+    /// ```
+    pub fn to_verilog(&self, name: &str) -> Result<String, Error> {
+        let module = unique_verilog_identifiers([name], verilog_identifier)?.remove(0);
+        let mut identifiers = unique_verilog_identifiers(
+            self.variables
+                .iter()
+                .map(String::as_str)
+                .chain(std::iter::once("F0")),
+            verilog_identifier,
+        )?;
+        let output = identifiers.pop().unwrap();
+        let ports = Vec::from_iter(
+            identifiers
+                .iter()
+                .map(|identifier| format!("input {}", identifier))
+                .chain(std::iter::once(format!("output {}", output))),
+        );
+        let expression = self.to_string_with(Style::VerilogOps);
+
+        Ok(format!(
+            "module {}({});\n  assign {} = {};\nendmodule\n",
+            module,
+            ports.join(", "),
+            output,
+            expression
+        ))
+    }
 
-        for (i, variables) in expression.iter().enumerate() {
-            if expression.len() > 1 && variables.len() > 1 {
-                write!(f, "(")?;
+    /// Returns the expression as a SystemVerilog `function automatic` of the given `name` with a `logic` input
+    /// for each variable, including the ones that don't appear in the expression.
+    ///
+    /// If the expression has a [`Certificate`] attached by [`BooleanFunction::certify`], the don't cares
+    /// it outputs 1 and 0 for are summarized in a comment. Identifiers are written as in [`to_verilog`](Self::to_verilog),
+    /// with the SystemVerilog keywords escaped too.
+    ///
+    /// Returns [`Error::UnsupportedName`] for the names [`to_verilog`](Self::to_verilog) rejects, or if an input
+    /// would have the same identifier as the function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1]).unwrap();
+    /// let mut solution = function.minimize(qmc::SOP, false, None).unwrap().remove(0);
+    /// function.certify(&mut solution);
+    ///
+    /// assert_eq!(
+    ///     solution.to_systemverilog("f").unwrap(),
+    ///     "\
+    /// function automatic logic f(input logic A, B);
+    ///   // Don't cares assigned 1: 2
+    ///   return A;
+    /// endfunction
+    /// "
+    /// );
+    /// ```
+    pub fn to_systemverilog(&self, name: &str) -> Result<String, Error> {
+        let mut inputs = unique_verilog_identifiers(
+            std::iter::once(name).chain(self.variables.iter().map(String::as_str)),
+            systemverilog_identifier,
+        )?;
+        let name = inputs.remove(0);
+        let mut function = format!(
+            "function automatic logic {}(input logic {});\n",
+            name,
+            inputs.join(", ")
+        );
+
+        if let Some(certificate) = &self.certificate {
+            for (terms, output) in [(&certificate.ones, 1), (&certificate.zeros, 0)] {
+                if !terms.is_empty() {
+                    let terms = Vec::from_iter(terms.iter().map(u32::to_string));
+
+                    function.push_str(&format!(
+                        "  // Don't cares assigned {}: {}\n",
+                        output,
+                        terms.join(", ")
+                    ));
+                }
             }
+        }
+
+        function.push_str(&format!(
+            "  return {};\nendfunction\n",
+            self.to_infix(
+                systemverilog_identifier,
+                ["1'b1", "1'b0", "~", " & ", " | "]
+            )
+        ));
+        Ok(function)
+    }
+
+    /// Returns the expression as a VHDL entity of the given `name` with a `std_logic` input for each variable,
+    /// including the ones that don't appear in the expression, and an output `F0`, and its `rtl` architecture
+    /// driving the output with a concurrent signal assignment.
+    ///
+    /// Names that are not basic VHDL identifiers, including reserved words, are written as extended identifiers
+    /// like `\a[0]\`.
+    ///
+    /// Returns [`Error::UnsupportedName`] if a name has characters other than printable ASCII and spaces,
+    /// which extended identifiers can't contain, or if two of the entity and its ports would have the same
+    /// identifier, e.g. for the variables `A` and `a`, which are the same basic identifier as VHDL ignores case,
+    /// or a variable `f0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(
+    ///     solution.to_vhdl("mux").unwrap(),
+    ///     "\
+    /// library ieee;
+    /// use ieee.std_logic_1164.all;
+    ///
+    /// entity mux is
+    ///   port (A, B, C : in std_logic; F0 : out std_logic);
+    /// end entity mux;
+    ///
+    /// architecture rtl of mux is
+    /// begin
+    ///   F0 <= (A and B) or (not A and C);
+    /// end architecture rtl;
+    /// "
+    /// );
+    /// ```
+    pub fn to_vhdl(&self, name: &str) -> Result<String, Error> {
+        let mut inputs = unique_vhdl_identifiers(
+            std::iter::once(name)
+                .chain(self.variables.iter().map(String::as_str))
+                .chain(std::iter::once("F0")),
+        )?;
+        let name = inputs.remove(0);
+        inputs.pop();
+        let expression = self.to_infix(vhdl_identifier, ["'1'", "'0'", "not ", " and ", " or "]);
+
+        Ok(format!(
+            concat!(
+                "library ieee;\n",
+                "use ieee.std_logic_1164.all;\n",
+                "\n",
+                "entity {name} is\n",
+                "  port ({inputs} : in std_logic; F0 : out std_logic);\n",
+                "end entity {name};\n",
+                "\n",
+                "architecture rtl of {name} is\n",
+                "begin\n",
+                "  F0 <= {expression};\n",
+                "end architecture rtl;\n",
+            ),
+            name = name,
+            inputs = inputs.join(", "),
+            expression = expression
+        ))
+    }
+
+    /// Returns the expression as an SMT-LIB2 `define-fun` of the given `name`,
+    /// with a `Bool` parameter for each variable, including the ones that don't appear in the expression.
+    ///
+    /// Names that are not simple SMT-LIB symbols or are reserved words or Boolean operators like `and`
+    /// are written as quoted symbols like `|out 1|`. Returns [`Error::UnsupportedName`] if a name contains
+    /// `|` or `\`, which quoted symbols can't contain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_string(), "(A ∧ B) ∨ (~A ∧ C)");
+    /// assert_eq!(
+    ///     solution.to_smtlib("f").unwrap(),
+    ///     "(define-fun f ((A Bool) (B Bool) (C Bool)) Bool (or (and A B) (and (not A) C)))"
+    /// );
+    /// ```
+    pub fn to_smtlib(&self, name: &str) -> Result<String, Error> {
+        let symbols = self
+            .variables
+            .iter()
+            .map(|variable| smtlib_symbol(variable).map(|symbol| (variable, symbol)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let parameters = Vec::from_iter(
+            self.variables
+                .iter()
+                .map(|variable| format!("({} Bool)", symbols[variable])),
+        );
+        let body = if self.is_one() {
+            "true".to_owned()
+        } else if self.is_zero() {
+            "false".to_owned()
+        } else {
+            let (inner_operator, outer_operator) = match self.form {
+                Form::SOP => ("and", "or"),
+                Form::POS => ("or", "and"),
+            };
+            let terms = Vec::from_iter(self.terms().iter().map(|variables| {
+                let literals = Vec::from_iter(variables.iter().map(|variable| {
+                    let symbol = &symbols[&variable.name];
+
+                    if variable.is_negated {
+                        format!("(not {})", symbol)
+                    } else {
+                        symbol.clone()
+                    }
+                }));
+
+                smtlib_application(inner_operator, literals)
+            }));
+
+            smtlib_application(outer_operator, terms)
+        };
+
+        Ok(format!(
+            "(define-fun {} ({}) Bool {})",
+            smtlib_symbol(name)?,
+            parameters.join(" "),
+            body
+        ))
+    }
+
+    /// Returns an expression in [`POS`](Form::POS) form, which is a CNF, as a DIMACS CNF file for SAT solvers.
+    ///
+    /// The `i`th variable is numbered `i + 1`, and the header maps the numbers to the names in `c` comment lines
+    /// with line breaks in the names replaced by spaces. The clauses are the ones of [`clauses`](Self::clauses),
+    /// so the constant 1 has none and the constant 0 has a single empty clause.
+    ///
+    /// Returns [`Error::UnsupportedForm`] if the expression is not in [`POS`](Form::POS) form.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 5], &[1, 3, 4, 6])
+    ///     .unwrap()
+    ///     .minimize(qmc::POS, false, None)
+    ///     .unwrap()
+    ///     .remove(0);
+    ///
+    /// assert_eq!(solution.to_string(), "(A ∨ ~C) ∧ (~A ∨ C)");
+    /// assert_eq!(
+    ///     solution.to_dimacs().unwrap(),
+    ///     "c 1 A\nc 2 B\nc 3 C\np cnf 3 2\n1 -3 0\n-1 3 0\n"
+    /// );
+    /// ```
+    pub fn to_dimacs(&self) -> Result<String, Error> {
+        let clauses = self.clauses().ok_or(Error::UnsupportedForm(self.form))?;
+        let mut lines =
+            Vec::from_iter(self.variables.iter().enumerate().map(|(i, variable)| {
+                format!("c {} {}", i + 1, variable.replace(['\r', '\n'], " "))
+            }));
+
+        lines.push(format!("p cnf {} {}", self.variables.len(), clauses.len()));
+        lines.extend(clauses.iter().map(|clause| {
+            let mut numbers = Vec::from_iter(clause.literals.iter().map(|literal| {
+                let sign = match literal.polarity {
+                    Polarity::Positive => "",
+                    Polarity::Negative => "-",
+                };
+
+                format!("{}{}", sign, literal.index + 1)
+            }));
+
+            numbers.push("0".to_owned());
+            numbers.join(" ")
+        }));
+
+        Ok(lines.join("\n") + "\n")
+    }
+
+    /// Returns what is guaranteed about the expression, see [`Guarantees`].
+    pub fn guarantees(&self) -> Guarantees {
+        self.guarantees
+    }
+
+    pub(crate) fn set_optimality(&mut self, optimality: Optimality) {
+        self.guarantees.optimality = optimality;
+    }
+
+    /// Returns the conditions of the input that the expression is valid for but may not be what was meant,
+    /// see [`Warning`].
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    pub(crate) fn add_warning(&mut self, warning: Warning) {
+        self.warnings.push(warning);
+    }
+
+    /// Attaches the `value` to the expression under the `key`, returning the previous value of the `key`, if any.
+    ///
+    /// Annotations are arbitrary metadata, such as the source of the specification or a revision,
+    /// and are kept by `serde` serialization and [`complement`](Self::complement).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let mut solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 3], &[0, 2])
+    ///     .unwrap()
+    ///     .minimize(qmc::SOP, false, None)
+    ///     .unwrap()
+    ///     .pop()
+    ///     .unwrap();
+    ///
+    /// solution.annotate("revision", "3");
+    ///
+    /// assert_eq!(solution.annotation("revision"), Some("3"));
+    /// assert_eq!(solution.annotate("revision", "4"), Some("3".to_owned()));
+    /// assert_eq!(solution.complement().annotation("revision"), Some("4"));
+    /// ```
+    pub fn annotate(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.annotations.insert(key.into(), value.into())
+    }
+
+    /// Returns the value attached under the `key` by [`annotate`](Self::annotate), if any.
+    pub fn annotation(&self, key: &str) -> Option<&str> {
+        self.annotations.get(key).map(String::as_str)
+    }
+
+    /// Removes the annotation under the `key`, returning its value, if any.
+    pub fn remove_annotation(&mut self, key: &str) -> Option<String> {
+        self.annotations.remove(key)
+    }
+
+    /// Returns all annotations, ordered by key.
+    pub fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
 
-            for (j, variable) in variables.iter().enumerate() {
-                write!(f, "{}", variable)?;
+    pub(crate) fn implicants(&self) -> &[Implicant] {
+        &self.implicants
+    }
+
+    /// Returns the output of the expression for the given `term`.
+    pub(crate) fn evaluate_term(&self, term: u32) -> bool {
+        let is_covered = self
+            .implicants
+            .iter()
+            .any(|implicant| implicant.contains(term));
+
+        match self.form {
+            Form::SOP => is_covered,
+            Form::POS => !is_covered,
+        }
+    }
+
+    /// Returns the literals of each product (or sum) like [`terms`](Self::terms) does.
+    fn literal_terms(&self) -> Vec<Vec<Literal>> {
+        if self.is_one() || self.is_zero() {
+            return vec![];
+        }
 
-                if j < variables.len() - 1 {
-                    write!(f, " {} ", if form == Form::SOP { "∧" } else { "∨" })?;
+        Vec::from_iter(
+            self.implicants
+                .iter()
+                .map(|&implicant| self.literals(implicant)),
+        )
+    }
+
+    fn literals(&self, implicant: Implicant) -> Vec<Literal> {
+        let variable_count = self.variables.len();
+
+        (0..variable_count)
+            .filter_map(|index| {
+                implicant
+                    .literal(index, variable_count, self.form)
+                    .map(|polarity| Literal {
+                        index,
+                        variable: self.variables[index].clone(),
+                        polarity,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns a `bool` parameter for each variable, prefixed with `_` if it doesn't appear in the expression,
+    /// checking that no two of them have the same identifier.
+    fn rust_parameters(&self) -> Result<String, Error> {
+        let variable_count = self.variables.len();
+        let identifiers =
+            Vec::from_iter(self.variables.iter().enumerate().map(|(index, variable)| {
+                let is_used = self.implicants.iter().any(|implicant| {
+                    implicant
+                        .literal(index, variable_count, self.form)
+                        .is_some()
+                });
+                let identifier = rust_identifier(variable);
+
+                if is_used {
+                    identifier
+                } else {
+                    format!("_{}", identifier.trim_start_matches("r#"))
                 }
+            }));
+        let mut names_by_identifier = HashMap::new();
+
+        for (name, identifier) in self.variables.iter().zip(&identifiers) {
+            if let Some(other_name) = names_by_identifier.insert(identifier, name) {
+                return Err(Error::UnsupportedName(format!(
+                    "{:?} and {:?} are the same Rust identifier",
+                    other_name, name
+                )));
             }
+        }
 
-            if expression.len() > 1 && variables.len() > 1 {
-                write!(f, ")")?;
+        let parameters = Vec::from_iter(
+            identifiers
+                .iter()
+                .map(|identifier| format!("{}: bool", identifier)),
+        );
+
+        Ok(parameters.join(", "))
+    }
+
+    /// Returns the expression with the given spellings of the constants 1 and 0, the negation prefix
+    /// and the AND and OR operators, and the variables written as given by `identifier`.
+    /// Products (or sums) of more than one literal are parenthesized if there are several of them.
+    fn to_infix(
+        &self,
+        identifier: impl Fn(&str) -> String,
+        [one, zero, not, and, or]: [&str; 5],
+    ) -> String {
+        self.to_infix_with(
+            identifier,
+            |identifier| format!("{}{}", not, identifier),
+            [one, zero, and, or, "(", ")"],
+        )
+    }
+
+    /// Returns the expression like [`to_infix`](Self::to_infix) with negated variables written as given by `negate`
+    /// and the given spellings of the parentheses.
+    fn to_infix_with(
+        &self,
+        identifier: impl Fn(&str) -> String,
+        negate: impl Fn(String) -> String,
+        [one, zero, and, or, open, close]: [&str; 6],
+    ) -> String {
+        if self.is_one() {
+            return one.to_owned();
+        } else if self.is_zero() {
+            return zero.to_owned();
+        }
+
+        let (inner_operator, outer_operator) = match self.form {
+            Form::SOP => (and, or),
+            Form::POS => (or, and),
+        };
+        let terms = self.terms();
+
+        Vec::from_iter(terms.iter().map(|variables| {
+            let literals = Vec::from_iter(variables.iter().map(|variable| {
+                let identifier = identifier(&variable.name);
+
+                if variable.is_negated {
+                    negate(identifier)
+                } else {
+                    identifier
+                }
+            }));
+
+            if terms.len() > 1 && literals.len() > 1 {
+                format!("{}{}{}", open, literals.join(inner_operator), close)
+            } else {
+                literals.join(inner_operator)
             }
+        }))
+        .join(outer_operator)
+    }
+
+    /// Returns the number of literals of each product (or sum), or nothing for a constant.
+    fn term_literal_counts(&self) -> impl Iterator<Item = usize> + '_ {
+        let implicants = if self.is_constant() {
+            &[][..]
+        } else {
+            &self.implicants[..]
+        };
 
-            if i < expression.len() - 1 {
-                write!(f, " {} ", if form == Form::SOP { "∨" } else { "∧" })?;
+        implicants
+            .iter()
+            .map(|implicant| (self.variables.len() as u32 - implicant.wildcard_count()) as usize)
+    }
+
+    fn has_empty_term(&self) -> bool {
+        let variable_mask = (1 << self.variables.len()) - 1;
+
+        self.implicants
+            .iter()
+            .any(|implicant| implicant.mask() & variable_mask == variable_mask)
+    }
+}
+
+impl Display for Cover {
+    /// Writes the expression in the style set with [`set_default_format`](crate::set_default_format).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&with_default_format(|style| self.to_string_with(style)))
+    }
+}
+
+/// The reserved keywords of Verilog (IEEE 1364-2005).
+const VERILOG_KEYWORDS: &[&str] = &[
+    "always",
+    "and",
+    "assign",
+    "automatic",
+    "begin",
+    "buf",
+    "bufif0",
+    "bufif1",
+    "case",
+    "casex",
+    "casez",
+    "cell",
+    "cmos",
+    "config",
+    "deassign",
+    "default",
+    "defparam",
+    "design",
+    "disable",
+    "edge",
+    "else",
+    "end",
+    "endcase",
+    "endconfig",
+    "endfunction",
+    "endgenerate",
+    "endmodule",
+    "endprimitive",
+    "endspecify",
+    "endtable",
+    "endtask",
+    "event",
+    "for",
+    "force",
+    "forever",
+    "fork",
+    "function",
+    "generate",
+    "genvar",
+    "highz0",
+    "highz1",
+    "if",
+    "ifnone",
+    "incdir",
+    "include",
+    "initial",
+    "inout",
+    "input",
+    "instance",
+    "integer",
+    "join",
+    "large",
+    "liblist",
+    "library",
+    "localparam",
+    "macromodule",
+    "medium",
+    "module",
+    "nand",
+    "negedge",
+    "nmos",
+    "nor",
+    "noshowcancelled",
+    "not",
+    "notif0",
+    "notif1",
+    "or",
+    "output",
+    "parameter",
+    "pmos",
+    "posedge",
+    "primitive",
+    "pull0",
+    "pull1",
+    "pulldown",
+    "pullup",
+    "pulsestyle_onevent",
+    "pulsestyle_ondetect",
+    "rcmos",
+    "real",
+    "realtime",
+    "reg",
+    "release",
+    "repeat",
+    "rnmos",
+    "rpmos",
+    "rtran",
+    "rtranif0",
+    "rtranif1",
+    "scalared",
+    "showcancelled",
+    "signed",
+    "small",
+    "specify",
+    "specparam",
+    "strong0",
+    "strong1",
+    "supply0",
+    "supply1",
+    "table",
+    "task",
+    "time",
+    "tran",
+    "tranif0",
+    "tranif1",
+    "tri",
+    "tri0",
+    "tri1",
+    "triand",
+    "trior",
+    "trireg",
+    "unsigned",
+    "use",
+    "uwire",
+    "vectored",
+    "wait",
+    "wand",
+    "weak0",
+    "weak1",
+    "while",
+    "wire",
+    "wor",
+    "xnor",
+    "xor",
+];
+
+/// Returns `name` as a simple Verilog identifier if it is one, and as an escaped identifier otherwise.
+pub(crate) fn verilog_identifier(name: &str) -> String {
+    let is_simple = name.starts_with(|char: char| char.is_ascii_alphabetic() || char == '_')
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$')
+        && !VERILOG_KEYWORDS.contains(&name);
+
+    if is_simple {
+        name.to_owned()
+    } else {
+        format!("\\{} ", name.replace(char::is_whitespace, "_"))
+    }
+}
+
+/// Returns the `names` as Verilog identifiers written by `identifier`, such as [`verilog_identifier`], checking that
+/// escaped identifiers can represent them and that no two of them are the same identifier.
+fn unique_verilog_identifiers<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+    identifier: impl Fn(&str) -> String,
+) -> Result<Vec<String>, Error> {
+    let mut identifiers = vec![];
+    let mut names_by_identifier = HashMap::new();
+
+    for name in names {
+        if !name
+            .chars()
+            .all(|char| char.is_ascii_graphic() || char.is_ascii_whitespace())
+        {
+            return Err(Error::UnsupportedName(format!(
+                "{:?} can't be a Verilog identifier",
+                name
+            )));
+        }
+
+        let identifier = identifier(name);
+        // An escaped identifier is the same as the simple identifier with the same characters.
+        let key = match identifier.strip_prefix('\\') {
+            Some(escaped) => escaped.trim_end().to_owned(),
+            None => identifier.clone(),
+        };
+
+        if let Some(other_name) = names_by_identifier.insert(key, name) {
+            return Err(Error::UnsupportedName(format!(
+                "{:?} and {:?} are the same Verilog identifier",
+                other_name, name
+            )));
+        }
+
+        identifiers.push(identifier);
+    }
+
+    Ok(identifiers)
+}
+
+/// The keywords of C (ISO/IEC 9899:2024).
+const C_KEYWORDS: &[&str] = &[
+    "alignas",
+    "alignof",
+    "auto",
+    "bool",
+    "break",
+    "case",
+    "char",
+    "const",
+    "constexpr",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "nullptr",
+    "register",
+    "restrict",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "static_assert",
+    "struct",
+    "switch",
+    "thread_local",
+    "true",
+    "typedef",
+    "typeof",
+    "typeof_unqual",
+    "union",
+    "unsigned",
+    "void",
+    "volatile",
+    "while",
+    "_Alignas",
+    "_Alignof",
+    "_Atomic",
+    "_BitInt",
+    "_Bool",
+    "_Complex",
+    "_Decimal128",
+    "_Decimal32",
+    "_Decimal64",
+    "_Generic",
+    "_Imaginary",
+    "_Noreturn",
+    "_Static_assert",
+    "_Thread_local",
+];
+
+/// The keywords of Python 3.
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Returns `name` as a C identifier, replacing invalid characters with `_`,
+/// prefixing a leading digit with `_` and suffixing keywords with `_`.
+pub(crate) fn c_identifier(name: &str) -> String {
+    identifier_with_keywords(name, C_KEYWORDS)
+}
+
+/// Returns `name` as a Python identifier like [`c_identifier`] does.
+pub(crate) fn python_identifier(name: &str) -> String {
+    identifier_with_keywords(name, PYTHON_KEYWORDS)
+}
+
+/// The operator words of the expression syntax of Logisim-evolution and Digital.
+const LOGISIM_KEYWORDS: &[&str] = &[
+    "and", "AND", "or", "OR", "not", "NOT", "xor", "XOR", "nand", "NAND", "nor", "NOR", "xnor",
+    "XNOR",
+];
+
+/// Returns `name` as an identifier of Logisim-evolution and Digital like [`c_identifier`] does,
+/// keeping a bit index like in `S[1]`.
+fn logisim_identifier(name: &str) -> String {
+    let bit_index = name
+        .strip_suffix(']')
+        .and_then(|name| name.rsplit_once('['))
+        .filter(|(base, index)| {
+            !base.is_empty() && !index.is_empty() && index.chars().all(|char| char.is_ascii_digit())
+        });
+
+    match bit_index {
+        Some((base, index)) => format!(
+            "{}[{}]",
+            identifier_with_keywords(base, LOGISIM_KEYWORDS),
+            index
+        ),
+        None => identifier_with_keywords(name, LOGISIM_KEYWORDS),
+    }
+}
+
+/// Returns `name` with characters other than ASCII letters and digits replaced by `_`,
+/// a leading digit prefixed with `_` and the `keywords` suffixed with `_`.
+fn identifier_with_keywords(name: &str, keywords: &[&str]) -> String {
+    let mut identifier = String::from_iter(name.chars().map(|char| {
+        if char.is_ascii_alphanumeric() {
+            char
+        } else {
+            '_'
+        }
+    }));
+
+    if identifier.starts_with(|char: char| char.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+
+    if keywords.contains(&identifier.as_str()) {
+        identifier.push('_');
+    }
+
+    identifier
+}
+
+/// Returns `text` as a quoted DOT string.
+fn dot_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns `name` as a LaTeX math identifier, escaping special characters and writing names
+/// of more than one character upright.
+fn latex_identifier(name: &str) -> String {
+    let mut escaped = String::new();
+
+    for char in name.chars() {
+        match char {
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(char);
             }
+            '\\' => escaped.push_str("\\backslash{}"),
+            '~' => escaped.push_str("\\sim{}"),
+            '^' => escaped.push_str("\\hat{}"),
+            _ => escaped.push(char),
+        }
+    }
+
+    if name.chars().count() > 1 {
+        format!("\\mathrm{{{}}}", escaped)
+    } else {
+        escaped
+    }
+}
+
+/// The strict and reserved keywords of Rust.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Returns `name` as a snake case Rust identifier, replacing invalid characters with `_`,
+/// prefixing a leading digit with `_` and writing keywords as raw identifiers.
+fn rust_identifier(name: &str) -> String {
+    let mut identifier = String::from_iter(name.chars().map(|char| {
+        if char.is_ascii_alphanumeric() {
+            char.to_ascii_lowercase()
+        } else {
+            '_'
+        }
+    }));
+
+    if identifier.starts_with(|char: char| char.is_ascii_digit()) || identifier == "_" {
+        identifier.insert(0, '_');
+    }
+
+    match identifier.as_str() {
+        // These can't be raw identifiers.
+        "crate" | "self" | "super" => identifier + "_",
+        _ if RUST_KEYWORDS.contains(&identifier.as_str()) => format!("r#{}", identifier),
+        _ => identifier,
+    }
+}
+
+/// The keywords SystemVerilog (IEEE 1800-2017) adds to [`VERILOG_KEYWORDS`].
+const SYSTEMVERILOG_KEYWORDS: &[&str] = &[
+    "accept_on",
+    "alias",
+    "always_comb",
+    "always_ff",
+    "always_latch",
+    "assert",
+    "assume",
+    "before",
+    "bind",
+    "bins",
+    "binsof",
+    "bit",
+    "break",
+    "byte",
+    "chandle",
+    "checker",
+    "class",
+    "clocking",
+    "const",
+    "constraint",
+    "context",
+    "continue",
+    "cover",
+    "covergroup",
+    "coverpoint",
+    "cross",
+    "dist",
+    "do",
+    "endchecker",
+    "endclass",
+    "endclocking",
+    "endgroup",
+    "endinterface",
+    "endpackage",
+    "endprogram",
+    "endproperty",
+    "endsequence",
+    "enum",
+    "eventually",
+    "expect",
+    "export",
+    "extends",
+    "extern",
+    "final",
+    "first_match",
+    "foreach",
+    "forkjoin",
+    "global",
+    "iff",
+    "ignore_bins",
+    "illegal_bins",
+    "implements",
+    "implies",
+    "import",
+    "inside",
+    "int",
+    "interconnect",
+    "interface",
+    "intersect",
+    "join_any",
+    "join_none",
+    "let",
+    "local",
+    "logic",
+    "longint",
+    "matches",
+    "modport",
+    "nettype",
+    "new",
+    "nexttime",
+    "null",
+    "package",
+    "packed",
+    "priority",
+    "program",
+    "property",
+    "protected",
+    "pure",
+    "rand",
+    "randc",
+    "randcase",
+    "randsequence",
+    "ref",
+    "reject_on",
+    "restrict",
+    "return",
+    "s_always",
+    "s_eventually",
+    "s_nexttime",
+    "s_until",
+    "s_until_with",
+    "sequence",
+    "shortint",
+    "shortreal",
+    "soft",
+    "solve",
+    "static",
+    "string",
+    "strong",
+    "struct",
+    "super",
+    "sync_accept_on",
+    "sync_reject_on",
+    "tagged",
+    "this",
+    "throughout",
+    "timeprecision",
+    "timeunit",
+    "type",
+    "typedef",
+    "union",
+    "unique",
+    "unique0",
+    "until",
+    "until_with",
+    "untyped",
+    "var",
+    "virtual",
+    "void",
+    "wait_order",
+    "weak",
+    "wildcard",
+    "with",
+    "within",
+];
+
+/// Returns `name` as a simple SystemVerilog identifier if it is one, and as an escaped identifier otherwise.
+fn systemverilog_identifier(name: &str) -> String {
+    if SYSTEMVERILOG_KEYWORDS.contains(&name) {
+        format!("\\{} ", name)
+    } else {
+        verilog_identifier(name)
+    }
+}
+
+/// The reserved words of VHDL (IEEE 1076-2008).
+const VHDL_RESERVED_WORDS: &[&str] = &[
+    "abs",
+    "access",
+    "after",
+    "alias",
+    "all",
+    "and",
+    "architecture",
+    "array",
+    "assert",
+    "assume",
+    "assume_guarantee",
+    "attribute",
+    "begin",
+    "block",
+    "body",
+    "buffer",
+    "bus",
+    "case",
+    "component",
+    "configuration",
+    "constant",
+    "context",
+    "cover",
+    "default",
+    "disconnect",
+    "downto",
+    "else",
+    "elsif",
+    "end",
+    "entity",
+    "exit",
+    "fairness",
+    "file",
+    "for",
+    "force",
+    "function",
+    "generate",
+    "generic",
+    "group",
+    "guarded",
+    "if",
+    "impure",
+    "in",
+    "inertial",
+    "inout",
+    "is",
+    "label",
+    "library",
+    "linkage",
+    "literal",
+    "loop",
+    "map",
+    "mod",
+    "nand",
+    "new",
+    "next",
+    "nor",
+    "not",
+    "null",
+    "of",
+    "on",
+    "open",
+    "or",
+    "others",
+    "out",
+    "package",
+    "parameter",
+    "port",
+    "postponed",
+    "procedure",
+    "process",
+    "property",
+    "protected",
+    "pure",
+    "range",
+    "record",
+    "register",
+    "reject",
+    "release",
+    "rem",
+    "report",
+    "restrict",
+    "restrict_guarantee",
+    "return",
+    "rol",
+    "ror",
+    "select",
+    "sequence",
+    "severity",
+    "shared",
+    "signal",
+    "sla",
+    "sll",
+    "sra",
+    "srl",
+    "strong",
+    "subtype",
+    "then",
+    "to",
+    "transport",
+    "type",
+    "unaffected",
+    "units",
+    "until",
+    "use",
+    "variable",
+    "vmode",
+    "vprop",
+    "vunit",
+    "wait",
+    "when",
+    "while",
+    "with",
+    "xnor",
+    "xor",
+];
+
+/// Returns `name` as a basic VHDL identifier if it is one, and as an extended identifier otherwise.
+fn vhdl_identifier(name: &str) -> String {
+    let is_basic = name.starts_with(|char: char| char.is_ascii_alphabetic())
+        && !name.ends_with('_')
+        && !name.contains("__")
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_')
+        && !VHDL_RESERVED_WORDS.contains(&name.to_ascii_lowercase().as_str());
+
+    if is_basic {
+        name.to_owned()
+    } else {
+        format!("\\{}\\", name.replace('\\', "\\\\"))
+    }
+}
+
+/// Returns the `names` as VHDL identifiers like [`vhdl_identifier`] does, checking that extended identifiers
+/// can represent them and that no two of them are the same identifier.
+fn unique_vhdl_identifiers<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<String>, Error> {
+    let mut identifiers = vec![];
+    let mut names_by_identifier = HashMap::new();
+
+    for name in names {
+        if !name
+            .chars()
+            .all(|char| char.is_ascii_graphic() || char == ' ')
+        {
+            return Err(Error::UnsupportedName(format!(
+                "{:?} can't be a VHDL identifier",
+                name
+            )));
+        }
+
+        let identifier = vhdl_identifier(name);
+        // Basic identifiers are case-insensitive, while extended identifiers are not.
+        let key = if identifier.starts_with('\\') {
+            identifier.clone()
+        } else {
+            identifier.to_ascii_lowercase()
+        };
+
+        if let Some(other_name) = names_by_identifier.insert(key, name) {
+            return Err(Error::UnsupportedName(format!(
+                "{:?} and {:?} are the same VHDL identifier",
+                other_name, name
+            )));
         }
 
-        Ok(())
+        identifiers.push(identifier);
+    }
+
+    Ok(identifiers)
+}
+
+/// The reserved words of SMT-LIB and the symbols of the Core theory, which are quoted so that they are not
+/// mistaken for the keywords or the Boolean operators.
+const SMTLIB_RESERVED_WORDS: &[&str] = &[
+    "!",
+    "_",
+    "as",
+    "BINARY",
+    "DECIMAL",
+    "exists",
+    "HEXADECIMAL",
+    "forall",
+    "let",
+    "match",
+    "NUMERAL",
+    "par",
+    "STRING",
+    "Bool",
+    "true",
+    "false",
+    "not",
+    "and",
+    "or",
+    "xor",
+    "=>",
+    "=",
+    "distinct",
+    "ite",
+];
+
+/// Returns `name` as a simple SMT-LIB symbol if it is one, and as a quoted symbol otherwise.
+fn smtlib_symbol(name: &str) -> Result<String, Error> {
+    if name.contains(&['|', '\\'][..]) {
+        return Err(Error::UnsupportedName(format!(
+            "{:?} can't be an SMT-LIB symbol",
+            name
+        )));
+    }
+
+    let is_simple = !name.starts_with(|char: char| char.is_ascii_digit())
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || "~!@$%^&*_-+=<>.?/".contains(char))
+        && !SMTLIB_RESERVED_WORDS.contains(&name);
+
+    if is_simple {
+        Ok(name.to_owned())
+    } else {
+        Ok(format!("|{}|", name))
+    }
+}
+
+/// Returns the application of the n-ary `operator` to the `arguments`, or the argument itself if there is only one.
+fn smtlib_application(operator: &str, mut arguments: Vec<String>) -> String {
+    if arguments.len() == 1 {
+        arguments.pop().unwrap()
+    } else {
+        format!("({} {})", operator, arguments.join(" "))
+    }
+}
+
+/// Returns whether the `implicants` cover all terms of the `variable_count` variables,
+/// splitting on the variables they depend on until an implicant covers all terms or none remain.
+pub(crate) fn covers_all_terms(implicants: &[Implicant], variable_count: u32) -> bool {
+    let full_mask = (1u32 << variable_count).wrapping_sub(1);
+
+    if implicants
+        .iter()
+        .any(|implicant| implicant.mask() & full_mask == full_mask)
+    {
+        return true;
     }
+
+    let dependent_bits = implicants
+        .iter()
+        .fold(0, |bits, implicant| bits | !implicant.mask() & full_mask);
+
+    if implicants.is_empty() || dependent_bits == 0 {
+        return false;
+    }
+
+    let bit = 1 << (31 - dependent_bits.leading_zeros());
+
+    [0, bit].iter().all(|&value| {
+        let cofactor = Vec::from_iter(
+            implicants
+                .iter()
+                .filter(|implicant| implicant.mask() & bit != 0 || implicant.value() & bit == value)
+                .map(|implicant| Implicant::from_parts(implicant.value(), implicant.mask() | bit)),
+        );
+
+        covers_all_terms(&cofactor, variable_count)
+    })
+}
+
+/// Returns the probability of a term being in any of the `implicants`, expanding the variables from `index` on.
+fn cover_probability(implicants: &[Implicant], one_probabilities: &[f64], index: usize) -> f64 {
+    let remaining_mask = (1 << (one_probabilities.len() - index)) - 1;
+
+    if implicants.is_empty() {
+        return 0.0;
+    } else if implicants
+        .iter()
+        .any(|implicant| implicant.mask() & remaining_mask == remaining_mask)
+    {
+        return 1.0;
+    }
+
+    let bit = 1 << (one_probabilities.len() - index - 1);
+    let cofactor =
+        |value: u32| {
+            Vec::from_iter(implicants.iter().copied().filter(|implicant| {
+                implicant.mask() & bit != 0 || implicant.value() & bit == value
+            }))
+        };
+
+    let probability = one_probabilities[index];
+
+    probability * cover_probability(&cofactor(bit), one_probabilities, index + 1)
+        + (1.0 - probability) * cover_probability(&cofactor(0), one_probabilities, index + 1)
+}
+
+/// Whether a variable appears negated in a [`Cover`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Polarity {
+    /// The variable appears as is.
+    Positive,
+    /// The variable appears negated.
+    Negative,
+}
+
+/// Returns the implicants that are not duplicates of or covered by another one, keeping the first of duplicates.
+fn absorb(implicants: &[Implicant]) -> Vec<Implicant> {
+    let mut remaining = vec![];
+
+    for (i, implicant) in implicants.iter().enumerate() {
+        let is_absorbed = implicants
+            .iter()
+            .enumerate()
+            .any(|(j, other)| other.covers(*implicant) && (other != implicant || j < i));
+
+        if !is_absorbed {
+            remaining.push(*implicant);
+        }
+    }
+
+    remaining
 }
 
-/// A variable as part of a [`Solution`].
+/// A variable as part of a [`Solution`] or a [`Cover`].
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Variable {
@@ -99,6 +3304,26 @@ impl Variable {
     }
 }
 
+/// The result of [`Cover::substitute_constants`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Substitution {
+    /// The expression over the variables that were not substituted.
+    pub residual: Cover,
+    /// The output if it is determined by the substituted constants, or `None` if it depends on the other variables.
+    pub output: Option<bool>,
+}
+
+/// The sensitizing terms of a literal, see [`Cover::sensitization`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiteralSensitization {
+    pub variable: String,
+    pub polarity: Polarity,
+    /// The terms for which flipping the variable changes the output in ascending order.
+    pub terms: Vec<u32>,
+}
+
 impl Display for Variable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}{}", if self.is_negated { "~" } else { "" }, self.name)
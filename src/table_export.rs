@@ -0,0 +1,261 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{validate_variables, Error, TruthTable};
+
+/// A multi-output truth table exported as text by a circuit simulator such as Logisim-evolution or Digital.
+///
+/// Multi-bit columns are named like `A[2..0]` and are split into one variable (or output) per bit,
+/// named `A[2]`, `A[1]` and `A[0]` from the most significant bit. Outputs are `1`, `0` or `X` (also `x` or `-`)
+/// for a don't care, and inputs can be `X` (also `x` or `-`) in the compact rows that stand for both values.
+/// Every term must be in exactly one row.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let export = qmc::TableExport::from_logisim(
+///     "\
+/// ## Truth table
+/// ## Generated by Logisim-evolution
+///
+/// A B | Sum Carry
+/// ~~~~~~~~~~~~~~~
+/// 0 0 | 0   0
+/// 0 1 | 1   0
+/// 1 0 | 1   0
+/// 1 1 | 0   1
+/// ",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(export.inputs(), ["A", "B"]);
+/// assert_eq!(export.outputs(), ["Sum", "Carry"]);
+///
+/// let mut solutions = export.tables()[1].minimize(qmc::SOP, false, None).unwrap();
+///
+/// assert_eq!(solutions.pop().unwrap().to_string(), "A ∧ B");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableExport {
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    tables: Vec<TruthTable>,
+}
+
+impl TableExport {
+    /// Parses a truth table exported by Logisim-evolution from the combinational analysis window.
+    ///
+    /// Lines starting with `#` are comments. The first of the other lines has the input names and the output names
+    /// separated by `|`, and the rows have the values of the inputs and the outputs in the same way.
+    /// Lines made of `~` or `-` only, such as the one under the names, are ignored.
+    ///
+    /// Returns [`Error::InvalidTableExport`] if the table is malformed or doesn't have a row for each term,
+    /// and the same errors as [`minimize`](crate::minimize) for invalid input names.
+    pub fn from_logisim(s: &str) -> Result<Self, Error> {
+        let lines = s.lines().enumerate().filter_map(|(i, line)| {
+            let line = line.trim();
+            let is_rule = line.chars().all(|char| char == '~' || char == '-');
+
+            if line.starts_with('#') || is_rule {
+                None
+            } else {
+                Some((i + 1, line.split('|').map(str::trim).collect()))
+            }
+        });
+
+        parse(lines, "'|'")
+    }
+
+    /// Parses a truth table exported by Digital as CSV or plain text.
+    ///
+    /// The cells of a line are separated by `,` (with optional quotes) or by tabs, and an empty cell separates
+    /// the inputs from the outputs. The first line has the names and the other lines are the rows,
+    /// where the value of each column is in its own cell.
+    ///
+    /// Returns [`Error::InvalidTableExport`] if the table is malformed or doesn't have a row for each term,
+    /// and the same errors as [`minimize`](crate::minimize) for invalid input names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let export = qmc::TableExport::from_digital("A,B,,Y\n0,0,,1\n0,1,,X\n1,X,,0\n").unwrap();
+    ///
+    /// assert_eq!(export.tables()[0].minterms(), [0]);
+    /// assert_eq!(export.tables()[0].dont_cares(), [1]);
+    /// ```
+    pub fn from_digital(s: &str) -> Result<Self, Error> {
+        let lines = s.lines().enumerate().filter_map(|(i, line)| {
+            if line.trim().is_empty() {
+                return None;
+            }
+
+            let separator = if line.contains(',') { ',' } else { '\t' };
+            let cells = Vec::from_iter(
+                line.split(separator)
+                    .map(|cell| cell.trim().trim_matches('"')),
+            );
+
+            let parts = match cells.iter().position(|cell| cell.is_empty()) {
+                Some(position) => {
+                    vec![cells[..position].join(" "), cells[position + 1..].join(" ")]
+                }
+                None => vec![cells.join(" ")],
+            };
+
+            Some((i + 1, parts))
+        });
+
+        parse(lines, "an empty cell")
+    }
+
+    /// Returns the names of the inputs, which are the variables of the tables.
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// Returns the names of the outputs.
+    pub fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    /// Returns the truth table of each output.
+    pub fn tables(&self) -> &[TruthTable] {
+        &self.tables
+    }
+}
+
+/// Parses the names and rows of a table given as lines split into the input part and the output part
+/// by the `separator`.
+fn parse<T: AsRef<str>>(
+    mut lines: impl Iterator<Item = (usize, Vec<T>)>,
+    separator: &str,
+) -> Result<TableExport, Error> {
+    let expected_separator = format!("expected {} between the inputs and the outputs", separator);
+    let (line_number, header) = lines
+        .next()
+        .ok_or_else(|| Error::InvalidTableExport("missing names".to_owned()))?;
+    let (inputs, outputs) = match &header[..] {
+        [inputs, outputs] => (bit_names(inputs.as_ref()), bit_names(outputs.as_ref())),
+        _ => return Err(invalid(line_number, expected_separator)),
+    };
+
+    if outputs.is_empty() {
+        return Err(invalid(line_number, "expected at least one output"));
+    }
+
+    validate_variables(&inputs)?;
+
+    let mut columns = vec![vec![None; 1 << inputs.len()]; outputs.len()];
+
+    for (line_number, row) in lines {
+        let (input_values, output_values) = match &row[..] {
+            [inputs, outputs] => (values(inputs.as_ref()), values(outputs.as_ref())),
+            _ => return Err(invalid(line_number, &expected_separator)),
+        };
+
+        if input_values.len() != inputs.len() || output_values.len() != outputs.len() {
+            return Err(invalid(
+                line_number,
+                format!(
+                    "expected {} input and {} output values",
+                    inputs.len(),
+                    outputs.len()
+                ),
+            ));
+        }
+
+        let mut terms = vec![0u32];
+
+        for value in input_values {
+            terms = match value {
+                '0' => Vec::from_iter(terms.iter().map(|term| term << 1)),
+                '1' => Vec::from_iter(terms.iter().map(|term| term << 1 | 1)),
+                'X' | 'x' | '-' => {
+                    Vec::from_iter(terms.iter().flat_map(|term| [term << 1, term << 1 | 1]))
+                }
+                value => return Err(invalid(line_number, format!("invalid input {:?}", value))),
+            };
+        }
+
+        for (column, value) in columns.iter_mut().zip(output_values) {
+            let output = match value {
+                '1' => Some(true),
+                '0' => Some(false),
+                'X' | 'x' | '-' => None,
+                value => return Err(invalid(line_number, format!("invalid output {:?}", value))),
+            };
+
+            for &term in &terms {
+                if column[term as usize].replace(output).is_some() {
+                    return Err(invalid(
+                        line_number,
+                        format!("duplicate row for term {}", term),
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut tables = vec![];
+
+    for column in columns {
+        let outputs = column
+            .iter()
+            .enumerate()
+            .map(|(term, output)| {
+                output.ok_or_else(|| {
+                    Error::InvalidTableExport(format!("missing row for term {}", term))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        tables.push(TruthTable::new(&inputs, &outputs)?);
+    }
+
+    Ok(TableExport {
+        inputs,
+        outputs,
+        tables,
+    })
+}
+
+/// Returns the names of the bits of the columns with the given names, e.g. `A[1]` and `A[0]` for `A[1..0]`.
+fn bit_names(names: &str) -> Vec<String> {
+    let mut bit_names = vec![];
+
+    for name in names.split_whitespace() {
+        let range = name
+            .strip_suffix(']')
+            .and_then(|name| name.split_once('['))
+            .and_then(|(base, range)| {
+                let (high, low) = range.split_once("..")?;
+
+                Some((base, high.parse::<u32>().ok()?, low.parse::<u32>().ok()?))
+            });
+
+        match range {
+            Some((base, high, low)) if high >= low => {
+                bit_names.extend((low..=high).rev().map(|bit| format!("{}[{}]", base, bit)))
+            }
+            _ => bit_names.push(name.to_owned()),
+        }
+    }
+
+    bit_names
+}
+
+/// Returns the values of a part of a row, where the values of a multi-bit column may be written together.
+fn values(part: &str) -> Vec<char> {
+    Vec::from_iter(part.chars().filter(|char| !char.is_whitespace()))
+}
+
+fn invalid(line_number: usize, reason: impl Display) -> Error {
+    Error::InvalidTableExport(format!("line {}: {}", line_number, reason))
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of perceptron updates before giving up on finding weights.
+const MAX_UPDATES: usize = 10_000;
+
+/// A function of the form `w1 * x1 + w2 * x2 + ... ≥ t`, i.e. a threshold function.
+///
+/// See [`BooleanFunction::threshold_form`](crate::BooleanFunction::threshold_form).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ThresholdForm {
+    /// The weight of each variable, in the order of the variables of the function.
+    pub weights: Vec<(String, i64)>,
+    /// The threshold the weighted sum of the variables that are 1 must reach for the output to be 1.
+    pub threshold: i64,
+}
+
+impl Display for ThresholdForm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut is_first = true;
+
+        for (variable, weight) in &self.weights {
+            if *weight == 0 {
+                continue;
+            }
+
+            match (is_first, *weight < 0) {
+                (true, false) => {}
+                (true, true) => write!(f, "-")?,
+                (false, false) => write!(f, " + ")?,
+                (false, true) => write!(f, " - ")?,
+            }
+
+            if weight.abs() != 1 {
+                write!(f, "{}", weight.abs())?;
+            }
+
+            write!(f, "{}", variable)?;
+            is_first = false;
+        }
+
+        if is_first {
+            write!(f, "0")?;
+        }
+
+        write!(f, " ≥ {}", self.threshold)
+    }
+}
+
+/// Finds integer weights and a threshold separating the minterms from the maxterms among the `care_terms`.
+///
+/// The Chow parameters of the care terms are tried first, as they are the weights of most threshold functions
+/// of few variables, and the perceptron algorithm is used otherwise.
+pub fn fit(variable_count: u32, care_terms: &[(u32, bool)]) -> Option<(Vec<i64>, i64)> {
+    if !is_unate(variable_count, care_terms) {
+        return None;
+    }
+
+    let weighted_sum = |weights: &[i64], term: u32| -> i64 {
+        weights
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| term >> (variable_count as usize - index - 1) & 1 == 1)
+            .map(|(_, weight)| weight)
+            .sum()
+    };
+
+    let chow_parameters = Vec::from_iter((0..variable_count).map(|index| {
+        let bit = variable_count - index - 1;
+
+        care_terms
+            .iter()
+            .map(|&(term, output)| {
+                let sign = if output { 1 } else { -1 };
+                if term >> bit & 1 == 1 {
+                    sign
+                } else {
+                    -sign
+                }
+            })
+            .sum::<i64>()
+    }));
+
+    if let Some(threshold) = separate(&chow_parameters, care_terms, weighted_sum) {
+        return Some(normalize(chow_parameters, threshold));
+    }
+
+    let mut weights = vec![0; variable_count as usize];
+    let mut threshold = 0;
+    let mut update_count = 0;
+
+    while update_count < MAX_UPDATES {
+        let mut is_separated = true;
+
+        for &(term, output) in care_terms {
+            let sum = weighted_sum(&weights, term);
+            let direction = match (output, sum >= threshold) {
+                (true, false) => 1,
+                (false, true) => -1,
+                _ => continue,
+            };
+
+            for (index, weight) in weights.iter_mut().enumerate() {
+                if term >> (variable_count as usize - index - 1) & 1 == 1 {
+                    *weight += direction;
+                }
+            }
+
+            threshold -= direction;
+            update_count += 1;
+            is_separated = false;
+        }
+
+        if is_separated {
+            let threshold = separate(&weights, care_terms, weighted_sum)?;
+            return Some(normalize(weights, threshold));
+        }
+    }
+
+    None
+}
+
+/// Returns whether each variable only ever increases or only ever decreases the output
+/// between care terms that differ in that variable only, which is necessary for a threshold function.
+fn is_unate(variable_count: u32, care_terms: &[(u32, bool)]) -> bool {
+    let outputs: HashMap<u32, bool> = care_terms.iter().copied().collect();
+
+    (0..variable_count).all(|bit| {
+        let mut is_increasing = false;
+        let mut is_decreasing = false;
+
+        for (&term, &output) in &outputs {
+            if term >> bit & 1 == 1 {
+                continue;
+            }
+
+            match (output, outputs.get(&(term | 1 << bit))) {
+                (false, Some(true)) => is_increasing = true,
+                (true, Some(false)) => is_decreasing = true,
+                _ => {}
+            }
+        }
+
+        !(is_increasing && is_decreasing)
+    })
+}
+
+/// Returns the tightest threshold for the given weights if they separate the minterms from the maxterms.
+fn separate(
+    weights: &[i64],
+    care_terms: &[(u32, bool)],
+    weighted_sum: impl Fn(&[i64], u32) -> i64,
+) -> Option<i64> {
+    let min_minterm_sum = care_terms
+        .iter()
+        .filter(|(_, output)| *output)
+        .map(|&(term, _)| weighted_sum(weights, term))
+        .min();
+    let max_maxterm_sum = care_terms
+        .iter()
+        .filter(|(_, output)| !*output)
+        .map(|&(term, _)| weighted_sum(weights, term))
+        .max();
+
+    match (min_minterm_sum, max_maxterm_sum) {
+        (Some(min_minterm_sum), Some(max_maxterm_sum)) => {
+            (min_minterm_sum > max_maxterm_sum).then(|| min_minterm_sum)
+        }
+        (Some(min_minterm_sum), None) => Some(min_minterm_sum),
+        (None, Some(max_maxterm_sum)) => Some(max_maxterm_sum + 1),
+        (None, None) => Some(0),
+    }
+}
+
+/// Divides the weights by their greatest common divisor, rounding the threshold up.
+fn normalize(mut weights: Vec<i64>, threshold: i64) -> (Vec<i64>, i64) {
+    fn gcd(a: i64, b: i64) -> i64 {
+        if b == 0 {
+            a.abs()
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    let divisor = weights
+        .iter()
+        .fold(0, |divisor, &weight| gcd(divisor, weight));
+
+    if divisor <= 1 {
+        return (weights, threshold);
+    }
+
+    for weight in &mut weights {
+        *weight /= divisor;
+    }
+
+    // The weighted sums are multiples of the divisor, so rounding up keeps the separation.
+    let threshold = threshold.div_euclid(divisor) + (threshold.rem_euclid(divisor) != 0) as i64;
+
+    (weights, threshold)
+}
@@ -0,0 +1,302 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::time::Duration;
+
+use crate::{own_variables, validate_input, BooleanFunction, Cover, Error, Form};
+
+/// The outputs of a boolean function for all of its terms.
+///
+/// `None` outputs are don't care conditions.
+/// Use the [`truth_table!`](crate::truth_table!) macro to write a truth table visually.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TruthTable {
+    variables: Vec<String>,
+    outputs: Vec<Option<bool>>,
+}
+
+impl TruthTable {
+    /// Creates a truth table from the `outputs` of all terms in ascending order.
+    ///
+    /// Returns [`Error::InvalidOutputCount`] if the number of outputs is not 2 to the power of the number of variables,
+    /// and the same errors as [`minimize`](crate::minimize) for invalid variables.
+    pub fn new<T: AsRef<str>>(variables: &[T], outputs: &[Option<bool>]) -> Result<Self, Error> {
+        let variables = own_variables(variables);
+
+        validate_input(&variables, &Default::default(), &Default::default())?;
+
+        if outputs.len() != 1 << variables.len() {
+            return Err(Error::InvalidOutputCount(outputs.len()));
+        }
+
+        Ok(TruthTable {
+            variables,
+            outputs: outputs.to_vec(),
+        })
+    }
+
+    /// Creates a truth table from the output column written as a string of `1`, `0` and `X` (also `x` or `-`)
+    /// characters, one for each row, e.g. `"10X0X101"`.
+    ///
+    /// `bit_order` determines whether the first variable is the most or the least significant bit of the row index.
+    ///
+    /// Returns [`Error::InvalidTruthTableColumn`] if the column contains other characters,
+    /// [`Error::InvalidOutputCount`] if it doesn't have a character for each row,
+    /// and the same errors as [`minimize`](crate::minimize) for invalid variables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    /// use qmc::BitOrder::{LsbFirst, MsbFirst};
+    ///
+    /// let table = qmc::TruthTable::from_column(&["A", "B"], "1X00", MsbFirst).unwrap();
+    ///
+    /// assert_eq!(table.minterms(), [0]);
+    /// assert_eq!(table.dont_cares(), [1]);
+    ///
+    /// // The second row is A = 1 and B = 0, which is term 2.
+    /// let table = qmc::TruthTable::from_column(&["A", "B"], "1X00", LsbFirst).unwrap();
+    ///
+    /// assert_eq!(table.dont_cares(), [2]);
+    /// ```
+    pub fn from_column<T: AsRef<str>>(
+        variables: &[T],
+        column: &str,
+        bit_order: BitOrder,
+    ) -> Result<Self, Error> {
+        let variable_count = variables.len();
+        let outputs = column
+            .chars()
+            .map(|char| match char {
+                '1' => Ok(Some(true)),
+                '0' => Ok(Some(false)),
+                'X' | 'x' | '-' => Ok(None),
+                _ => Err(Error::InvalidTruthTableColumn(column.to_owned())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let outputs = match bit_order {
+            BitOrder::MsbFirst => outputs,
+            BitOrder::LsbFirst
+                if 1usize.checked_shl(variable_count as u32) == Some(outputs.len()) =>
+            {
+                Vec::from_iter((0..outputs.len()).map(|term| {
+                    let row = (0..variable_count).fold(0, |row, bit| row << 1 | term >> bit & 1);
+                    outputs[row]
+                }))
+            }
+            // Let `new` report the invalid output count.
+            BitOrder::LsbFirst => outputs,
+        };
+
+        TruthTable::new(variables, &outputs)
+    }
+
+    /// Creates a fully specified truth table from its hexadecimal encoding, as used for the initial values of LUTs,
+    /// e.g. `"E8"` for the 3-input majority function.
+    ///
+    /// The least significant bit of the number is the output of term 0. There must be exactly as many digits
+    /// as needed for 2 to the power of the number of variables bits, and a `0x` prefix is allowed.
+    ///
+    /// Returns [`Error::InvalidHexTruthTable`] if the encoding is malformed or has the wrong length,
+    /// and the same errors as [`minimize`](crate::minimize) for invalid variables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let table = qmc::TruthTable::from_hex(&["A", "B", "C"], "E8").unwrap();
+    ///
+    /// assert_eq!(table.minterms(), [3, 5, 6, 7]);
+    /// assert_eq!(
+    ///     table.minimize(qmc::SOP, false, None).unwrap().pop().unwrap().to_string(),
+    ///     "(A ∧ B) ∨ (A ∧ C) ∨ (B ∧ C)"
+    /// );
+    /// ```
+    pub fn from_hex<T: AsRef<str>>(variables: &[T], hex: &str) -> Result<Self, Error> {
+        let variables = own_variables(variables);
+
+        validate_input(&variables, &Default::default(), &Default::default())?;
+
+        let invalid_hex_truth_table = || Error::InvalidHexTruthTable(hex.to_owned());
+        let digits = hex
+            .strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .unwrap_or(hex);
+        let output_count = 1usize << variables.len();
+
+        if digits.len() != (output_count + 3) / 4 {
+            return Err(invalid_hex_truth_table());
+        }
+
+        let mut outputs = Vec::with_capacity(output_count);
+
+        // The last digit holds the outputs of the first terms.
+        for char in digits.chars().rev() {
+            let digit = char.to_digit(16).ok_or_else(invalid_hex_truth_table)?;
+
+            for bit in 0..4 {
+                if outputs.len() < output_count {
+                    outputs.push(Some(digit >> bit & 1 == 1));
+                } else if digit >> bit & 1 == 1 {
+                    return Err(invalid_hex_truth_table());
+                }
+            }
+        }
+
+        TruthTable::new(&variables, &outputs)
+    }
+
+    #[doc(hidden)]
+    pub fn from_rows(variables: &[&str], rows: &[(&[u32], Option<bool>)]) -> Self {
+        let mut outputs = vec![None; 1 << variables.len()];
+        let mut is_specified = vec![false; outputs.len()];
+
+        for (bits, output) in rows {
+            assert_eq!(
+                bits.len(),
+                variables.len(),
+                "row {:?} does not have a bit for each variable",
+                bits
+            );
+
+            let term = bits.iter().fold(0, |term, &bit| {
+                assert!(bit <= 1, "row {:?} contains a bit other than 0 and 1", bits);
+                term << 1 | bit
+            }) as usize;
+
+            assert!(!is_specified[term], "row {:?} is duplicated", bits);
+
+            outputs[term] = *output;
+            is_specified[term] = true;
+        }
+
+        assert!(
+            is_specified.iter().all(|&is_specified| is_specified),
+            "truth table does not contain all rows"
+        );
+
+        TruthTable::new(variables, &outputs).unwrap()
+    }
+
+    /// Returns the variables of the truth table.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the outputs of all terms in ascending order.
+    pub fn outputs(&self) -> &[Option<bool>] {
+        &self.outputs
+    }
+
+    /// Returns the terms whose output is 1 in ascending order.
+    pub fn minterms(&self) -> Vec<u32> {
+        self.terms_with_output(Some(true))
+    }
+
+    /// Returns the terms whose output is 0 in ascending order.
+    pub fn maxterms(&self) -> Vec<u32> {
+        self.terms_with_output(Some(false))
+    }
+
+    /// Returns the terms whose output is a don't care in ascending order.
+    pub fn dont_cares(&self) -> Vec<u32> {
+        self.terms_with_output(None)
+    }
+
+    /// Minimizes the function given by the truth table.
+    ///
+    /// See [`minimize`](crate::minimize) for the meaning of the arguments.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let table = qmc::TruthTable::new(&["A", "B"], &[Some(false), None, Some(true), Some(true)]).unwrap();
+    /// let mut solutions = table.minimize(qmc::SOP, false, None).unwrap();
+    ///
+    /// assert_eq!(solutions.pop().unwrap().to_string(), "A");
+    /// ```
+    pub fn minimize(
+        &self,
+        form: Form,
+        find_all_solutions: bool,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Cover>, Error> {
+        BooleanFunction::from(self).minimize(form, find_all_solutions, timeout)
+    }
+
+    fn terms_with_output(&self, output: Option<bool>) -> Vec<u32> {
+        (0..self.outputs.len() as u32)
+            .filter(|&term| self.outputs[term as usize] == output)
+            .collect()
+    }
+}
+
+impl From<&TruthTable> for BooleanFunction {
+    fn from(table: &TruthTable) -> Self {
+        BooleanFunction::new(&table.variables, &table.minterms(), &table.maxterms()).unwrap()
+    }
+}
+
+/// The significance of the variables in the row index of a truth table column,
+/// see [`TruthTable::from_column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BitOrder {
+    /// The first variable is the most significant bit, as in the terms of this crate.
+    MsbFirst,
+    /// The first variable is the least significant bit.
+    LsbFirst,
+}
+
+/// Creates a [`TruthTable`] from rows written visually.
+///
+/// The first line lists the variables and the output, and the rest of the lines list the value of each variable
+/// and the output, which is `1`, `0` or `X` (also `x` or `-`) for a don't care. Rows can be in any order,
+/// but each row must be given exactly once. The name of the output is for readability only.
+///
+/// # Panics
+///
+/// Panics if a row is missing, duplicated, has the wrong number of bits or has a bit other than 0 and 1,
+/// or if the variables are invalid.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let table = qmc::truth_table! {
+///     A B C => Y;
+///     0 0 0 => 1;
+///     0 0 1 => 0;
+///     0 1 0 => X;
+///     0 1 1 => 0;
+///     1 0 0 => 0;
+///     1 0 1 => 1;
+///     1 1 0 => 0;
+///     1 1 1 => X;
+/// };
+///
+/// assert_eq!(table.minterms(), [0, 5]);
+/// assert_eq!(table.maxterms(), [1, 3, 4, 6]);
+/// assert_eq!(table.dont_cares(), [2, 7]);
+/// ```
+#[macro_export]
+macro_rules! truth_table {
+    (@output 0) => { Some(false) };
+    (@output 1) => { Some(true) };
+    (@output X) => { None };
+    (@output x) => { None };
+    (@output -) => { None };
+    ($($variable:ident)+ => $output:ident; $($($bit:literal)+ => $value:tt);+ $(;)?) => {
+        $crate::TruthTable::from_rows(
+            &[$(stringify!($variable)),+],
+            &[$((&[$($bit),+][..], $crate::truth_table!(@output $value))),+],
+        )
+    };
+}
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+use crate::{duplicate_positions, own_variables, validate_variables, Error};
+
+/// A validated list of variables.
+///
+/// Terms and cubes created with a variable set are guaranteed to be within its bounds,
+/// so [`Error::TermOutOfBounds`] can't occur when they are passed to
+/// [`BooleanFunction::from_terms`](crate::BooleanFunction::from_terms) or
+/// [`BooleanFunction::from_cubes`](crate::BooleanFunction::from_cubes) with the same set.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = qmc::VariableSet::new(&["A", "B", "C"]).unwrap();
+///
+/// assert!(qmc::Term::new(&variables, 7).is_ok());
+/// assert!(qmc::Term::new(&variables, 8).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "Vec<String>", into = "Vec<String>")
+)]
+pub struct VariableSet {
+    variables: Vec<String>,
+}
+
+impl VariableSet {
+    /// Creates a variable set from the given `variables`.
+    ///
+    /// Returns the same errors as [`minimize`](crate::minimize) for invalid variables.
+    pub fn new<T: AsRef<str>>(variables: &[T]) -> Result<Self, Error> {
+        let variables = own_variables(variables);
+
+        validate_variables(&variables)?;
+
+        Ok(VariableSet { variables })
+    }
+
+    /// Returns the positions at which each duplicate variable appears in `variables`, in ascending order.
+    ///
+    /// The keys are the variables reported by [`Error::DuplicateVariables`] when `variables` is passed to
+    /// [`VariableSet::new`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let duplicates = qmc::VariableSet::duplicate_positions(&["A", "B", "A"]);
+    ///
+    /// assert_eq!(duplicates.len(), 1);
+    /// assert_eq!(duplicates["A"], [0, 2]);
+    /// ```
+    pub fn duplicate_positions<T: AsRef<str>>(variables: &[T]) -> HashMap<String, Vec<usize>> {
+        duplicate_positions(variables)
+    }
+
+    /// Returns the variables of the set.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the number of variables of the set.
+    pub fn variable_count(&self) -> u32 {
+        self.variables.len() as u32
+    }
+}
+
+impl TryFrom<Vec<String>> for VariableSet {
+    type Error = Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        VariableSet::new(&value)
+    }
+}
+
+impl From<VariableSet> for Vec<String> {
+    fn from(value: VariableSet) -> Self {
+        value.variables
+    }
+}
+
+/// A term that is within the bounds of a [`VariableSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct Term(u32);
+
+impl Term {
+    /// Creates a term of the given `variables`.
+    ///
+    /// Returns [`Error::TermOutOfBounds`] if `value` is not less than 2 to the power of the number of variables.
+    pub fn new(variables: &VariableSet, value: u32) -> Result<Self, Error> {
+        if value >= 1 << variables.variable_count() {
+            return Err(Error::TermOutOfBounds {
+                offending_terms: [value].into_iter().collect(),
+                variable_count: variables.variables.len(),
+            });
+        }
+
+        Ok(Term(value))
+    }
+
+    /// Returns the value of the term.
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Term> for u32 {
+    fn from(value: Term) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,218 @@
+//! Differential testing against external minimizers.
+//!
+//! [`cross_check`] compares a [`Cover`] with the cover found by a reference minimizer,
+//! which can be any callback or an `espresso` binary through [`espresso`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use quine_mccluskey as qmc;
+//! use qmc::verification::{self, Discrepancy};
+//!
+//! let function = qmc::BooleanFunction::new(&["A", "B"], &[1, 2, 3], &[0]).unwrap();
+//! let solution = function.minimize(qmc::SOP, false, None).unwrap().pop().unwrap();
+//!
+//! // A reference minimizer that returns a correct but non-minimal cover.
+//! let report = verification::cross_check(&function, &solution, |_, _| {
+//!     Ok(vec!["01".parse()?, "1-".parse()?])
+//! })
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     report.discrepancies,
+//!     [Discrepancy::Cost {
+//!         solution: verification::Cost { terms: 2, literals: 2 },
+//!         reference: verification::Cost { terms: 2, literals: 3 },
+//!     }]
+//! );
+//! ```
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BooleanFunction, Cover, Cube, Error, Form, SOP};
+
+/// The result of [`cross_check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Report {
+    pub solution_cost: Cost,
+    pub reference_cost: Cost,
+    /// Empty if the solution and the reference cover agree.
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+/// The cost of a cover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cost {
+    /// The number of products (or sums).
+    pub terms: usize,
+    /// The total number of literals in all products (or sums).
+    pub literals: usize,
+}
+
+/// A difference between a solution and the reference cover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Discrepancy {
+    /// The solution and the reference cover have different costs.
+    Cost { solution: Cost, reference: Cost },
+    /// The solution outputs the wrong value for a care term.
+    SolutionMismatch(u32),
+    /// The reference cover outputs the wrong value for a care term.
+    ReferenceMismatch(u32),
+}
+
+/// Cross-checks the cost and the correctness of a `solution` of the `function` against a `reference` minimizer.
+///
+/// The reference is called with the function and the form of the solution and must return the cover as cubes:
+/// the products of the function in [`SOP`] form, or the products of its complement in [`POS`](crate::POS) form.
+/// Errors returned by the reference are returned as is.
+pub fn cross_check<F>(
+    function: &BooleanFunction,
+    solution: &Cover,
+    reference: F,
+) -> Result<Report, Error>
+where
+    F: FnOnce(&BooleanFunction, Form) -> Result<Vec<Cube>, Error>,
+{
+    let form = solution.form();
+    let cubes = reference(function, form)?;
+
+    let variable_count = function.variable_count();
+
+    if let Some(cube) = cubes
+        .iter()
+        .find(|cube| cube.variable_count() != variable_count)
+    {
+        return Err(Error::InvalidCube(cube.to_string()));
+    }
+
+    let solution_cost = Cost {
+        terms: solution.implicants().len(),
+        literals: solution
+            .implicants()
+            .iter()
+            .map(|implicant| (variable_count - implicant.wildcard_count()) as usize)
+            .sum(),
+    };
+
+    let reference_cost = Cost {
+        terms: cubes.len(),
+        literals: cubes.iter().map(Cube::literal_count).sum(),
+    };
+
+    let mut discrepancies = vec![];
+
+    if solution_cost != reference_cost {
+        discrepancies.push(Discrepancy::Cost {
+            solution: solution_cost,
+            reference: reference_cost,
+        });
+    }
+
+    let mut care_terms = Vec::from_iter(function.minterms().union(function.maxterms()).copied());
+    care_terms.sort_unstable();
+
+    for &term in &care_terms {
+        if function.evaluate(term) != Some(solution.evaluate_term(term)) {
+            discrepancies.push(Discrepancy::SolutionMismatch(term));
+        }
+    }
+
+    for &term in &care_terms {
+        let is_covered = cubes.iter().any(|cube| cube.contains(term));
+
+        if function.evaluate(term) != Some(is_covered == (form == SOP)) {
+            discrepancies.push(Discrepancy::ReferenceMismatch(term));
+        }
+    }
+
+    Ok(Report {
+        solution_cost,
+        reference_cost,
+        discrepancies,
+    })
+}
+
+/// Returns a reference minimizer for [`cross_check`] that runs the `espresso` binary at the given `path`.
+///
+/// Returns [`Error::ExternalMinimizer`] if the binary could not be run or its output could not be parsed.
+pub fn espresso(
+    path: impl AsRef<Path>,
+) -> impl FnOnce(&BooleanFunction, Form) -> Result<Vec<Cube>, Error> {
+    move |function, form| {
+        let mut child = Command::new(path.as_ref())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|error| Error::ExternalMinimizer(error.to_string()))?;
+
+        let input = espresso_input(function, form);
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(input.as_bytes())
+            .map_err(|error| Error::ExternalMinimizer(error.to_string()))?;
+
+        let output = child
+            .wait_with_output()
+            .map_err(|error| Error::ExternalMinimizer(error.to_string()))?;
+
+        if !output.status.success() {
+            return Err(Error::ExternalMinimizer(format!(
+                "espresso exited with {}",
+                output.status
+            )));
+        }
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('.') && !line.starts_with('#'))
+            .map(
+                |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                    [cube, "1"] => cube.parse(),
+                    _ => Err(Error::ExternalMinimizer(format!(
+                        "unexpected espresso output: {}",
+                        line
+                    ))),
+                },
+            )
+            .collect()
+    }
+}
+
+fn espresso_input(function: &BooleanFunction, form: Form) -> String {
+    let variable_count = function.variable_count() as usize;
+    let (ones, zeros) = if form == SOP {
+        (function.minterms(), function.maxterms())
+    } else {
+        (function.maxterms(), function.minterms())
+    };
+
+    let mut input = format!(".i {}\n.o 1\n.type fr\n", variable_count);
+
+    for (terms, output) in [(ones, 1), (zeros, 0)] {
+        let mut terms = Vec::from_iter(terms.iter().copied());
+        terms.sort_unstable();
+
+        for term in terms {
+            input.push_str(&format!(
+                "{:0width$b} {}\n",
+                term,
+                output,
+                width = variable_count
+            ));
+        }
+    }
+
+    input.push_str(".e\n");
+    input
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A condition of the input that a [`Cover`](crate::Cover) is valid for but may not be what the caller meant,
+/// see [`Cover::warnings`](crate::Cover::warnings).
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let solutions = qmc::BooleanFunction::new(&["A", "B"], &[], &[])
+///     .unwrap()
+///     .minimize(qmc::SOP, true, None)
+///     .unwrap();
+///
+/// assert_eq!(solutions.len(), 2);
+/// assert!(solutions.iter().all(qmc::Cover::is_constant));
+/// assert_eq!(solutions[0].warnings(), [qmc::Warning::AllDontCares]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Warning {
+    /// Every term is a don't care, so both constants implement the function. The solution is the one without
+    /// products (or sums), which is the constant 0 in [`SOP`](crate::SOP) form and the constant 1
+    /// in [`POS`](crate::POS) form, followed by the other constant if all solutions are requested.
+    AllDontCares,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::AllDontCares => write!(
+                f,
+                "every term is a don't care, so any constant is a solution"
+            ),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "audit")]
+
+use quine_mccluskey as qmc;
+
+#[test]
+fn audit_log() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+    let minterms = [30, 22, 19, 4, 7, 14, 31, 17, 16, 24, 21, 2];
+    let maxterms = [1, 27, 6, 11, 8, 10, 0, 13, 9, 20, 23, 28, 26];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let terms: &[u32] = if form == qmc::SOP {
+            &minterms
+        } else {
+            &maxterms
+        };
+        let (solutions, audit_log) =
+            qmc::minimize_audited(variables, &minterms, &maxterms, form, false, None).unwrap();
+        let expected = qmc::minimize(variables, &minterms, &maxterms, form, false, None).unwrap();
+
+        assert_eq!(
+            Vec::from_iter(solutions.iter().map(ToString::to_string)),
+            Vec::from_iter(expected.iter().map(ToString::to_string))
+        );
+        assert!(!audit_log.events.is_empty());
+
+        for event in &audit_log.events {
+            match event {
+                qmc::AuditEvent::EssentialPrimeImplicant { implicant, term } => {
+                    assert!(implicant.contains(*term));
+                    assert!(terms.contains(term));
+                    assert!(solutions
+                        .iter()
+                        .all(|solution| solution.cubes().contains(implicant)));
+                }
+                qmc::AuditEvent::DominatingTermRemoved {
+                    term,
+                    dominated_term,
+                } => {
+                    assert!(terms.contains(term) && terms.contains(dominated_term));
+                }
+                qmc::AuditEvent::DominatedImplicantRemoved {
+                    implicant,
+                    dominating_implicant,
+                } => {
+                    assert!(implicant.literal_count() >= dominating_implicant.literal_count());
+                }
+            }
+        }
+
+        let json = audit_log.to_json();
+
+        assert!(json.starts_with(r#"{"events":[{"kind":""#) && json.ends_with("}]}"));
+        assert_eq!(json.matches(r#""kind""#).count(), audit_log.events.len());
+    }
+}
+
+#[test]
+fn audit_log_timeout() {
+    let (_, audit_log) = qmc::minimize_audited(
+        &["A", "B"],
+        &[1, 2],
+        &[0, 3],
+        qmc::SOP,
+        false,
+        Some(std::time::Duration::from_secs(10)),
+    )
+    .unwrap();
+
+    assert_eq!(
+        audit_log.to_json(),
+        r#"{"events":[{"kind":"essential_prime_implicant","implicant":"01","term":1},{"kind":"essential_prime_implicant","implicant":"10","term":2}]}"#
+    );
+}
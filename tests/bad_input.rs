@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use quine_mccluskey as qmc;
 
 #[test]
@@ -45,6 +47,15 @@ fn too_many_variables3() {
     qmc::minimize_maxterms(&variables, &[], &[], false, None).unwrap();
 }
 
+#[test]
+#[should_panic(expected = "InvalidVariableCount")]
+fn too_many_variables_fn() {
+    let mut variables = qmc::DEFAULT_VARIABLES.to_vec();
+    variables.push("test");
+
+    qmc::minimize_fn(&variables, |_| unreachable!(), qmc::SOP, false, None).unwrap();
+}
+
 #[test]
 #[should_panic(expected = "InvalidVariable")]
 fn variable_is_0() {
@@ -153,6 +164,24 @@ fn duplicate_variables3() {
     qmc::minimize_maxterms(&["A", "B", "A"], &[], &[], false, None).unwrap();
 }
 
+#[test]
+fn duplicate_variable_positions() {
+    let variables = ["A", "B", "A", "C", "B", "A"];
+    let error = qmc::minimize(&variables, &[], &[], qmc::SOP, false, None).unwrap_err();
+
+    let names = match error {
+        qmc::Error::DuplicateVariables(names) => names,
+        _ => panic!("unexpected error: {:?}", error),
+    };
+
+    let duplicates = qmc::VariableSet::duplicate_positions(&variables);
+
+    assert_eq!(names, HashSet::from(["A".to_owned(), "B".to_owned()]));
+    assert_eq!(duplicates.len(), 2);
+    assert_eq!(duplicates["A"], [0, 2, 5]);
+    assert_eq!(duplicates["B"], [1, 4]);
+}
+
 #[test]
 #[should_panic(expected = "TermOutOfBounds")]
 fn term_out_of_bounds() {
@@ -196,3 +225,149 @@ fn conflicting_terms2() {
 fn conflicting_terms3() {
     qmc::minimize_maxterms(&["A", "B", "C"], &[0, 1, 2, 3], &[1, 4, 3], false, None).unwrap();
 }
+
+#[test]
+#[should_panic(expected = "InvalidCube")]
+fn invalid_cube_character() {
+    qmc::Cube::expand(3, &["1x0"]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidCube")]
+fn invalid_cube_length() {
+    qmc::Cube::expand(3, &["1-0-"]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidProbabilities")]
+fn invalid_probability_count() {
+    qmc::minimize_power_aware(&["A", "B"], &[1], &[0], qmc::SOP, &[0.5], None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidProbabilities")]
+fn probability_out_of_range() {
+    qmc::minimize_power_aware(&["A", "B"], &[1], &[0], qmc::SOP, &[0.5, 1.5], None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "TermConflict")]
+fn conflicting_specifications() {
+    let specifications = [
+        qmc::BooleanFunction::new(&["A", "B"], &[3], &[0]).unwrap(),
+        qmc::BooleanFunction::new(&["A", "B"], &[0], &[1]).unwrap(),
+    ];
+
+    qmc::minimize_union(&specifications, qmc::SOP, false, None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "MismatchedVariables")]
+fn mismatched_specifications() {
+    let specifications = [
+        qmc::BooleanFunction::new(&["A", "B"], &[3], &[0]).unwrap(),
+        qmc::BooleanFunction::new(&["A", "C"], &[2], &[1]).unwrap(),
+    ];
+
+    qmc::minimize_union(&specifications, qmc::SOP, false, None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidVariableCount")]
+fn no_specifications() {
+    qmc::minimize_union(&[], qmc::SOP, false, None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "TermOutOfBounds")]
+fn bits_out_of_bounds() {
+    qmc::minimize_from_bits(&["A", "B"], &[0b1_0001], &[], qmc::SOP, false, None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "TermConflict")]
+fn conflicting_bits() {
+    qmc::minimize_from_bits(&["A", "B"], &[0b0011], &[0b0110], qmc::SOP, false, None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "TermConflict")]
+fn conflicting_term_outputs() {
+    qmc::minimize_terms(
+        &["A", "B"],
+        [(1, true), (2, false), (1, false)],
+        qmc::SOP,
+        false,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn range_out_of_bounds() {
+    let result = qmc::minimize_ranges(
+        &["A", "B"],
+        &[0..2, 3..9],
+        &[2..3, 7..7],
+        qmc::SOP,
+        false,
+        None,
+    );
+
+    assert!(matches!(
+        result,
+        Err(qmc::Error::TermOutOfBounds { offending_terms, .. }) if offending_terms == [4].into()
+    ));
+}
+
+#[test]
+fn conflicting_ranges() {
+    let result = qmc::minimize_ranges(
+        &["A", "B", "C"],
+        &[0..3, 6..8],
+        &[2..4, 4..5],
+        qmc::POS,
+        false,
+        None,
+    );
+
+    assert!(matches!(
+        result,
+        Err(qmc::Error::TermConflict(conflicts)) if conflicts == [2].into()
+    ));
+}
+
+#[test]
+#[should_panic(expected = "InvalidCube")]
+fn invalid_cover() {
+    qmc::minimize_cover(&["A", "B"], &["1-", "0-1"], &["00"], qmc::SOP, false, None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "TermConflict")]
+fn dont_care_predicate_conflict() {
+    qmc::minimize_minterms_with(&["A", "B"], &[1, 3], |term| term % 2 == 1, false, None).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidOutputCount")]
+fn invalid_output_count() {
+    qmc::minimize_outputs(&["A", "B"], &[Some(true), None], qmc::SOP, false, None).unwrap();
+}
+
+#[test]
+fn error_messages() {
+    let error: Box<dyn std::error::Error> = Box::new(qmc::Error::TermOutOfBounds {
+        offending_terms: [4].into(),
+        variable_count: 2,
+    });
+
+    assert_eq!(
+        error.to_string(),
+        "Terms out of bounds: {4} (expected < 4 for 2 variables)"
+    );
+    assert_eq!(
+        qmc::Error::Timeout.to_string(),
+        "Could not find the solution in time."
+    );
+}
@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+
+use quine_mccluskey as qmc;
+
+#[test]
+fn parse_blif() {
+    let blif: qmc::Blif = "\
+# Majority of three with a continued line
+.model majority
+.inputs a b \\
+  c
+.outputs m
+.names a b c m
+11- 1
+1-1 1  # a and c
+-11 1
+.end
+"
+    .parse()
+    .unwrap();
+
+    assert_eq!(blif.model(), Some("majority"));
+    assert_eq!(blif.output(), "m");
+    assert_eq!(blif.function().variables(), ["a", "b", "c"]);
+
+    let mut minterms = Vec::from_iter(blif.function().minterms().iter().copied());
+    minterms.sort_unstable();
+
+    assert_eq!(minterms, [3, 5, 6, 7]);
+    assert!(blif.function().dont_cares().is_empty());
+
+    let blif: qmc::Blif = ".names x y z\n00 0\n".parse().unwrap();
+
+    assert_eq!(blif.model(), None);
+    assert_eq!(blif.function().maxterms(), &HashSet::from_iter([0]));
+    assert_eq!(
+        blif.function().minimize(qmc::SOP, false, None).unwrap()[0].to_string(),
+        "x ∨ y"
+    );
+
+    let blif: qmc::Blif = ".names x y z\n".parse().unwrap();
+
+    assert!(blif.function().minimize(qmc::SOP, false, None).unwrap()[0].is_zero());
+}
+
+#[test]
+fn invalid_blif() {
+    for blif in [
+        ".model m\n.end\n",
+        "11 1\n",
+        ".names a b f\n1 1\n",
+        ".names a b f\n11 2\n",
+        ".names a b f\n1x 1\n",
+        ".names a b f\n11 1\n00 0\n",
+        ".names a f\n1 1\n.names b g\n1 1\n",
+        ".outputs g\n.names a f\n1 1\n",
+        ".latch a b\n",
+    ] {
+        assert!(
+            matches!(blif.parse::<qmc::Blif>(), Err(qmc::Error::InvalidBlif(_))),
+            "{}",
+            blif
+        );
+    }
+
+    assert!(matches!(
+        ".names a a f\n".parse::<qmc::Blif>(),
+        Err(qmc::Error::DuplicateVariables(_))
+    ));
+}
+
+#[test]
+fn write_blif() {
+    let variables = ["A", "B", "C"];
+
+    for (minterms, maxterms) in [
+        (vec![1, 3, 6, 7], vec![0, 2, 4, 5]),
+        (vec![0, 1, 2, 3, 4], vec![5, 6]),
+        (vec![], vec![0, 1, 2, 3, 4, 5, 6, 7]),
+        (vec![0, 1, 2, 3, 4, 5, 6, 7], vec![]),
+    ] {
+        for form in [qmc::SOP, qmc::POS] {
+            let solution = qmc::BooleanFunction::new(&variables, &minterms, &maxterms)
+                .unwrap()
+                .minimize(form, false, None)
+                .unwrap()
+                .remove(0);
+            let blif: qmc::Blif = format!(".model m\n{}.end\n", solution.to_blif("f"))
+                .parse()
+                .unwrap();
+
+            assert_eq!(blif.output(), "f");
+            assert_eq!(blif.function().variables(), variables);
+            assert_eq!(
+                blif.function().minterms(),
+                &HashSet::from_iter(minterms.iter().copied()),
+                "{}",
+                solution
+            );
+            assert!(blif.function().dont_cares().is_empty());
+        }
+    }
+}
+
+#[test]
+fn write_blif_model() {
+    let solution = qmc::BooleanFunction::new(&["a", "b"], &[1, 2], &[0, 3])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+    let other_solution = qmc::BooleanFunction::new(&["a", "c"], &[1], &[0, 2, 3])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    let blif: qmc::Blif = qmc::solutions_to_blif("xor", std::slice::from_ref(&solution), &["f"])
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    assert_eq!(blif.model(), Some("xor"));
+    assert_eq!(blif.output(), "f");
+    assert_eq!(
+        blif.function(),
+        &qmc::BooleanFunction::new(&["a", "b"], &[1, 2], &[0, 3]).unwrap()
+    );
+
+    assert!(matches!(
+        qmc::solutions_to_blif::<&str>("xor", &[], &[]),
+        Err(qmc::Error::InvalidBlif(_))
+    ));
+    assert!(matches!(
+        qmc::solutions_to_blif("xor", std::slice::from_ref(&solution), &["f", "g"]),
+        Err(qmc::Error::InvalidBlif(_))
+    ));
+    assert!(matches!(
+        qmc::solutions_to_blif("xor", &[solution, other_solution], &["f", "g"]),
+        Err(qmc::Error::MismatchedVariables { .. })
+    ));
+}
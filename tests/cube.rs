@@ -0,0 +1,185 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn cube_terms() {
+    let cube: qmc::Cube = "1--0".parse().unwrap();
+
+    assert_eq!(cube.variable_count(), 4);
+    assert_eq!(cube.terms(), [8, 10, 12, 14]);
+    assert!(cube.contains(12));
+    assert!(!cube.contains(9));
+    assert_eq!(cube.to_string(), "1--0");
+}
+
+#[test]
+fn expand() {
+    assert_eq!(
+        qmc::Cube::expand(3, &["1-0", "11-", "000"]).unwrap(),
+        [0, 4, 6, 7]
+    );
+    assert!(qmc::Cube::expand::<&str>(3, &[]).unwrap().is_empty());
+}
+
+#[test]
+fn dont_care_cubes() {
+    let dont_cares = qmc::Cube::expand(3, &["-1-"]).unwrap();
+
+    let mut solutions = qmc::minimize_maxterms(
+        &qmc::DEFAULT_VARIABLES[..3],
+        &[1, 4],
+        &dont_cares,
+        false,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(solutions.pop().unwrap().to_string(), "(A ∨ ~C) ∧ (~A ∨ C)");
+}
+
+#[test]
+fn solution_cubes() {
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::BooleanFunction::new(
+            &qmc::DEFAULT_VARIABLES[..4],
+            &[2, 4, 5, 7, 9],
+            &[3, 6, 10, 12, 15],
+        )
+        .unwrap()
+        .minimize(form, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+        let cubes = solution.cubes();
+        let terms = solution.terms();
+        assert_eq!(cubes.len(), terms.len());
+
+        for (cube, variables) in cubes.iter().zip(&terms) {
+            let literals = (0..4)
+                .filter_map(|index| {
+                    cube.literal(index, form).map(|polarity| {
+                        (
+                            qmc::DEFAULT_VARIABLES[index].to_owned(),
+                            polarity == qmc::Polarity::Negative,
+                        )
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                literals,
+                variables
+                    .iter()
+                    .map(|variable| (variable.name.clone(), variable.is_negated))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "variable index out of bounds")]
+fn literal_out_of_bounds() {
+    let cube: qmc::Cube = "1-0".parse().unwrap();
+
+    cube.literal(3, qmc::SOP);
+}
+
+#[test]
+fn minimize_with_dont_care_cubes() {
+    let cases: [(&[u32], &[&str]); 4] = [
+        (&[1, 3, 5, 7, 9], &["1-1-", "11--"]),
+        (&[0, 2, 8, 10], &["-1-1", "0-01"]),
+        (&[8, 12, 13], &["0---", "--11"]),
+        (&[], &["1---"]),
+    ];
+
+    for (terms, cubes) in cases {
+        let dont_cares = qmc::Cube::expand(4, cubes).unwrap();
+
+        fn to_strings<T: ToString>(solutions: Vec<T>) -> Vec<String> {
+            let mut strings = Vec::from_iter(solutions.iter().map(ToString::to_string));
+            strings.sort_unstable();
+            strings
+        }
+
+        assert_eq!(
+            to_strings(
+                qmc::minimize_minterms_with_cubes(
+                    &qmc::DEFAULT_VARIABLES[..4],
+                    terms,
+                    cubes,
+                    true,
+                    None
+                )
+                .unwrap()
+            ),
+            to_strings(
+                qmc::minimize_minterms(
+                    &qmc::DEFAULT_VARIABLES[..4],
+                    terms,
+                    &dont_cares,
+                    true,
+                    None
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(
+            to_strings(
+                qmc::minimize_maxterms_with_cubes(
+                    &qmc::DEFAULT_VARIABLES[..4],
+                    terms,
+                    cubes,
+                    true,
+                    None
+                )
+                .unwrap()
+            ),
+            to_strings(
+                qmc::minimize_maxterms(
+                    &qmc::DEFAULT_VARIABLES[..4],
+                    terms,
+                    &dont_cares,
+                    true,
+                    None
+                )
+                .unwrap()
+            )
+        );
+    }
+}
+
+#[test]
+fn large_dont_care_cubes() {
+    // The cubes contain 3 * 2^23 terms, which are never listed.
+    let mut solutions = qmc::minimize_minterms_with_cubes(
+        &qmc::DEFAULT_VARIABLES,
+        &[0, 1],
+        &["1-------------------------", "-1------------------------"],
+        false,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        solutions.pop().unwrap().to_string(),
+        "~C ∧ ~D ∧ ~E ∧ ~F ∧ ~G ∧ ~H ∧ ~I ∧ ~J ∧ ~K ∧ ~L ∧ ~M ∧ ~N ∧ ~O ∧ ~P ∧ ~Q ∧ ~R ∧ ~S ∧ ~T ∧ ~U ∧ ~V ∧ ~W ∧ ~X ∧ ~Y"
+    );
+}
+
+#[test]
+fn conflicting_dont_care_cubes() {
+    assert!(matches!(
+        qmc::minimize_minterms_with_cubes(&qmc::DEFAULT_VARIABLES[..3], &[1, 4], &["1-0"], false, None),
+        Err(qmc::Error::TermConflict(conflicts)) if conflicts == [4].into()
+    ));
+    assert!(matches!(
+        qmc::minimize_cover(&qmc::DEFAULT_VARIABLES[..3], &["1-0"], &["11-"], qmc::POS, false, None),
+        Err(qmc::Error::TermConflict(conflicts)) if conflicts == [6].into()
+    ));
+    assert!(matches!(
+        qmc::minimize_maxterms_with_cubes(&qmc::DEFAULT_VARIABLES[..3], &[1], &["1-"], false, None),
+        Err(qmc::Error::InvalidCube(_))
+    ));
+}
@@ -0,0 +1,79 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn cube_list_round_trip() {
+    let variables = qmc::VariableSet::new(&qmc::DEFAULT_VARIABLES[..10]).unwrap();
+    let cube = |cube| qmc::Cube::new(&variables, cube).unwrap();
+
+    let cube_list = qmc::CubeList::new(
+        &variables,
+        &[cube("1-0-1-0-1-"), cube("0000000001")],
+        &[cube("01--------"), cube("1111111111")],
+        &[],
+    )
+    .unwrap();
+    let bytes = cube_list.to_bytes().unwrap();
+
+    // 4 magic bytes, 1 version byte, 1 variable count byte, 10 variables of 3 bytes
+    // and 3 cube counts of 4 bytes with 4 cubes of 2 * 2 bytes.
+    assert_eq!(bytes.len(), 4 + 1 + 1 + 10 * 3 + 3 * 4 + 4 * 4);
+    assert_eq!(qmc::CubeList::from_bytes(&bytes).unwrap(), cube_list);
+
+    let function = cube_list.to_function().unwrap();
+
+    assert_eq!(
+        Vec::from_iter(cube_list.off_set().iter().flat_map(qmc::Cube::terms)).len(),
+        function.maxterms().len()
+    );
+}
+
+#[test]
+fn invalid_cube_list() {
+    let variables = qmc::VariableSet::new(&["A", "B"]).unwrap();
+    let cube = |cube| qmc::Cube::new(&variables, cube).unwrap();
+
+    assert!(matches!(
+        qmc::CubeList::new(&variables, &[cube("1-")], &[], &[cube("-1")]),
+        Err(qmc::Error::TermConflict(terms)) if terms == [3].into_iter().collect()
+    ));
+    assert!(matches!(
+        qmc::CubeList::new(&variables, &["1-0".parse().unwrap()], &[], &[]),
+        Err(qmc::Error::InvalidCube(_))
+    ));
+
+    let bytes = qmc::CubeList::new(&variables, &[cube("1-")], &[cube("0-")], &[])
+        .unwrap()
+        .to_bytes()
+        .unwrap();
+
+    for bytes in [
+        &bytes[..bytes.len() - 1],
+        &[&bytes[..], &[0]].concat(),
+        b"QMCM",
+    ] {
+        assert!(matches!(
+            qmc::CubeList::from_bytes(bytes),
+            Err(qmc::Error::InvalidCubeList(_))
+        ));
+    }
+
+    let mut bytes = bytes;
+    let last = bytes.len() - 1;
+    bytes[last] = 0b100;
+
+    assert!(matches!(
+        qmc::CubeList::from_bytes(&bytes),
+        Err(qmc::Error::InvalidCubeList(_))
+    ));
+}
+
+#[test]
+fn long_variable() {
+    let variables = qmc::VariableSet::new(&["A".repeat(1 << 16)]).unwrap();
+    let cube_list = qmc::CubeList::new(&variables, &[], &[], &[]).unwrap();
+
+    assert!(matches!(
+        cube_list.to_bytes(),
+        Err(qmc::Error::InvalidCubeList(_))
+    ));
+}
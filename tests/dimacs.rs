@@ -0,0 +1,72 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn dimacs_round_trip() {
+    let variables = ["x", "y z", "w"];
+    let solution = qmc::BooleanFunction::new(&variables, &[0, 1, 3, 7], &[2, 4, 6])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+    let dimacs: qmc::Dimacs = solution.to_dimacs().unwrap().parse().unwrap();
+
+    assert_eq!(dimacs.clause_count(), solution.clauses().unwrap().len());
+    assert_eq!(dimacs.function().variables(), variables);
+    assert_eq!(dimacs.function().minterms().len(), 8 - 3);
+
+    for term in [2, 4, 6] {
+        assert_eq!(dimacs.function().evaluate(term), Some(false));
+    }
+
+    for term in [0, 1, 3, 7] {
+        assert_eq!(dimacs.function().evaluate(term), Some(true));
+    }
+}
+
+#[test]
+fn dimacs_default_variables() {
+    let dimacs: qmc::Dimacs = "\
+c SATLIB style
+p cnf 2 2
+1 -1 0 -2
+0
+%
+0
+"
+    .parse()
+    .unwrap();
+
+    assert_eq!(dimacs.function().variables(), ["A", "B"]);
+
+    let mut maxterms = Vec::from_iter(dimacs.function().maxterms().iter().copied());
+    maxterms.sort_unstable();
+
+    assert_eq!(maxterms, [1, 3]);
+}
+
+#[test]
+fn dimacs_invalid() {
+    let parse = |s: &str| s.parse::<qmc::Dimacs>();
+
+    assert!(matches!(parse("1 0\n"), Err(qmc::Error::InvalidDimacs(_))));
+    assert!(matches!(
+        parse("p cnf 2 1\n3 0\n"),
+        Err(qmc::Error::InvalidDimacs(_))
+    ));
+    assert!(matches!(
+        parse("p cnf 2 2\n1 0\n"),
+        Err(qmc::Error::InvalidDimacs(_))
+    ));
+    assert!(matches!(
+        parse("p cnf 2 1\n1 2\n"),
+        Err(qmc::Error::InvalidDimacs(_))
+    ));
+    assert!(matches!(
+        parse("p dnf 2 1\n1 2 0\n"),
+        Err(qmc::Error::InvalidDimacs(_))
+    ));
+    assert!(matches!(
+        parse("p cnf 27 0\n"),
+        Err(qmc::Error::InvalidVariableCount(27))
+    ));
+}
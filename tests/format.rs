@@ -0,0 +1,153 @@
+use std::thread;
+
+use quine_mccluskey as qmc;
+
+#[test]
+fn default_format() {
+    let solution = qmc::minimize(
+        &["A", "B", "C"],
+        &[1, 3, 6, 7],
+        &[0, 2, 4, 5],
+        qmc::SOP,
+        false,
+        None,
+    )
+    .unwrap()
+    .remove(0);
+
+    assert_eq!(qmc::default_format(), qmc::ExpressionStyle::default());
+    assert_eq!(solution.to_string(), "(A ∧ B) ∨ (~A ∧ C)");
+
+    {
+        let _guard = qmc::set_default_format(qmc::ExpressionStyle::ascii());
+
+        assert_eq!(solution.to_string(), "(A & B) | (~A & C)");
+
+        {
+            let _guard = qmc::set_default_format(
+                qmc::ExpressionStyle::default()
+                    .with_negation(qmc::Negation::Postfix("'".to_owned())),
+            );
+
+            assert_eq!(solution.to_string(), "(A ∧ B) ∨ (A' ∧ C)");
+        }
+
+        assert_eq!(solution.to_string(), "(A & B) | (~A & C)");
+
+        // Other threads keep their own style.
+        let other_solution = solution.clone();
+
+        assert_eq!(
+            thread::spawn(move || other_solution.to_string())
+                .join()
+                .unwrap(),
+            "(A ∧ B) ∨ (~A ∧ C)"
+        );
+    }
+
+    assert_eq!(qmc::default_format(), qmc::ExpressionStyle::default());
+    assert_eq!(solution.to_string(), "(A ∧ B) ∨ (~A ∧ C)");
+}
+
+#[test]
+fn default_format_solution() {
+    let cover = qmc::BooleanFunction::new(&["A", "B"], &[1, 3], &[0])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+    let solution = qmc::Solution::from(&cover);
+
+    assert_eq!(solution.to_string(), cover.to_string());
+
+    let _guard = qmc::set_default_format(qmc::ExpressionStyle::python());
+
+    assert_eq!(solution.to_string(), cover.to_string());
+    assert_eq!(qmc::Solution::One.to_string(), "True");
+}
+
+#[test]
+fn to_string_with() {
+    let solution = qmc::BooleanFunction::new(&["A", "B1", "C"], &[4, 5, 7], &[0, 1, 2, 3, 6])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_string_with(qmc::ExpressionStyle::default()),
+        solution.to_string()
+    );
+
+    assert_eq!(
+        solution.to_string_with(qmc::ExpressionStyle::c_style()),
+        "A && (!B1 || C)"
+    );
+
+    let style = qmc::ExpressionStyle::default()
+        .with_and("·")
+        .with_or("+")
+        .with_negation(qmc::Negation::Overline)
+        .with_spaced(false);
+
+    assert_eq!(solution.to_string_with(&style), "A·(B\u{305}1\u{305}+C)");
+
+    let one = qmc::BooleanFunction::new(&["A"], &[0, 1], &[])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(one.to_string_with(&style), "1");
+    assert_eq!(one.to_string_with(style.with_one("⊤").with_zero("⊥")), "⊤");
+    assert_eq!(one.to_string_with(qmc::ExpressionStyle::python()), "True");
+    assert_eq!(
+        one.complement()
+            .to_string_with(qmc::ExpressionStyle::verilog()),
+        "1'b0"
+    );
+}
+
+#[test]
+fn presets() {
+    let solution = qmc::BooleanFunction::new(&["a b", "if", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_string_with(qmc::Style::Ascii),
+        "(a b & if) | (~a b & C)"
+    );
+    assert_eq!(
+        solution.to_string_with(qmc::Style::CStyle),
+        solution.to_c_expr(false)
+    );
+    assert_eq!(
+        solution.to_string_with(qmc::Style::Python),
+        solution.to_python_expr()
+    );
+    assert_eq!(
+        solution.to_string_with(qmc::Style::CStyle),
+        "(a_b && if_) || (!a_b && C)"
+    );
+    assert!(solution
+        .to_verilog("m")
+        .unwrap()
+        .contains(&solution.to_string_with(qmc::Style::VerilogOps)));
+
+    let _guard = qmc::set_default_format(qmc::Style::Python);
+
+    assert_eq!(solution.to_string(), solution.to_python_expr());
+    assert_eq!(
+        qmc::Solution::from(&solution).to_string(),
+        solution.to_python_expr()
+    );
+    assert_eq!(
+        solution.to_string_with(
+            qmc::ExpressionStyle::python().with_identifiers(qmc::Identifiers::Verbatim)
+        ),
+        "(a b and if) or (not a b and C)"
+    );
+}
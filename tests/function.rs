@@ -0,0 +1,521 @@
+use std::collections::HashSet;
+
+use quine_mccluskey as qmc;
+
+fn minimize(function: &qmc::BooleanFunction) -> String {
+    function
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn exists() {
+    // (A ∧ B) ∨ (~A ∧ C)
+    let function =
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5]).unwrap();
+
+    assert_eq!(minimize(&function.exists(&["A"]).unwrap()), "B ∨ C");
+    assert_eq!(minimize(&function.exists(&["B"]).unwrap()), "A ∨ C");
+    assert_eq!(minimize(&function.exists(&["A", "B"]).unwrap()), "1");
+    assert_eq!(function.exists(&["A"]).unwrap().variables(), ["B", "C"]);
+}
+
+#[test]
+fn forall() {
+    // (A ∧ B) ∨ (~A ∧ C)
+    let function =
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5]).unwrap();
+
+    assert_eq!(minimize(&function.forall(&["A"]).unwrap()), "B ∧ C");
+    assert_eq!(minimize(&function.forall(&["B"]).unwrap()), "~A ∧ C");
+    assert_eq!(minimize(&function.forall(&["A", "B"]).unwrap()), "0");
+}
+
+#[test]
+fn derivative() {
+    // (A ∧ B) ∨ (~A ∧ C)
+    let function =
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5]).unwrap();
+
+    assert_eq!(minimize(&function.derivative("B").unwrap()), "A");
+    assert_eq!(minimize(&function.derivative("C").unwrap()), "~A");
+    assert_eq!(function.derivative("B").unwrap().variables(), ["A", "C"]);
+
+    // A ⊕ B with 3 unspecified
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[1, 2], &[0]).unwrap();
+    let derivative = function.derivative("A").unwrap();
+
+    assert_eq!(derivative.evaluate(0), Some(true));
+    assert_eq!(derivative.evaluate(1), None);
+}
+
+#[test]
+fn quantify_dont_cares() {
+    // Equals A when B is 1 and is unspecified when B is 0.
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[1]).unwrap();
+
+    let exists = function.exists(&["A"]).unwrap();
+    assert_eq!(exists.evaluate(0), None);
+    assert_eq!(exists.evaluate(1), Some(true));
+
+    let forall = function.forall(&["A"]).unwrap();
+    assert_eq!(forall.evaluate(0), None);
+    assert_eq!(forall.evaluate(1), Some(false));
+}
+
+#[test]
+#[should_panic(expected = "UnknownVariable")]
+fn quantify_unknown_variable() {
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[1]).unwrap();
+
+    function.exists(&["C"]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidVariableCount")]
+fn quantify_all_variables() {
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[1]).unwrap();
+
+    function.forall(&["A", "B"]).unwrap();
+}
+
+#[test]
+fn dont_care_usage() {
+    // BCD digit 1 to 7 detector with invalid digits and a reserved code as don't cares.
+    let mut function =
+        qmc::BooleanFunction::new(&["A", "B", "C", "D"], &[1, 2, 3, 4, 5, 6, 7], &[0, 8, 9])
+            .unwrap();
+    function
+        .label_dont_cares("invalid digit", &[10, 11, 12, 13, 14])
+        .unwrap();
+    function.label_dont_cares("reserved", &[15]).unwrap();
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = function.minimize(form, false, None).unwrap().pop().unwrap();
+
+        assert_eq!(
+            function.exploited_dont_cares(&solution),
+            [10, 11, 12, 13, 14, 15]
+        );
+        assert_eq!(
+            function.dont_care_usage(&solution),
+            [
+                qmc::DontCareUsage {
+                    label: "invalid digit".to_owned(),
+                    terms: vec![10, 11, 12, 13, 14],
+                },
+                qmc::DontCareUsage {
+                    label: "reserved".to_owned(),
+                    terms: vec![15],
+                },
+            ]
+        );
+    }
+}
+
+#[test]
+fn unused_dont_cares() {
+    let mut function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0]).unwrap();
+    function.label_dont_cares("B only", &[1]).unwrap();
+    function.label_dont_cares("A only", &[2]).unwrap();
+
+    let solution = function
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+    let usage = function.dont_care_usage(&solution);
+
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage[0].label, format!("{} only", solution));
+}
+
+#[test]
+#[should_panic(expected = "TermConflict")]
+fn label_care_terms() {
+    let mut function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[1]).unwrap();
+
+    function.label_dont_cares("invalid", &[0, 1]).unwrap();
+}
+
+#[test]
+fn certificate() {
+    let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5], &[0, 2, 4]).unwrap();
+
+    for form in [qmc::SOP, qmc::POS] {
+        let mut solution = function.minimize(form, false, None).unwrap().pop().unwrap();
+        assert!(solution.certificate().is_none());
+
+        function.certify(&mut solution).unwrap();
+
+        let certificate = solution.certificate().unwrap();
+        assert_eq!(certificate.ones, [7]);
+        assert_eq!(certificate.zeros, [6]);
+
+        let completed_function = function.complete(certificate).unwrap();
+        assert!(completed_function.dont_cares().is_empty());
+        assert_eq!(completed_function.evaluate(6), Some(false));
+        assert_eq!(completed_function.evaluate(7), Some(true));
+    }
+}
+
+#[test]
+fn certificate_of_other_function() {
+    let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5], &[0, 2, 4]).unwrap();
+    let other_function =
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5], &[0, 2, 4, 6]).unwrap();
+
+    let mut solution = function.minimize(qmc::SOP, false, None).unwrap().remove(0);
+    function.certify(&mut solution).unwrap();
+
+    assert!(matches!(
+        other_function.complete(solution.certificate().unwrap()),
+        Err(qmc::Error::CertificateMismatch)
+    ));
+
+    let mut other_variables_solution =
+        qmc::BooleanFunction::new(&["A", "B", "D"], &[1, 3, 5], &[0, 2, 4])
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+    assert!(matches!(
+        function.certify(&mut other_variables_solution),
+        Err(qmc::Error::MismatchedVariables { .. })
+    ));
+    assert!(other_variables_solution.certificate().is_none());
+}
+
+#[test]
+fn completion() {
+    let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5], &[0, 2, 4]).unwrap();
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = function.minimize(form, false, None).unwrap().pop().unwrap();
+        let completion = function.completion(&solution);
+
+        assert_eq!(completion.forced_ones(), [7]);
+        assert_eq!(completion.forced_zeros(), [6]);
+        assert!(completion.function().dont_cares().is_empty());
+
+        assert_eq!(
+            completion.truth_table().outputs(),
+            Vec::from_iter((0..8).map(|term| Some(term % 2 == 1)))
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "IncompleteCertificate")]
+fn incomplete_certificate() {
+    let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5], &[0, 2, 4]).unwrap();
+
+    function
+        .complete(&qmc::Certificate {
+            ones: vec![7],
+            zeros: vec![],
+
+            function_digest: function.digest(),
+        })
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "TermConflict")]
+fn certificate_assigns_care_term() {
+    let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5], &[0, 2, 4]).unwrap();
+
+    function
+        .complete(&qmc::Certificate {
+            ones: vec![7],
+            zeros: vec![1, 6],
+
+            function_digest: function.digest(),
+        })
+        .unwrap();
+}
+
+#[test]
+fn term_assignment() {
+    assert_eq!(
+        qmc::term_assignment(&["A", "B", "C", "D"], 0b1001).unwrap(),
+        [("A", true), ("B", false), ("C", false), ("D", true)]
+    );
+    assert_eq!(qmc::term_assignment(&["X"], 0).unwrap(), [("X", false)]);
+}
+
+#[test]
+#[should_panic(expected = "TermOutOfBounds")]
+fn term_assignment_out_of_bounds() {
+    qmc::term_assignment(&["A", "B"], 4).unwrap();
+}
+
+#[test]
+fn affine_form() {
+    // ~(A ⊕ C) with B irrelevant
+    let function =
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 2, 5, 7], &[1, 3, 4, 6]).unwrap();
+    let affine_form = function.affine_form().unwrap();
+
+    assert!(affine_form.constant);
+    assert_eq!(affine_form.variables, ["A", "C"]);
+    assert_eq!(affine_form.to_string(), "A ⊕ C ⊕ 1");
+
+    // Completable to A ⊕ B only by assigning the don't care 3 to 0.
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[1, 2], &[0]).unwrap();
+
+    assert_eq!(function.affine_form().unwrap().to_string(), "A ⊕ B");
+
+    // A ∧ B
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1, 2]).unwrap();
+
+    assert_eq!(function.affine_form(), None);
+
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[], &[]).unwrap();
+
+    assert_eq!(function.affine_form().unwrap().to_string(), "0");
+}
+
+#[test]
+fn threshold_form() {
+    let variables = ["A", "B", "C", "D", "E"];
+
+    for (weights, threshold) in [
+        ([1, 1, 1, 1, 1], 3),
+        ([3, 2, 2, 1, 1], 5),
+        ([5, -3, 2, 2, 0], 2),
+        ([8, 4, 2, 1, -1], 9),
+    ] {
+        let weighted_sum = |weights: &[i64], term: u32| -> i64 {
+            (0..5)
+                .filter(|&i| term >> (4 - i) & 1 == 1)
+                .map(|i| weights[i])
+                .sum()
+        };
+
+        let (minterms, maxterms): (Vec<u32>, Vec<u32>) =
+            (0..32).partition(|&term| weighted_sum(&weights, term) >= threshold);
+        let function = qmc::BooleanFunction::new(&variables, &minterms, &maxterms).unwrap();
+        let threshold_form = function.threshold_form().unwrap();
+        let found_weights =
+            Vec::from_iter(threshold_form.weights.iter().map(|(_, weight)| *weight));
+
+        for term in 0..32 {
+            assert_eq!(
+                weighted_sum(&found_weights, term) >= threshold_form.threshold,
+                minterms.contains(&term),
+                "{}",
+                threshold_form
+            );
+        }
+    }
+
+    // A ∧ (B ∨ C)
+    let function =
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[5, 6, 7], &[0, 1, 2, 3, 4]).unwrap();
+
+    assert_eq!(
+        function.threshold_form().unwrap().to_string(),
+        "3A + B + C ≥ 4"
+    );
+
+    // A ⊕ B
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[1, 2], &[0, 3]).unwrap();
+
+    assert_eq!(function.threshold_form(), None);
+}
+
+#[test]
+fn union() {
+    let specifications = [
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[7], &[0, 1]).unwrap(),
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[5, 7], &[1, 2]).unwrap(),
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[], &[3]).unwrap(),
+    ];
+    let function = qmc::BooleanFunction::union(&specifications).unwrap();
+
+    assert_eq!(function.minterms(), &HashSet::from_iter([5, 7]));
+    assert_eq!(function.maxterms(), &HashSet::from_iter([0, 1, 2, 3]));
+
+    let mut solutions = qmc::minimize_union(&specifications, qmc::SOP, false, None).unwrap();
+
+    assert_eq!(solutions.pop().unwrap().to_string(), "A");
+}
+
+#[test]
+fn diff() {
+    let a = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 5, 7], &[0, 2, 4]).unwrap();
+    let b = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 2, 5], &[0, 3, 7]).unwrap();
+
+    let diff = qmc::diff_functions(&a, &b).unwrap();
+
+    assert_eq!(diff.differ, [2, 3, 7]);
+    assert_eq!(diff.agree, [0, 1, 5]);
+    assert_eq!(diff.may_agree, [4, 6]);
+    assert_eq!(diff, qmc::diff_functions(&b, &a).unwrap());
+    assert_eq!(minimize(&diff.difference()), "B");
+
+    let c = qmc::BooleanFunction::new(&["A", "C", "B"], &[], &[]).unwrap();
+
+    assert!(matches!(
+        qmc::diff_functions(&a, &c),
+        Err(qmc::Error::MismatchedVariables { .. })
+    ));
+}
+
+#[test]
+fn global_dont_cares() {
+    let variables = ["S2", "S1", "S0"];
+    // States 5 to 7 are unreachable.
+    let unreachable = qmc::GlobalDontCareSet::from_fn(&variables, |state| state >= 5).unwrap();
+    let mut next_state = [
+        qmc::BooleanFunction::new(&variables, &[3, 4], &[0, 1, 2]).unwrap(),
+        qmc::BooleanFunction::new(&variables, &[1, 2], &[0, 3, 4, 5, 6, 7]).unwrap(),
+        qmc::BooleanFunction::new(&variables, &[0, 2], &[1, 3, 4]).unwrap(),
+    ];
+    next_state[0].label_dont_cares("unused", &[5]).unwrap();
+
+    assert_eq!(unreachable.terms(), &HashSet::from_iter([5, 6, 7]));
+    assert!(unreachable
+        .overridden_care_terms(&next_state[0])
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        unreachable.overridden_care_terms(&next_state[1]).unwrap(),
+        [5, 6, 7]
+    );
+
+    let applied = unreachable.apply(&next_state[1]).unwrap();
+
+    assert_eq!(applied.dont_cares(), HashSet::from_iter([5, 6, 7]));
+    assert_eq!(applied.minterms(), next_state[1].minterms());
+
+    let solutions = unreachable
+        .minimize(&next_state, qmc::SOP, false, None)
+        .unwrap();
+
+    assert_eq!(
+        Vec::from_iter(solutions.iter().map(|solutions| solutions[0].to_string())),
+        ["S2 ∨ (S1 ∧ S0)", "(S1 ∧ ~S0) ∨ (~S1 ∧ S0)", "~S2 ∧ ~S0"]
+    );
+    assert_eq!(
+        next_state[0].dont_care_usage(&solutions[0][0]),
+        unreachable
+            .apply(&next_state[0])
+            .unwrap()
+            .dont_care_usage(&solutions[0][0])
+    );
+
+    let other = qmc::BooleanFunction::new(&["A", "B", "C"], &[], &[]).unwrap();
+
+    assert!(matches!(
+        unreachable.apply(&other),
+        Err(qmc::Error::MismatchedVariables { .. })
+    ));
+}
+
+#[test]
+fn unreachable_states() {
+    let state_variables = ["S2", "S1", "S0"];
+    // A counter from 0 to 4 that advances when E is 1.
+    let next = |state: u32, enable: bool| if enable { (state + 1) % 5 } else { state };
+    let transitions = Vec::from_iter(
+        (0..5).flat_map(|state| [false, true].map(|enable| (state, next(state, enable)))),
+    );
+
+    let unreachable =
+        qmc::GlobalDontCareSet::unreachable_states(&state_variables, &[0], &transitions).unwrap();
+
+    assert_eq!(unreachable.terms(), &HashSet::from_iter([5, 6, 7]));
+
+    let variables = ["S2", "S1", "S0", "E"];
+    let next_state = Vec::from_iter((0..3).map(|bit| {
+        let (minterms, maxterms): (Vec<_>, Vec<_>) =
+            (0..10).partition(|&term| next(term >> 1, term & 1 == 1) >> (2 - bit) & 1 == 1);
+
+        qmc::BooleanFunction::new(&variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0)
+    }));
+
+    assert_eq!(
+        qmc::GlobalDontCareSet::unreachable_states_from_equations(
+            &state_variables,
+            &["E"],
+            &[0],
+            &next_state
+        )
+        .unwrap(),
+        unreachable
+    );
+    assert!(matches!(
+        qmc::GlobalDontCareSet::unreachable_states_from_equations(
+            &state_variables,
+            &["E"],
+            &[0],
+            &next_state[1..]
+        ),
+        Err(qmc::Error::InvalidNextStateCount {
+            expected: 3,
+            found: 2
+        })
+    ));
+    assert!(matches!(
+        qmc::GlobalDontCareSet::unreachable_states_from_equations(
+            &state_variables,
+            &["X"],
+            &[0],
+            &next_state
+        ),
+        Err(qmc::Error::MismatchedVariables { .. })
+    ));
+    assert!(matches!(
+        qmc::GlobalDontCareSet::unreachable_states(&state_variables, &[8], &transitions),
+        Err(qmc::Error::TermOutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn one_hot_groups() {
+    let variables = ["S0", "S1", "S2", "D"];
+    // D when S0 is 1, ~D when S1 is 1, 1 when S2 is 1, and 0 when none of them is 1.
+    let function =
+        qmc::BooleanFunction::new(&variables, &[9, 4, 6, 2, 3], &[8, 5, 7, 0, 1]).unwrap();
+
+    let one_hot = function.one_hot_group(&["S0", "S1", "S2"]).unwrap();
+
+    assert_eq!(
+        one_hot.dont_cares(),
+        HashSet::from_iter([0, 1, 6, 7, 10, 11, 12, 13, 14, 15])
+    );
+
+    let at_most_one = function.at_most_one_group(&["S2", "S1", "S0"]).unwrap();
+
+    assert_eq!(
+        at_most_one.dont_cares(),
+        HashSet::from_iter([6, 7, 10, 11, 12, 13, 14, 15])
+    );
+
+    let solutions = at_most_one.minimize(qmc::SOP, false, None).unwrap();
+    let usage = at_most_one.dont_care_usage(&solutions[0]);
+
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage[0].label, "at most one of S2 S1 S0");
+
+    assert_eq!(solutions[0].to_string(), "S2 ∨ (S0 ∧ D) ∨ (S1 ∧ ~D)");
+
+    assert!(matches!(
+        function.one_hot_group(&["S0", "X"]),
+        Err(qmc::Error::UnknownVariable(_))
+    ));
+    assert!(matches!(
+        function.one_hot_group(&["S0", "S0"]),
+        Err(qmc::Error::DuplicateVariables(_))
+    ));
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "serde")]
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use quine_mccluskey as qmc;
+
+fn job_directory(name: &str) -> PathBuf {
+    let directory = env::temp_dir().join(format!("qmc-job-{}-{}", name, std::process::id()));
+
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+#[test]
+fn run_job_pla() {
+    let directory = job_directory("pla");
+
+    fs::write(
+        directory.join("in.pla"),
+        ".i 2\n.o 2\n.ilb a b\n.ob x y\n01 11\n10 11\n11 01\n.e\n",
+    )
+    .unwrap();
+    fs::write(
+        directory.join("job.json"),
+        r#"{
+            "input": { "pla": "in.pla" },
+            "options": { "form": "SOP" },
+            "outputs": [
+                { "format": "text", "path": "out/f.txt" },
+                { "format": "pla", "path": "out/f.pla" },
+                { "format": "verilog", "path": "out/f.v" },
+                { "format": "blif", "path": "out/f.blif" }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    // The text output doesn't follow the default format of the thread.
+    let _format = qmc::set_default_format(qmc::ExpressionStyle::ascii());
+    let written = qmc::run_job(directory.join("job.json")).unwrap();
+    let read = |path: &str| fs::read_to_string(directory.join(path)).unwrap();
+
+    assert_eq!(
+        written,
+        [
+            directory.join("out/f.txt"),
+            directory.join("out/f.pla"),
+            directory.join("out/f.v"),
+            directory.join("out/f.blif"),
+        ]
+    );
+    assert_eq!(read("out/f.txt"), "x = (a ∧ ~b) ∨ (~a ∧ b)\ny = a ∨ b\n");
+    assert_eq!(
+        read("out/f.pla").parse::<qmc::Pla>().unwrap(),
+        read("in.pla").parse::<qmc::Pla>().unwrap()
+    );
+    assert_eq!(read("out/f.v").matches("endmodule").count(), 2);
+    assert_eq!(
+        read("out/f.blif"),
+        ".model job\n.inputs a b\n.outputs x y\n.names a b x\n10 1\n01 1\n.names a b y\n1- 1\n-1 1\n.end\n"
+    );
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn run_job_problem() {
+    let directory = job_directory("problem");
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[1, 2, 3], &[0]).unwrap();
+    let options = qmc::SettingsProfile {
+        form: qmc::POS,
+        ..Default::default()
+    };
+
+    qmc::Problem::new(&function, options)
+        .save(directory.join("problem.json"))
+        .unwrap();
+    fs::write(
+        directory.join("job.json"),
+        r#"{
+            "input": { "problem": "problem.json" },
+            "outputs": [{ "format": "dimacs", "path": "f.cnf" }]
+        }"#,
+    )
+    .unwrap();
+
+    qmc::run_job(directory.join("job.json")).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(directory.join("f.cnf")).unwrap(),
+        "c 1 A\nc 2 B\np cnf 2 1\n1 2 0\n"
+    );
+
+    fs::write(
+        directory.join("job.json"),
+        r#"{
+            "input": { "problem": "problem.json" },
+            "outputs": [{ "format": "blif", "path": "f.blif" }]
+        }"#,
+    )
+    .unwrap();
+    qmc::run_job(directory.join("job.json")).unwrap();
+
+    let blif: qmc::Blif = fs::read_to_string(directory.join("f.blif"))
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    assert_eq!(blif.model(), Some("job"));
+    assert_eq!(blif.output(), "F0");
+    assert_eq!(blif.function(), &function);
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn run_job_invalid() {
+    let directory = job_directory("invalid");
+    let run = |job: &str| {
+        fs::write(directory.join("job.json"), job).unwrap();
+        qmc::run_job(directory.join("job.json"))
+    };
+    let input = r#""input": { "variables": ["A"], "minterms": [1], "maxterms": [0] }"#;
+
+    assert!(matches!(
+        run(&format!(
+            r#"{{ {}, "outputs": [{{ "format": "png", "path": "f" }}] }}"#,
+            input
+        )),
+        Err(qmc::Error::InvalidJson(_))
+    ));
+    assert!(matches!(
+        run(&format!(
+            r#"{{ {}, "outputs": [{{ "format": "dimacs", "path": "f" }}] }}"#,
+            input
+        )),
+        Err(qmc::Error::InvalidJson(_))
+    ));
+    assert!(matches!(
+        run(r#"{ "input": { "pla": "missing.pla" } }"#),
+        Err(qmc::Error::Io(_))
+    ));
+    assert_eq!(
+        run(&format!("{{ {} }}", input)).unwrap(),
+        Vec::<PathBuf>::new()
+    );
+
+    fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,127 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn karnaugh_svg() {
+    // ~B ∧ ~D covers the four corners of the map.
+    let function = qmc::BooleanFunction::new(
+        &["A", "B", "C", "D"],
+        &[0, 2, 8, 10],
+        &[1, 3, 4, 5, 6, 7, 9, 11, 12, 13, 14],
+    )
+    .unwrap();
+    let solution = function
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(solution.to_string(), "~B ∧ ~D");
+
+    let svg = function.to_karnaugh_svg(Some(&solution));
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    assert!(svg.contains(">A B \\ C D</text>"));
+    assert_eq!(svg.matches(">1</text>").count(), 4);
+    assert_eq!(svg.matches(">X</text>").count(), 1);
+    assert_eq!(svg.matches("rx=\"8\"").count(), 4);
+
+    let svg = function.to_karnaugh_svg(None);
+
+    assert_eq!(svg.matches("rx=\"8\"").count(), 0);
+    assert_eq!(svg.matches("<rect").count(), 16);
+}
+
+#[test]
+#[should_panic(expected = "expected at most 6 variables")]
+fn karnaugh_svg_too_many_variables() {
+    let function = qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..7], &[0], &[1]).unwrap();
+
+    function.to_karnaugh_svg(None);
+}
+
+#[test]
+fn karnaugh_string() {
+    let function = qmc::BooleanFunction::new(&["A", "B"], &[1, 2, 3], &[0]).unwrap();
+    let solution = function
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        function.to_karnaugh_string(Some(&solution)),
+        "\
+A \\ B | 0  1
+------+------
+    0 | 0a 1
+    1 | 1  1
+
+a: A ∨ B
+"
+    );
+
+    let function = qmc::BooleanFunction::new(&["A"], &[], &[0, 1]).unwrap();
+    let solution = function
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        function.to_karnaugh_string(Some(&solution)),
+        " \\ A | 0 1\n-----+----\n     | 0 0\n"
+    );
+}
+
+#[test]
+fn karnaugh_string_six_variables() {
+    let variables = &qmc::DEFAULT_VARIABLES[..6];
+    let function = qmc::BooleanFunction::new(variables, &[0, 63], &[]).unwrap();
+    let text = function.to_karnaugh_string(None);
+    let lines = Vec::from_iter(text.lines());
+
+    assert_eq!(lines.len(), 10);
+    assert_eq!(lines[0], "A B C \\ D E F | 000 001 011 010 110 111 101 100");
+    assert_eq!(lines[2], "          000 | 1   X   X   X   X   X   X   X");
+    assert_eq!(lines[7], "          111 | X   X   X   X   X   1   X   X");
+}
+
+#[test]
+fn karnaugh_groups() {
+    // ~D ∧ ~F spans the non-adjacent columns 000 and 010 of every row.
+    let (minterms, maxterms): (Vec<u32>, Vec<u32>) = (0..64).partition(|term| term & 0b101 == 0);
+    let solution = qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..6], &minterms, &maxterms)
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_string(), "~D ∧ ~F");
+
+    let groups = solution.karnaugh_groups();
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].cells.len(), 16);
+    assert!(groups[0]
+        .cells
+        .iter()
+        .all(|cell| minterms.contains(&cell.term) && (cell.col == 0 || cell.col == 3)));
+    assert_eq!(
+        groups[0].rectangles,
+        [
+            qmc::KarnaughRectangle {
+                first_row: 0,
+                last_row: 7,
+                first_col: 0,
+                last_col: 0,
+            },
+            qmc::KarnaughRectangle {
+                first_row: 0,
+                last_row: 7,
+                first_col: 3,
+                last_col: 3,
+            },
+        ]
+    );
+}
@@ -0,0 +1,257 @@
+use quine_mccluskey as qmc;
+
+fn minterms(variables: &[&str], expression: &str) -> Vec<u32> {
+    qmc::parse_expression(variables, expression).unwrap().0
+}
+
+#[test]
+fn parse_expression() {
+    let variables = ["A", "B", "C"];
+
+    assert_eq!(minterms(&variables, "A"), [4, 5, 6, 7]);
+    assert_eq!(minterms(&variables, "~C"), [0, 2, 4, 6]);
+    assert_eq!(minterms(&variables, "A & B | C"), [1, 3, 5, 6, 7]);
+    assert_eq!(minterms(&variables, "A & (B | C)"), [5, 6, 7]);
+    assert_eq!(minterms(&variables, "A ^ B & C"), [3, 4, 5, 6]);
+    assert_eq!(minterms(&variables, "!!A * B + 0"), [6, 7]);
+    assert_eq!(minterms(&variables, "¬A ⊕ 1"), [4, 5, 6, 7]);
+    assert!(minterms(&variables, "0").is_empty());
+    assert_eq!(minterms(&variables, "1").len(), 8);
+
+    let (minterms, maxterms) = qmc::parse_expression(&["in_1", "in_2"], "in_1∧~in_2").unwrap();
+
+    assert_eq!(minterms, [2]);
+    assert_eq!(maxterms, [0, 1, 3]);
+}
+
+#[test]
+fn parse_solution() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let minterms = [1, 2, 6, 7, 9, 12, 13];
+    let maxterms = [0, 3, 4, 5, 8, 10, 11, 14, 15];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::minimize(variables, &minterms, &maxterms, form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        assert_eq!(
+            qmc::parse_expression(variables, &solution.to_string()).unwrap(),
+            (minterms.to_vec(), maxterms.to_vec())
+        );
+    }
+}
+
+#[test]
+fn simplify_expression() {
+    let variables = ["A", "B", "C", "D"];
+
+    for (expression, sop, pos) in [
+        ("A & B | A & ~B & C", "(A ∧ B) ∨ (A ∧ C)", "A ∧ (B ∨ C)"),
+        ("(A | B) & (A | ~B) & D", "A ∧ D", "A ∧ D"),
+        ("A ^ A | B & 0", "0", "0"),
+    ] {
+        for (form, expected) in [(qmc::SOP, sop), (qmc::POS, pos)] {
+            let mut solutions =
+                qmc::simplify_expression(&variables, expression, form, false, None).unwrap();
+
+            assert_eq!(solutions.pop().unwrap().to_string(), expected);
+        }
+    }
+}
+
+#[test]
+fn invalid_expression() {
+    let variables = ["A", "B"];
+
+    for expression in ["", "A &", "(A | B", "A B", "A | )", "A # B"] {
+        assert!(
+            matches!(
+                qmc::parse_expression(&variables, expression),
+                Err(qmc::Error::InvalidExpression(_))
+            ),
+            "{}",
+            expression
+        );
+    }
+
+    assert!(matches!(
+        qmc::parse_expression(&variables, "A & C"),
+        Err(qmc::Error::UnknownVariable(variable)) if variable == "C"
+    ));
+}
+
+#[test]
+fn expr_from_str() {
+    use qmc::Expr::{And, Const, Not, Or, Var, Xor};
+
+    let var = |name: &str| Var(name.to_owned());
+
+    assert_eq!("x1".parse::<qmc::Expr>().unwrap(), var("x1"));
+    assert_eq!("1".parse::<qmc::Expr>().unwrap(), Const(true));
+    assert_eq!(
+        "A & B | ~C".parse::<qmc::Expr>().unwrap(),
+        Or(vec![And(vec![var("A"), var("B")]), Not(Box::new(var("C")))])
+    );
+    assert_eq!(
+        "A & (B & C)".parse::<qmc::Expr>().unwrap(),
+        And(vec![var("A"), And(vec![var("B"), var("C")])])
+    );
+    assert!(matches!(
+        "A & (B".parse::<qmc::Expr>(),
+        Err(qmc::Error::InvalidExpression(_))
+    ));
+
+    let xor: qmc::Expr = "A ^ B ^ C".parse().unwrap();
+
+    assert_eq!(xor, Xor(vec![var("A"), var("B"), var("C")]));
+
+    for term in 0..8u32 {
+        let value = |name: &str| term >> (2 - (name.as_bytes()[0] - b'A')) & 1 == 1;
+
+        assert_eq!(xor.evaluate(&value), term.count_ones() % 2 == 1);
+    }
+
+    // Nested XOR is kept as it is instead of growing when written with AND, OR and NOT.
+    let nested_xor = format!("{}A{}", "(".repeat(40), ")^B".repeat(40));
+
+    assert_eq!(
+        nested_xor.parse::<qmc::Expr>().unwrap().to_string().len(),
+        "A".len() + 40 * " ⊕ B".len() + 39 * "()".len()
+    );
+
+    assert_eq!(
+        r#""A B" & "1" | "x\"y\\""#.parse::<qmc::Expr>().unwrap(),
+        Or(vec![And(vec![var("A B"), var("1")]), var("x\"y\\")])
+    );
+    assert!(matches!(
+        r#""A"#.parse::<qmc::Expr>(),
+        Err(qmc::Error::InvalidExpression(_))
+    ));
+
+    let depth = qmc::MAX_NESTING_DEPTH;
+
+    for (open, close) in [("(", ")"), ("~", "")] {
+        let nested = |depth| format!("{}A{}", open.repeat(depth), close.repeat(depth));
+
+        assert!(nested(depth).parse::<qmc::Expr>().is_ok());
+        assert!(matches!(
+            nested(depth + 1).parse::<qmc::Expr>(),
+            Err(qmc::Error::InvalidExpression(_))
+        ));
+    }
+
+    assert!(matches!(
+        qmc::parse_expression(&["A"], &"~".repeat(1_000_000)),
+        Err(qmc::Error::InvalidExpression(_))
+    ));
+}
+
+#[test]
+fn expr_display() {
+    use qmc::Expr::{And, Const, Not, Or, Var, Xor};
+
+    let var = |name: &str| Var(name.to_owned());
+
+    for (expr, expected) in [
+        (
+            Or(vec![And(vec![var("A"), var("B")]), Not(Box::new(var("C")))]),
+            "A ∧ B ∨ ~C",
+        ),
+        (
+            And(vec![Or(vec![var("A"), var("B")]), var("C")]),
+            "(A ∨ B) ∧ C",
+        ),
+        (
+            Or(vec![var("A"), Or(vec![var("B"), var("C")])]),
+            "A ∨ (B ∨ C)",
+        ),
+        (
+            Not(Box::new(Not(Box::new(And(vec![var("A"), var("B")]))))),
+            "~~(A ∧ B)",
+        ),
+        (
+            Or(vec![
+                Xor(vec![And(vec![var("A"), var("B")]), var("C")]),
+                var("D"),
+            ]),
+            "A ∧ B ⊕ C ∨ D",
+        ),
+        (
+            And(vec![Xor(vec![var("A"), var("B")]), Not(Box::new(var("C")))]),
+            "(A ⊕ B) ∧ ~C",
+        ),
+        (
+            Or(vec![var("A B"), var("0"), var(""), var("x\"y\\")]),
+            r#""A B" ∨ "0" ∨ "" ∨ "x\"y\\""#,
+        ),
+        (Const(false), "0"),
+    ] {
+        assert_eq!(expr.to_string(), expected);
+        assert_eq!(expected.parse::<qmc::Expr>().unwrap(), expr);
+    }
+
+    assert_eq!(And(vec![]).to_string(), "1");
+    assert_eq!(Or(vec![]).to_string(), "0");
+    assert_eq!(
+        Not(Box::new(Or(vec![And(vec![var("A"), var("B")])]))).to_string(),
+        "~(A ∧ B)"
+    );
+
+    let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_ast().to_string(), "A ∧ B ∨ ~A ∧ C");
+    assert_eq!(
+        solution.to_ast().to_string().parse::<qmc::Expr>().unwrap(),
+        solution.to_ast()
+    );
+}
+
+#[test]
+fn expr_display_round_trip() {
+    use qmc::Expr::{And, Const, Not, Or, Var, Xor};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    const NAMES: &[&str] = &[
+        "A", "x1", "_", "é", "A B", "1", "0", "", "x|y", "\"", "a\\b", "~", "(", "⊕",
+    ];
+
+    fn random_expr(rng: &mut StdRng, depth: u32) -> qmc::Expr {
+        let choice = if depth == 0 {
+            rng.random_range(0..2)
+        } else {
+            rng.random_range(0..6)
+        };
+        let mut exprs = || {
+            let count = rng.random_range(2..4);
+            Vec::from_iter((0..count).map(|_| random_expr(rng, depth - 1)))
+        };
+
+        match choice {
+            0 => Var(NAMES[rng.random_range(0..NAMES.len())].to_owned()),
+            1 => Const(rng.random_bool(0.5)),
+            2 => And(exprs()),
+            3 => Or(exprs()),
+            4 => Xor(exprs()),
+            _ => Not(Box::new(random_expr(rng, depth - 1))),
+        }
+    }
+
+    let mut rng = StdRng::seed_from_u64(537);
+
+    for _ in 0..1000 {
+        let expr = random_expr(&mut rng, 4);
+
+        assert_eq!(
+            expr.to_string().parse::<qmc::Expr>().unwrap(),
+            expr,
+            "{}",
+            expr
+        );
+    }
+}
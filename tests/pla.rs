@@ -0,0 +1,167 @@
+use quine_mccluskey as qmc;
+
+fn minimize(function: &qmc::BooleanFunction) -> String {
+    function
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn parse_pla() {
+    let pla: qmc::Pla = "
+# A 2-bit comparator with an unused input code.
+.i 4
+.o 2
+.ilb a1 a0 b1 b0
+.ob eq gt
+.type fd
+.p 5
+00 00  10
+01 01  10
+1- 0-  01
+11 10  01
+10 10  1~
+.e
+"
+    .parse()
+    .unwrap();
+
+    assert_eq!(pla.inputs(), ["a1", "a0", "b1", "b0"]);
+    assert_eq!(pla.outputs(), ["eq", "gt"]);
+
+    let [eq, gt] = [&pla.functions()[0], &pla.functions()[1]];
+
+    assert_eq!(eq.evaluate(0b0000), Some(true));
+    assert_eq!(eq.evaluate(0b1010), Some(true));
+    assert_eq!(eq.evaluate(0b1111), Some(false));
+    assert_eq!(gt.evaluate(0b1000), Some(true));
+    assert_eq!(gt.evaluate(0b1010), Some(false));
+    assert!(eq.dont_cares().is_empty() && gt.dont_cares().is_empty());
+}
+
+#[test]
+fn pla_types() {
+    let cubes = "1- 1\n01 0\n00 -\n";
+
+    for (pla_type, minterms, maxterms) in [
+        ("f", vec![2, 3], vec![0, 1]),
+        ("fd", vec![2, 3], vec![1]),
+        ("fr", vec![2, 3], vec![1]),
+        ("fdr", vec![2, 3], vec![1]),
+    ] {
+        let pla: qmc::Pla = format!(".i 2\n.o 1\n.type {}\n{}", pla_type, cubes)
+            .parse()
+            .unwrap();
+        let function = &pla.functions()[0];
+
+        assert_eq!(pla.inputs(), ["A", "B"]);
+        assert_eq!(pla.outputs(), ["F0"]);
+        assert_eq!(
+            function.minterms(),
+            &minterms.into_iter().collect(),
+            "{}",
+            pla_type
+        );
+        assert_eq!(
+            function.maxterms(),
+            &maxterms.into_iter().collect(),
+            "{}",
+            pla_type
+        );
+        assert_eq!(minimize(function), "A");
+    }
+
+    // Don't cares take precedence over the on-set.
+    let pla: qmc::Pla = ".i 2\n.o 1\n1- 1\n11 -\n".parse().unwrap();
+
+    assert_eq!(pla.functions()[0].evaluate(3), None);
+}
+
+#[test]
+fn invalid_pla() {
+    for pla in [
+        ".o 1\n1 1\n",
+        ".i 2\n.o 1\n1 1\n",
+        ".i 2\n.o 1\n.p 2\n11 1\n",
+        ".i 2\n.o 1\n12 1\n",
+        ".i 2\n.o 1\n11 x\n",
+        ".i 2\n.o 1\n.ilb a\n",
+        ".i 2\n.o 1\n.mv 3 1\n",
+        ".i 2\n.o 1\n.type fx\n",
+    ] {
+        assert!(
+            matches!(pla.parse::<qmc::Pla>(), Err(qmc::Error::InvalidPla(_))),
+            "{}",
+            pla
+        );
+    }
+
+    assert!(matches!(
+        ".i 2\n.o 1\n.type fr\n1- 1\n-1 0\n".parse::<qmc::Pla>(),
+        Err(qmc::Error::TermConflict(_))
+    ));
+}
+
+#[test]
+fn write_pla() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let functions = [
+        qmc::BooleanFunction::new(variables, &[1, 3, 5, 7, 9, 13], &[0, 2, 4, 6, 8, 10]).unwrap(),
+        qmc::BooleanFunction::new(variables, &[0, 1, 2, 3, 15], &[4, 5, 6, 8, 12]).unwrap(),
+        qmc::BooleanFunction::new(variables, &[], &[0, 1, 2]).unwrap(),
+        qmc::BooleanFunction::new(variables, &[0, 1, 2], &[]).unwrap(),
+    ];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solutions = Vec::from_iter(
+            functions
+                .iter()
+                .map(|function| function.minimize(form, false, None).unwrap().remove(0)),
+        );
+        let pla: qmc::Pla = qmc::solutions_to_pla(&solutions, &["w", "x", "y", "z"])
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        assert_eq!(pla.outputs(), ["w", "x", "y", "z"]);
+
+        for (function, parsed) in functions.iter().zip(pla.functions()) {
+            assert!(parsed.dont_cares().is_empty());
+
+            for term in 0..16 {
+                if let Some(output) = function.evaluate(term) {
+                    assert_eq!(parsed.evaluate(term), Some(output), "{:?} {}", form, term);
+                }
+            }
+        }
+
+        let single: qmc::Pla = solutions[0].to_pla().parse().unwrap();
+
+        assert_eq!(single.outputs(), ["F0"]);
+        assert_eq!(
+            single.functions()[0].minterms(),
+            pla.functions()[0].minterms()
+        );
+    }
+
+    let sop = functions[0]
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+    let pos = functions[0]
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(matches!(
+        qmc::solutions_to_pla(&[sop.clone(), pos], &["x", "y"]),
+        Err(qmc::Error::InvalidPla(_))
+    ));
+    assert!(matches!(
+        qmc::solutions_to_pla(&[sop], &["x", "y"]),
+        Err(qmc::Error::InvalidPla(_))
+    ));
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "serde")]
+
+use std::env;
+use std::time::Duration;
+
+use quine_mccluskey as qmc;
+
+#[test]
+fn problem_round_trip() {
+    let function = qmc::BooleanFunction::new(&["A", "B \"C\""], &[1, 2], &[0]).unwrap();
+    let options = qmc::SettingsProfile {
+        name: "power".to_owned(),
+        form: qmc::POS,
+        find_all_solutions: true,
+        cost_model: qmc::CostModel::Power {
+            signal_probabilities: vec![0.25, 0.5],
+        },
+        timeout: Some(Duration::from_millis(1500)),
+        max_work: Some(1 << 40),
+    };
+    let problem = qmc::Problem::new(&function, options);
+
+    assert_eq!(problem.function().unwrap(), function);
+    assert_eq!(
+        problem.to_json().unwrap().parse::<qmc::Problem>().unwrap(),
+        problem
+    );
+
+    let path = env::temp_dir().join(format!("qmc-problem-{}.json", std::process::id()));
+
+    problem.save(&path).unwrap();
+    let loaded = qmc::Problem::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.unwrap(), problem);
+
+    let error = qmc::Problem::load(&path).unwrap_err();
+
+    assert!(matches!(error, qmc::Error::Io(_)));
+    assert_eq!(
+        std::error::Error::source(&error)
+            .and_then(|source| source.downcast_ref::<std::io::Error>())
+            .map(std::io::Error::kind),
+        Some(std::io::ErrorKind::NotFound)
+    );
+}
+
+#[test]
+fn problem_invalid_json() {
+    assert!(matches!(
+        "{\"variables\": [\"A\"], \"minterms\": [0],".parse::<qmc::Problem>(),
+        Err(qmc::Error::InvalidJson(_))
+    ));
+    assert!(matches!(
+        "{\"variables\": [\"A\"], \"minterms\": [-1], \"maxterms\": []}".parse::<qmc::Problem>(),
+        Err(qmc::Error::InvalidJson(_))
+    ));
+    assert!(matches!(
+        "{\"variables\": [\"A\"], \"minterms\": [0], \"maxterms\": [], \"options\": {\"form\": \"CNF\"}}"
+            .parse::<qmc::Problem>(),
+        Err(qmc::Error::InvalidJson(_))
+    ));
+}
+
+#[test]
+fn problem_non_finite_probabilities() {
+    let function = qmc::BooleanFunction::new(&["A"], &[1], &[0]).unwrap();
+
+    for probability in [f64::NAN, f64::INFINITY] {
+        let problem = qmc::Problem::new(
+            &function,
+            qmc::SettingsProfile {
+                cost_model: qmc::CostModel::Power {
+                    signal_probabilities: vec![probability],
+                },
+                ..Default::default()
+            },
+        );
+
+        assert!(matches!(
+            problem.to_json(),
+            Err(qmc::Error::InvalidProbabilities(_))
+        ));
+    }
+}
@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use quine_mccluskey as qmc;
+
+#[test]
+fn settings_profile() {
+    let function =
+        qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..3], &[0, 5], &[1, 3, 4, 6]).unwrap();
+
+    let profile = qmc::SettingsProfile {
+        form: qmc::POS,
+        find_all_solutions: true,
+        timeout: Some(Duration::from_secs(10)),
+        ..Default::default()
+    };
+    let solutions = profile.minimize(&function).unwrap();
+
+    assert_eq!(
+        Vec::from_iter(solutions.iter().map(ToString::to_string)),
+        Vec::from_iter(
+            function
+                .minimize(qmc::POS, true, None)
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+        )
+    );
+
+    let profile = qmc::SettingsProfile {
+        max_work: Some(4),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        profile.minimize(&function),
+        Err(qmc::Error::ExceedsStaticBudget {
+            estimated_work: 5,
+            max_work: 4
+        })
+    ));
+
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+    let minterms = [0, 1, 2, 5, 6, 7];
+    let maxterms = [3, 4];
+    let function = qmc::BooleanFunction::new(variables, &minterms, &maxterms).unwrap();
+    let profile = qmc::SettingsProfile {
+        name: "low power".to_owned(),
+        cost_model: qmc::CostModel::Power {
+            signal_probabilities: vec![0.9, 0.8, 0.5],
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        profile.minimize(&function).unwrap()[0].to_string(),
+        qmc::minimize_power_aware(
+            variables,
+            &minterms,
+            &maxterms,
+            qmc::SOP,
+            &[0.9, 0.8, 0.5],
+            None
+        )
+        .unwrap()[0]
+            .to_string()
+    );
+
+    let profile = qmc::SettingsProfile {
+        cost_model: qmc::CostModel::Power {
+            signal_probabilities: vec![0.5],
+        },
+        ..profile
+    };
+
+    assert!(matches!(
+        profile.minimize(&function),
+        Err(qmc::Error::InvalidProbabilities(_))
+    ));
+}
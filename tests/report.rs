@@ -0,0 +1,98 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn html_report() {
+    let function = qmc::BooleanFunction::new(&["A", "B", "C<"], &[0, 5], &[1, 3, 4, 6]).unwrap();
+
+    for form in [qmc::SOP, qmc::POS] {
+        let report = qmc::MinimizeReport::new(&function, form, true, None).unwrap();
+        let html = report.to_html();
+
+        assert_eq!(report.form(), form);
+        assert!(html.starts_with("<!DOCTYPE html>") && html.ends_with("</html>\n"));
+        assert!(html.contains("<th>C&lt;</th>"));
+        assert!(!html.contains("<th>C<</th>"));
+
+        // One row for each term in the truth table and one cell for each term in the Karnaugh map.
+        for term in 0..8 {
+            assert_eq!(
+                html.matches(&format!("<tr data-term=\"{}\">", term))
+                    .count(),
+                1
+            );
+            assert!(html.contains(&format!("<td data-term=\"{}\">", term)));
+        }
+
+        for cube in report.prime_implicants() {
+            let terms = Vec::from_iter(cube.terms().iter().map(u32::to_string)).join(" ");
+
+            assert!(html.contains(&format!("<th data-terms=\"{}\">", terms)));
+        }
+
+        for solution in report.solutions() {
+            for cube in solution.cubes() {
+                let terms = Vec::from_iter(cube.terms().iter().map(u32::to_string)).join(" ");
+
+                assert!(html.contains(&format!("<span data-terms=\"{}\">", terms)));
+            }
+        }
+    }
+
+    let html = qmc::MinimizeReport::new(&function, qmc::SOP, false, None)
+        .unwrap()
+        .to_html();
+
+    assert!(html.contains(&format!("Algorithm revision {}", qmc::ALGORITHM_REVISION)));
+    assert!(html.contains("<span data-terms=\"5 7\">(A ∧ C&lt;)</span> ∨ <span data-terms=\"0 2\">(~A ∧ ~C&lt;)</span>"));
+}
+
+#[test]
+fn html_report_constant() {
+    let function = qmc::BooleanFunction::new(&["A"], &[0, 1], &[]).unwrap();
+    let html = qmc::MinimizeReport::new(&function, qmc::SOP, false, None)
+        .unwrap()
+        .to_html();
+
+    assert!(html.contains("<li>1 <small>(minimum)</small></li>"));
+}
+
+#[test]
+fn chart_terms() {
+    // Mostly don't cares, so the chart only has a column for each of the few care terms.
+    let function =
+        qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..8], &[200, 3], &[17]).unwrap();
+
+    let report = qmc::MinimizeReport::new(&function, qmc::SOP, false, None).unwrap();
+    assert_eq!(report.chart_terms(), [3, 200]);
+
+    let report = qmc::MinimizeReport::new(&function, qmc::POS, false, None).unwrap();
+    assert_eq!(report.chart_terms(), [17]);
+}
+
+#[test]
+fn html_report_without_truth_table() {
+    let variable_count = qmc::MinimizeReport::MAX_TRUTH_TABLE_VARIABLES + 1;
+    let variables = &qmc::DEFAULT_VARIABLES[..variable_count];
+    let maxterms = Vec::from_iter(4..1 << variable_count);
+    let function = qmc::BooleanFunction::new(variables, &[0, 1, 3], &maxterms).unwrap();
+    let report = qmc::MinimizeReport::new(&function, qmc::SOP, false, None).unwrap();
+    let html = report.to_html();
+
+    assert!(!html.contains("<h2>Truth table</h2>"));
+    assert!(html.contains("<h2>Prime implicant chart</h2>"));
+    assert!(html.len() < 1 << 12);
+
+    // Only the terms of the chart are highlighted.
+    for cube in report.prime_implicants() {
+        let terms = Vec::from_iter(
+            report
+                .chart_terms()
+                .into_iter()
+                .filter(|&term| cube.contains(term))
+                .map(|term| term.to_string()),
+        )
+        .join(" ");
+
+        assert!(html.contains(&format!("<th data-terms=\"{}\">", terms)));
+    }
+}
@@ -0,0 +1,128 @@
+use quine_mccluskey as qmc;
+
+fn check<const N: usize>(minterms: &[u32], maxterms: &[u32]) {
+    let mut on_set = [0; 4];
+    let mut off_set = [0; 4];
+
+    for &term in minterms {
+        on_set[term as usize / 64] |= 1 << (term % 64);
+    }
+
+    for &term in maxterms {
+        off_set[term as usize / 64] |= 1 << (term % 64);
+    }
+
+    for form in [qmc::SOP, qmc::POS] {
+        let mut cubes = [qmc::Cube::default(); 256];
+        let count = qmc::minimize_const::<N>(on_set, off_set, form, &mut cubes).unwrap();
+        let cubes = &cubes[..count];
+
+        let (terms, excluded) = if form == qmc::SOP {
+            (minterms, maxterms)
+        } else {
+            (maxterms, minterms)
+        };
+
+        for &term in terms {
+            assert!(cubes.iter().any(|cube| cube.contains(term)));
+        }
+
+        for &term in excluded {
+            assert!(cubes.iter().all(|cube| !cube.contains(term)));
+        }
+
+        let solution = qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..N], minterms, maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .remove(0);
+        let literal_count: usize = cubes.iter().map(qmc::Cube::literal_count).sum();
+
+        if solution.is_constant() {
+            assert_eq!(literal_count, 0);
+            assert!(count <= 1);
+        } else {
+            assert_eq!(
+                (count, literal_count),
+                (solution.term_count(), solution.literal_count())
+            );
+        }
+    }
+}
+
+/// Splits the terms of `N` variables into minterms, maxterms and don't cares with a fixed pseudo-random sequence.
+fn random_terms<const N: usize>(seed: u64) -> (Vec<u32>, Vec<u32>) {
+    let mut state = seed;
+    let mut minterms = vec![];
+    let mut maxterms = vec![];
+
+    for term in 0..1 << N {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        match state % 5 {
+            0 | 1 => minterms.push(term),
+            2 | 3 => maxterms.push(term),
+            _ => {}
+        }
+    }
+
+    (minterms, maxterms)
+}
+
+#[test]
+fn minimize_const_exhaustive() {
+    for outputs in 0..3u32.pow(4) {
+        let mut minterms = vec![];
+        let mut maxterms = vec![];
+        let mut digits = outputs;
+
+        for term in 0..4 {
+            match digits % 3 {
+                0 => maxterms.push(term),
+                1 => minterms.push(term),
+                _ => {}
+            }
+
+            digits /= 3;
+        }
+
+        check::<2>(&minterms, &maxterms);
+    }
+}
+
+#[test]
+fn minimize_const_random() {
+    for seed in 1..=20 {
+        let (minterms, maxterms) = random_terms::<5>(seed);
+        check::<5>(&minterms, &maxterms);
+    }
+
+    for seed in [1, 5] {
+        let (minterms, maxterms) = random_terms::<8>(seed);
+        check::<8>(&minterms, &maxterms);
+    }
+}
+
+#[test]
+fn minimize_const_errors() {
+    let mut cubes = [qmc::Cube::default(); 1];
+
+    assert!(matches!(
+        qmc::minimize_const::<9>([0; 4], [0; 4], qmc::SOP, &mut cubes),
+        Err(qmc::Error::InvalidVariableCount(9))
+    ));
+    assert!(matches!(
+        qmc::minimize_const::<2>([0b1_0000, 0, 0, 0], [0; 4], qmc::SOP, &mut cubes),
+        Err(qmc::Error::TermOutOfBounds { .. })
+    ));
+    assert!(matches!(
+        qmc::minimize_const::<2>([0b11, 0, 0, 0], [0b10, 0, 0, 0], qmc::SOP, &mut cubes),
+        Err(qmc::Error::TermConflict(_))
+    ));
+    assert!(matches!(
+        qmc::minimize_const::<2>([0b1001, 0, 0, 0], [0b0110, 0, 0, 0], qmc::SOP, &mut cubes),
+        Err(qmc::Error::BufferTooSmall(2))
+    ));
+}
@@ -1,4 +1,5 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 use quine_mccluskey as qmc;
 
@@ -8,13 +9,13 @@ fn solution() {
     test(1, &[0], &[1], "~A", "~A", "~A", "~A");
     test(1, &[1], &[0], "A", "A", "A", "A");
     test(1, &[0, 1], &[], "1", "1", "1", "1");
-    test(1, &[], &[], "0", "0", "1", "1");
+    test(1, &[], &[], "0", "1", "1", "0");
     test(1, &[], &[0], "0", "0", "0", "0");
     test(1, &[], &[1], "0", "0", "0", "0");
     test(1, &[0], &[], "1", "1", "1", "1");
     test(1, &[1], &[], "1", "1", "1", "1");
 
-    test(2, &[], &[], "0", "0", "1", "1");
+    test(2, &[], &[], "0", "1", "1", "0");
     test(2, &[1], &[0, 3], "~A ∧ B", "~A ∧ B", "~A ∧ B", "~A ∧ B");
     test(
         2,
@@ -57,6 +58,1624 @@ fn solution() {
     );
 }
 
+#[test]
+fn solution_enum() {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+
+    let solution = qmc::minimize(
+        variables,
+        &[1, 3, 6, 7],
+        &[0, 2, 4, 5],
+        qmc::SOP,
+        false,
+        None,
+    )
+    .unwrap()
+    .remove(0);
+
+    match &solution {
+        qmc::Solution::SOP(products) => {
+            let products = Vec::from_iter(products.iter().map(|product| {
+                Vec::from_iter(
+                    product
+                        .iter()
+                        .map(|variable| (variable.name.as_str(), variable.is_negated)),
+                )
+            }));
+
+            assert_eq!(
+                products,
+                [
+                    vec![("A", false), ("B", false)],
+                    vec![("A", true), ("C", false)]
+                ]
+            );
+        }
+        _ => panic!("expected a SOP solution"),
+    }
+
+    let cover = qmc::BooleanFunction::new(variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        qmc::Solution::from(&cover).to_string(),
+        solution.to_string()
+    );
+
+    let one = qmc::minimize(variables, &[0], &[], qmc::POS, false, None).unwrap();
+    let sum = qmc::minimize_maxterms(variables, &[0], &[], false, None).unwrap();
+
+    assert!(matches!(one[..], [qmc::Solution::One]));
+    assert!(matches!(sum[..], [qmc::Solution::POS(_)]));
+    assert!(one[0].is_constant() && !sum[0].is_constant());
+}
+
+#[test]
+fn truth_table_string() {
+    let variables = ["In", "B"];
+    let function = qmc::BooleanFunction::new(&variables, &[1, 2], &[0, 3]).unwrap();
+    let solution = qmc::BooleanFunction::new(&variables, &[1, 2, 3], &[0])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        solution.to_truth_table_string(&function, 0),
+        "\
+In B | Function | Solution
+0  0 | 0        | 0
+0  1 | 1        | 1
+1  0 | 1        | 1
+1  1 | 0        | 1 ✗
+"
+    );
+    assert_eq!(
+        solution.to_truth_table_string(&function, 10),
+        "\
+In         B          | Function   | Solution
+0          0          | 0          | 0
+0          1          | 1          | 1
+1          0          | 1          | 1
+1          1          | 0          | 1 ✗
+"
+    );
+}
+
+#[test]
+fn markdown_table() {
+    let solution = qmc::BooleanFunction::new(&["In", "a|b"], &[1, 2, 3], &[0])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        solution.to_markdown_table(),
+        "\
+| In | a\\|b | Output |
+|:--:|:----:|:------:|
+| 0  | 0    | 0      |
+| 0  | 1    | 1      |
+| 1  | 0    | 1      |
+| 1  | 1    | 1      |
+"
+    );
+}
+
+#[test]
+fn complement() {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+    let minterms = [4, 6, 7, 1, 2, 3];
+    let maxterms = [5, 0];
+
+    let pos = qmc::BooleanFunction::new(variables, &minterms, &maxterms)
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+    let complement = pos.complement();
+
+    assert_eq!(complement.form(), qmc::SOP);
+    assert_eq!(complement.to_string(), "(~A ∧ ~B ∧ ~C) ∨ (A ∧ ~B ∧ C)");
+    assert_eq!(
+        pos.to_complement_string(),
+        "~((~A ∧ ~B ∧ ~C) ∨ (A ∧ ~B ∧ C))"
+    );
+    assert_eq!(complement.complement().to_string(), pos.to_string());
+
+    for term in 0..8 {
+        assert_eq!(
+            complement.cubes().iter().any(|cube| cube.contains(term)),
+            maxterms.contains(&term)
+        );
+    }
+
+    let one = qmc::BooleanFunction::new(variables, &[0], &[])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert!(one.complement().is_zero());
+    assert_eq!(one.to_complement_string(), "1");
+}
+
+#[test]
+fn specialize() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let minterms = [10, 13, 3, 7, 4];
+    let maxterms = [11, 2, 1, 12, 15, 0, 5, 9, 6];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::BooleanFunction::new(variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+        let specializations = solution.specialize(&["B", "D"]).unwrap();
+
+        assert_eq!(specializations.len(), 4);
+
+        for (assignment, residual) in specializations.iter().enumerate() {
+            let (b, d) = (assignment as u32 >> 1, assignment as u32 & 1);
+
+            assert_eq!(residual.variables(), ["A", "C"]);
+            assert_eq!(residual.form(), form);
+
+            for residual_term in 0..4 {
+                let (a, c) = (residual_term >> 1, residual_term & 1);
+                let term = a << 3 | b << 2 | c << 1 | d;
+                let is_covered = residual
+                    .cubes()
+                    .iter()
+                    .any(|cube| cube.contains(residual_term));
+                let output = is_covered == (form == qmc::SOP);
+
+                if minterms.contains(&term) {
+                    assert!(output, "{} {}", residual, term);
+                } else if maxterms.contains(&term) {
+                    assert!(!output, "{} {}", residual, term);
+                }
+            }
+        }
+    }
+
+    let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 2, 3], &[0])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(solution.cofactor(&[("A", true)]).unwrap().to_string(), "1");
+    assert_eq!(solution.cofactor(&[("A", false)]).unwrap().to_string(), "B");
+    assert!(matches!(
+        solution.cofactor(&[("C", true)]),
+        Err(qmc::Error::UnknownVariable(_))
+    ));
+    assert!(matches!(
+        solution.cofactor(&[("A", true), ("A", false)]),
+        Err(qmc::Error::DuplicateVariables(_))
+    ));
+    assert!(matches!(
+        solution.specialize(&["A"; 40]),
+        Err(qmc::Error::DuplicateVariables(_))
+    ));
+    assert!(matches!(
+        solution.specialize(&["C"]),
+        Err(qmc::Error::UnknownVariable(_))
+    ));
+}
+
+#[test]
+fn sensitization() {
+    let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(solution.to_string(), "(A ∧ B) ∨ (~A ∧ C)");
+    assert_eq!(solution.boolean_difference("A").unwrap(), [1, 2, 5, 6]);
+    assert_eq!(solution.boolean_difference("B").unwrap(), [4, 5, 6, 7]);
+    assert!(matches!(
+        solution.boolean_difference("D"),
+        Err(qmc::Error::UnknownVariable(_))
+    ));
+
+    let sensitization = solution.sensitization();
+
+    assert_eq!(
+        sensitization[0],
+        [
+            qmc::LiteralSensitization {
+                variable: "A".to_owned(),
+                polarity: qmc::Polarity::Positive,
+                terms: vec![6],
+            },
+            qmc::LiteralSensitization {
+                variable: "B".to_owned(),
+                polarity: qmc::Polarity::Positive,
+                terms: vec![6, 7],
+            },
+        ]
+    );
+    assert_eq!(sensitization[1][0].polarity, qmc::Polarity::Negative);
+    assert_eq!(sensitization[1][0].terms, [1]);
+    assert_eq!(sensitization[1][1].terms, [1, 3]);
+}
+
+#[test]
+fn power_aware() {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+    let minterms = [0, 1, 2, 5, 6, 7];
+    let maxterms = [3, 4];
+
+    let minimize_power_aware = |signal_probabilities: &[f64], timeout| {
+        qmc::minimize_power_aware(
+            variables,
+            &minterms,
+            &maxterms,
+            qmc::SOP,
+            signal_probabilities,
+            timeout,
+        )
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        minimize_power_aware(&[0.9, 0.8, 0.5], None),
+        ["(A ∧ B) ∨ (~A ∧ ~C) ∨ (~B ∧ C)"]
+    );
+    assert_eq!(
+        minimize_power_aware(&[0.1, 0.2, 0.5], Some(Duration::from_secs(10))),
+        ["(A ∧ C) ∨ (~A ∧ ~B) ∨ (B ∧ ~C)"]
+    );
+    assert_eq!(minimize_power_aware(&[0.5, 0.5, 0.5], None).len(), 2);
+}
+
+#[test]
+fn probability() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+    let minterms = [30, 22, 19, 4, 7, 14, 31, 17, 16, 24, 21, 2];
+    let maxterms = [1, 27, 6, 11, 8, 10, 0, 13, 9, 20, 23, 28, 26];
+    let one_probabilities = [0.1, 0.3, 0.5, 0.7, 0.9];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::BooleanFunction::new(variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+        let is_covered = |term| solution.cubes().iter().any(|cube| cube.contains(term));
+
+        let expected: f64 = (0..32)
+            .filter(|&term| is_covered(term) == (form == qmc::SOP))
+            .map(|term| {
+                (0..5)
+                    .map(|i| {
+                        if term >> (4 - i) & 1 == 1 {
+                            one_probabilities[i]
+                        } else {
+                            1.0 - one_probabilities[i]
+                        }
+                    })
+                    .product::<f64>()
+            })
+            .sum();
+
+        let probability = solution.probability(&one_probabilities).unwrap();
+
+        assert!((probability - expected).abs() < 1e-12);
+    }
+
+    let zero = qmc::BooleanFunction::new(&["A"], &[], &[0, 1])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(zero.probability(&[0.5]).unwrap(), 0.0);
+    assert_eq!(zero.complement().probability(&[0.5]).unwrap(), 1.0);
+    assert!(matches!(
+        zero.probability(&[0.5, 0.5]),
+        Err(qmc::Error::InvalidProbabilities(_))
+    ));
+}
+
+#[test]
+fn compose() {
+    for form in [qmc::SOP, qmc::POS] {
+        // A ∧ B
+        let f = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1, 2])
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+        // ~B ∨ C
+        let g = qmc::BooleanFunction::new(&["B", "C"], &[0, 1, 3], &[2])
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        let solutions = [f.or(&g).unwrap(), f.and(&g).unwrap(), f.xor(&g).unwrap()];
+
+        for (i, solution) in solutions.iter().enumerate() {
+            assert_eq!(solution.variables(), ["A", "B", "C"]);
+            assert_eq!(solution.form(), form);
+
+            for term in 0..8u32 {
+                let is_covered = solution.cubes().iter().any(|cube| cube.contains(term));
+                let (a, b, c) = (term & 4 != 0, term & 2 != 0, term & 1 != 0);
+                let (f_output, g_output) = (a && b, !b || c);
+                let expected = match i {
+                    0 => f_output || g_output,
+                    1 => f_output && g_output,
+                    _ => f_output != g_output,
+                };
+
+                assert_eq!(is_covered == (form == qmc::SOP), expected);
+            }
+        }
+
+        if form == qmc::SOP {
+            assert_eq!(solutions[0].to_string(), "A ∨ ~B ∨ C");
+            assert_eq!(solutions[1].to_string(), "A ∧ B ∧ C");
+        }
+
+        let one = f.cofactor(&[("A", true), ("B", true)]).unwrap();
+        let zero = f.cofactor(&[("A", false), ("B", true)]).unwrap();
+
+        assert!(one.variables().is_empty() && one.is_one());
+        assert!(zero.variables().is_empty() && zero.is_zero());
+
+        assert!(one.or(&zero).unwrap().is_one());
+        assert!(one.and(&zero).unwrap().is_zero());
+        assert!(one.xor(&one).unwrap().is_zero());
+        assert!(one.or(&zero).unwrap().variables().is_empty());
+
+        let g_and_one = g.and(&one).unwrap();
+
+        assert_eq!(g_and_one.variables(), ["B", "C"]);
+        assert_eq!(g_and_one.to_string(), g.to_string());
+        assert!(zero.and(&g).unwrap().is_zero());
+    }
+}
+
+#[test]
+fn minimize_fn() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+    let minterms = [30, 22, 19, 4, 7, 14, 31, 17, 16, 24, 21, 2];
+    let maxterms = [1, 27, 6, 11, 8, 10, 0, 13, 9, 20, 23, 28, 26];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solutions = qmc::minimize_fn(
+            variables,
+            |term| {
+                if minterms.contains(&term) {
+                    Some(true)
+                } else if maxterms.contains(&term) {
+                    Some(false)
+                } else {
+                    None
+                }
+            },
+            form,
+            true,
+            None,
+        )
+        .unwrap();
+        let expected = qmc::minimize(variables, &minterms, &maxterms, form, true, None).unwrap();
+
+        assert_eq!(
+            Vec::from_iter(solutions.iter().map(ToString::to_string)),
+            Vec::from_iter(expected.iter().map(ToString::to_string))
+        );
+    }
+}
+
+#[test]
+fn minimize_from_bits() {
+    let variables = &qmc::DEFAULT_VARIABLES[..7];
+    let on_set = [0x0123_4567_89ab_cdef, 0x8000_0000_0000_0001];
+    let dc_set = [0xfedc_ba98_0000_0000, 0x0000_0000_ff00_0000];
+    let is_set = |words: &[u64], term: u32| words[term as usize / 64] >> (term % 64) & 1 == 1;
+
+    let minterms = Vec::from_iter((0..128).filter(|&term| is_set(&on_set, term)));
+    let maxterms =
+        Vec::from_iter((0..128).filter(|&term| !is_set(&on_set, term) && !is_set(&dc_set, term)));
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solutions =
+            qmc::minimize_from_bits(variables, &on_set, &dc_set, form, false, None).unwrap();
+        let expected = qmc::minimize(variables, &minterms, &maxterms, form, false, None).unwrap();
+
+        assert_eq!(
+            Vec::from_iter(solutions.iter().map(ToString::to_string)),
+            Vec::from_iter(expected.iter().map(ToString::to_string))
+        );
+    }
+}
+
+#[test]
+fn dont_care_predicate() {
+    let variables = &qmc::DEFAULT_VARIABLES[..6];
+    let minterms = [0, 3, 5, 17, 18, 33, 40, 41];
+    let maxterms = [1, 2, 4, 16, 19, 32, 34, 42];
+    // Any term whose upper three bits are at least 6.
+    let is_dont_care = |term: u32| term >> 3 >= 6;
+    let dont_cares = Vec::from_iter((0..64).filter(|&term| is_dont_care(term)));
+
+    let solutions =
+        qmc::minimize_minterms_with(variables, &minterms, is_dont_care, true, None).unwrap();
+    let expected = qmc::minimize_minterms(variables, &minterms, &dont_cares, true, None).unwrap();
+
+    assert_eq!(
+        Vec::from_iter(solutions.iter().map(ToString::to_string)),
+        Vec::from_iter(expected.iter().map(ToString::to_string))
+    );
+
+    let solutions =
+        qmc::minimize_maxterms_with(variables, &maxterms, is_dont_care, true, None).unwrap();
+    let expected = qmc::minimize_maxterms(variables, &maxterms, &dont_cares, true, None).unwrap();
+
+    assert_eq!(
+        Vec::from_iter(solutions.iter().map(ToString::to_string)),
+        Vec::from_iter(expected.iter().map(ToString::to_string))
+    );
+}
+
+#[test]
+fn minimize_terms() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+    let minterms = [30, 22, 19, 4, 7, 14, 31, 17, 16, 24, 21, 2];
+    let maxterms = [1, 27, 6, 11, 8, 10, 0, 13, 9, 20, 23, 28, 26];
+    let terms = minterms
+        .iter()
+        .map(|&term| (term, true))
+        .chain(maxterms.iter().map(|&term| (term, false)))
+        // Repeated terms with the same output are allowed.
+        .chain([(4, true), (0, false)]);
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solutions = qmc::minimize_terms(variables, terms.clone(), form, true, None).unwrap();
+        let expected = qmc::minimize(variables, &minterms, &maxterms, form, true, None).unwrap();
+
+        assert_eq!(
+            Vec::from_iter(solutions.iter().map(ToString::to_string)),
+            Vec::from_iter(expected.iter().map(ToString::to_string))
+        );
+    }
+}
+
+#[test]
+fn minimize_map() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+    let minterms = [30, 22, 19, 4, 7, 14, 31, 17, 16, 24, 21, 2];
+    let maxterms = [1, 27, 6, 11, 8, 10, 0, 13, 9, 20, 23, 28, 26];
+    let outputs = HashMap::from_iter(
+        minterms
+            .iter()
+            .map(|&term| (term, true))
+            .chain(maxterms.iter().map(|&term| (term, false))),
+    );
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solutions = qmc::minimize_map(variables, &outputs, form, true, None).unwrap();
+        let expected = qmc::minimize(variables, &minterms, &maxterms, form, true, None).unwrap();
+
+        assert_eq!(
+            Vec::from_iter(solutions.iter().map(ToString::to_string)),
+            Vec::from_iter(expected.iter().map(ToString::to_string))
+        );
+    }
+}
+
+#[test]
+fn minimize_ranges() {
+    let variables = &qmc::DEFAULT_VARIABLES[..10];
+    let minterms = [0x100..0x180, 0x3F0..0x3F1];
+    let maxterms = [0x000..0x100, 0x180..0x200, 0x300..0x3F0, 0x3F8..0x400];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solutions =
+            qmc::minimize_ranges(variables, &minterms, &maxterms, form, false, None).unwrap();
+        let expected = qmc::minimize(
+            variables,
+            &Vec::from_iter(minterms.iter().cloned().flatten()),
+            &Vec::from_iter(maxterms.iter().cloned().flatten()),
+            form,
+            false,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Vec::from_iter(solutions.iter().map(ToString::to_string)),
+            Vec::from_iter(expected.iter().map(ToString::to_string))
+        );
+    }
+}
+
+#[test]
+fn minimize_ranges_large() {
+    // Almost all of the 2^24 terms are don't cares, which are never listed.
+    let variables = &qmc::DEFAULT_VARIABLES[..24];
+    let minterms = [0x123400..0x123480, 0x123480..0x123500];
+    let maxterms = [0x000000..0x100000, 0x123500..0x123501];
+
+    let solutions =
+        qmc::minimize_ranges(variables, &minterms, &maxterms, qmc::SOP, false, None).unwrap();
+
+    assert_eq!(
+        Vec::from_iter(solutions.iter().map(ToString::to_string)),
+        ["D ∧ ~P"]
+    );
+}
+
+#[test]
+fn minimize_cover() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let cover = ["10-1", "0--0", "1001", "0110"];
+    let dont_cares = ["1111"];
+    let minterms = qmc::Cube::expand(4, &cover).unwrap();
+    let maxterms = Vec::from_iter((0..16).filter(|term| !minterms.contains(term) && *term != 15));
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solutions =
+            qmc::minimize_cover(variables, &cover, &dont_cares, form, true, None).unwrap();
+        let expected = qmc::minimize(variables, &minterms, &maxterms, form, true, None).unwrap();
+
+        assert_eq!(
+            Vec::from_iter(solutions.iter().map(ToString::to_string)),
+            Vec::from_iter(expected.iter().map(ToString::to_string))
+        );
+    }
+}
+
+#[test]
+fn annotations() {
+    let mut solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 3], &[0, 2])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert!(solution.annotations().is_empty());
+    assert_eq!(solution.annotate("source", "spec-17"), None);
+    assert_eq!(solution.annotate("reviewer", "alice"), None);
+    assert_eq!(
+        Vec::from_iter(solution.annotations().keys().map(String::as_str)),
+        ["reviewer", "source"]
+    );
+
+    let complement = solution.complement();
+
+    assert_eq!(complement.annotations(), solution.annotations());
+    assert_eq!(
+        solution.remove_annotation("source"),
+        Some("spec-17".to_owned())
+    );
+    assert_eq!(solution.annotation("source"), None);
+    assert_eq!(complement.annotation("source"), Some("spec-17"));
+}
+
+#[test]
+fn reminimize_preserving() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+    let minterms = [30, 22, 19, 4, 7, 14, 31, 17, 16, 24, 21, 2];
+    let maxterms = [1, 27, 6, 11, 8, 10, 0, 13, 9, 20, 23, 28, 26];
+    // Term 4 becomes a maxterm and don't cares 3 and 5 become minterms.
+    let new_minterms = [30, 22, 19, 3, 5, 7, 14, 31, 17, 16, 24, 21, 2];
+    let new_maxterms = [1, 27, 6, 11, 8, 10, 0, 13, 9, 20, 23, 28, 26, 4];
+    let new_specification =
+        qmc::BooleanFunction::new(variables, &new_minterms, &new_maxterms).unwrap();
+
+    for form in [qmc::SOP, qmc::POS] {
+        let old_solution = qmc::BooleanFunction::new(variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+        let expected = qmc::BooleanFunction::new(variables, &new_minterms, &new_maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+        let literal_count = |solution: &qmc::Cover| {
+            solution
+                .cubes()
+                .iter()
+                .map(qmc::Cube::literal_count)
+                .sum::<usize>()
+        };
+        let reused_count = |solution: &qmc::Cover| {
+            solution
+                .cubes()
+                .iter()
+                .filter(|cube| old_solution.cubes().contains(cube))
+                .count()
+        };
+
+        let cheapest = qmc::reminimize_preserving(&old_solution, &new_specification, 0, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+        let preserving = qmc::reminimize_preserving(&old_solution, &new_specification, 100, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        for solution in [&cheapest, &preserving] {
+            assert_eq!(solution.form(), form);
+            assert!(!solution
+                .to_truth_table_string(&new_specification, 0)
+                .contains('✗'));
+        }
+
+        assert_eq!(literal_count(&cheapest), literal_count(&expected));
+        assert!(reused_count(&preserving) >= reused_count(&cheapest));
+        assert!(reused_count(&preserving) > 0);
+    }
+
+    let old_solution = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+    let new_specification = qmc::BooleanFunction::new(&["A", "C"], &[3], &[0]).unwrap();
+
+    assert!(matches!(
+        qmc::reminimize_preserving(&old_solution, &new_specification, 1, None),
+        Err(qmc::Error::MismatchedVariables { .. })
+    ));
+}
+
+#[test]
+fn kind() {
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 2], &[0, 3])
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        assert_eq!(solution.kind(), qmc::OutputKind::from(form));
+        assert_eq!(solution.kind().form(), Some(solution.form()));
+        assert_eq!(
+            solution.complement().kind().form(),
+            Some(solution.complement().form())
+        );
+    }
+}
+
+#[test]
+fn guarantees() {
+    let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+    let minimum = qmc::Guarantees {
+        algorithm_revision: qmc::ALGORITHM_REVISION,
+        optimality: qmc::Optimality::Minimum,
+    };
+
+    assert_eq!(solution.guarantees(), minimum);
+    assert_eq!(solution.complement().guarantees(), minimum);
+    assert_eq!(
+        solution
+            .cofactor(&[("B", true)])
+            .unwrap()
+            .guarantees()
+            .optimality,
+        qmc::Optimality::Heuristic
+    );
+}
+
+#[test]
+fn substitute_constants() {
+    let variables = ["A", "B", "C", "D"];
+    let minterms = [1, 3, 5, 7, 8, 9, 12, 13, 14];
+    let maxterms = [0, 2, 4, 6, 10, 11, 15];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::BooleanFunction::new(&variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .remove(0);
+
+        for fixed_count in 0..=variables.len() {
+            for assignment in 0..1u32 << fixed_count {
+                let fixed = Vec::from_iter(variables[..fixed_count].iter().enumerate().map(
+                    |(i, &variable)| (variable, assignment >> (fixed_count - i - 1) & 1 == 1),
+                ));
+                let substitution = solution.substitute_constants(&fixed).unwrap();
+                let free_count = variables.len() - fixed_count;
+                let outputs = Vec::from_iter(
+                    (0..1 << free_count)
+                        .map(|term| minterms.contains(&(assignment << free_count | term))),
+                );
+                let expected_output = if outputs.iter().all(|&output| output) {
+                    Some(true)
+                } else if outputs.iter().all(|&output| !output) {
+                    Some(false)
+                } else {
+                    None
+                };
+
+                assert_eq!(substitution.output, expected_output, "{:?}", fixed);
+                assert_eq!(substitution.residual.variables(), &variables[fixed_count..]);
+
+                match expected_output {
+                    Some(true) => assert!(substitution.residual.is_one()),
+                    Some(false) => assert!(substitution.residual.is_zero()),
+                    None => assert_eq!(
+                        substitution.residual.to_string(),
+                        solution.cofactor(&fixed).unwrap().to_string()
+                    ),
+                }
+            }
+        }
+    }
+
+    let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[2, 3, 5, 7], &[0, 1, 4, 6])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_string(), "(A ∧ C) ∨ (~A ∧ B)");
+    assert_eq!(
+        solution
+            .substitute_constants(&[("B", true), ("C", true)])
+            .unwrap()
+            .output,
+        Some(true)
+    );
+}
+
+#[test]
+fn compile() {
+    let variables = ["A", "B", "C", "D"];
+    let minterms = [1, 3, 5, 7, 8, 9, 12, 13, 14];
+    let maxterms = [0, 2, 4, 6, 10, 11, 15];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::BooleanFunction::new(&variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .remove(0);
+        let evaluate = solution.compile();
+
+        for term in 0..16u32 {
+            let inputs = Vec::from_iter((0..4).rev().map(|i| term >> i & 1 == 1));
+
+            assert_eq!(evaluate(&inputs), minterms.contains(&term));
+        }
+    }
+
+    for (minterms, maxterms, output) in [
+        ([].as_slice(), [0, 1].as_slice(), false),
+        (&[0, 1], &[], true),
+    ] {
+        for form in [qmc::SOP, qmc::POS] {
+            let solution = qmc::BooleanFunction::new(&["A"], minterms, maxterms)
+                .unwrap()
+                .minimize(form, false, None)
+                .unwrap()
+                .remove(0);
+
+            assert_eq!(solution.compile()(&[false]), output);
+        }
+    }
+}
+
+#[test]
+fn evaluate() {
+    let variables = ["A", "B", "C", "D"];
+    let minterms = [1, 3, 5, 7, 8, 9, 12, 13, 14];
+    let maxterms = [0, 2, 4, 6, 10, 11, 15];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::BooleanFunction::new(&variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .remove(0);
+
+        for term in 0..16u32 {
+            let inputs = Vec::from_iter((0..4).rev().map(|i| term >> i & 1 == 1));
+            let assignment = HashMap::from_iter(variables.iter().copied().zip(inputs.clone()));
+
+            assert_eq!(solution.evaluate(&inputs), minterms.contains(&term));
+            assert_eq!(
+                solution.evaluate_map(&assignment).unwrap(),
+                minterms.contains(&term)
+            );
+        }
+
+        let assignment = HashMap::from([("A".to_owned(), true), ("B".to_owned(), false)]);
+
+        assert!(matches!(
+            solution.evaluate_map(&assignment),
+            Err(qmc::Error::MissingVariable(variable)) if variable == "C"
+        ));
+    }
+}
+
+#[test]
+#[should_panic(expected = "expected a value for each variable")]
+fn evaluate_wrong_length() {
+    qmc::BooleanFunction::new(&["A", "B"], &[1], &[0])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0)
+        .evaluate(&[true]);
+}
+
+#[test]
+fn evaluate_batch() {
+    let variables = ["A", "B", "C", "D", "E"];
+    let minterms = [1, 3, 5, 7, 8, 9, 12, 13, 14, 17, 19, 22, 23, 28, 30];
+    let maxterms = [
+        0, 2, 4, 6, 10, 11, 15, 16, 18, 20, 21, 24, 25, 26, 27, 29, 31,
+    ];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::BooleanFunction::new(&variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .remove(0);
+
+        // Vector i is term i % 32, so all terms are evaluated twice.
+        let lanes = Vec::from_iter((0..variables.len()).map(|index| {
+            (0..64).fold(0u64, |lane, i| {
+                lane | ((i % 32) >> (variables.len() - index - 1) & 1) << i
+            })
+        }));
+        let outputs = solution.evaluate_batch(&lanes);
+
+        for i in 0..64 {
+            assert_eq!(outputs >> i & 1 == 1, minterms.contains(&(i as u32 % 32)));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "expected a lane for each variable")]
+fn evaluate_batch_lane_count() {
+    qmc::BooleanFunction::new(&["A", "B"], &[1, 3], &[0, 2])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()[0]
+        .evaluate_batch(&[0]);
+}
+
+#[test]
+fn variable_indices() {
+    let variables = ["A", "B", "C", "D"];
+    let solution = qmc::BooleanFunction::new(
+        &variables,
+        &[1, 3, 5, 7, 8, 9, 12, 13, 14],
+        &[0, 2, 4, 6, 10, 11, 15],
+    )
+    .unwrap()
+    .minimize(qmc::SOP, false, None)
+    .unwrap()
+    .remove(0);
+
+    for (index, variable) in variables.iter().enumerate() {
+        assert_eq!(solution.variable_index(variable), Some(index));
+    }
+
+    assert_eq!(solution.variable_index("E"), None);
+
+    for (term, product) in solution.terms().iter().zip(solution.products().unwrap()) {
+        for (variable, literal) in term.iter().zip(&product.literals) {
+            assert_eq!(variables[literal.index], variable.name);
+        }
+    }
+}
+
+#[test]
+fn clauses_and_products() {
+    use qmc::Polarity::{Negative, Positive};
+
+    let variables = ["A", "B", "C"];
+    fn literals(literals: &[qmc::Literal]) -> Vec<(usize, &str, qmc::Polarity)> {
+        Vec::from_iter(
+            literals
+                .iter()
+                .map(|literal| (literal.index, literal.variable.as_str(), literal.polarity)),
+        )
+    }
+
+    let pos = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+    let clauses = pos.clauses().unwrap();
+
+    assert_eq!(pos.to_string(), "(A ∨ C) ∧ (~A ∨ B)");
+    assert_eq!(clauses.len(), 2);
+    assert_eq!(
+        literals(&clauses[0].literals),
+        [(0, "A", Positive), (2, "C", Positive)]
+    );
+    assert_eq!(
+        literals(&clauses[1].literals),
+        [(0, "A", Negative), (1, "B", Positive)]
+    );
+
+    let sop = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+    let products = sop.products().unwrap();
+
+    assert_eq!(sop.to_string(), "(A ∧ B) ∨ (~A ∧ C)");
+    assert_eq!(
+        literals(&products[0].literals),
+        [(0, "A", Positive), (1, "B", Positive)]
+    );
+    assert_eq!(
+        literals(&products[1].literals),
+        [(0, "A", Negative), (2, "C", Positive)]
+    );
+
+    let one = |form| {
+        qmc::BooleanFunction::new(&variables, &[0, 1, 2, 3, 4, 5, 6, 7], &[])
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .remove(0)
+    };
+    let zero = |form| {
+        qmc::BooleanFunction::new(&variables, &[], &[0, 1, 2, 3, 4, 5, 6, 7])
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap()
+            .remove(0)
+    };
+
+    assert!(one(qmc::POS).clauses().unwrap().is_empty());
+    assert_eq!(
+        zero(qmc::POS).clauses().unwrap(),
+        [qmc::Clause { literals: vec![] }]
+    );
+    assert!(zero(qmc::SOP).products().unwrap().is_empty());
+    assert_eq!(
+        one(qmc::SOP).products().unwrap(),
+        [qmc::Product { literals: vec![] }]
+    );
+}
+
+#[test]
+fn clauses_of_sop() {
+    let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 3], &[0, 2])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(solution.clauses().is_none());
+    assert!(solution.complement().products().is_none());
+}
+
+#[test]
+fn to_c_expr() {
+    let variables = ["in.a", "int", "2c"];
+    let solution = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_c_expr(false),
+        "(in_a || _2c) && (!in_a || int_)"
+    );
+    assert_eq!(solution.to_c_expr(true), "(in_a | _2c) & (~in_a | int_)");
+
+    for (minterms, maxterms, logical, bitwise) in [
+        ([].as_slice(), [0, 1].as_slice(), "0", "0"),
+        (&[0, 1], &[], "1", "~0"),
+    ] {
+        let solution = qmc::BooleanFunction::new(&["A"], minterms, maxterms)
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(solution.to_c_expr(false), logical);
+        assert_eq!(solution.to_c_expr(true), bitwise);
+    }
+
+    let solution =
+        qmc::BooleanFunction::new(&["a b", "a_b", "a_b_2"], &[7], &[0, 1, 2, 3, 4, 5, 6])
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+    assert_eq!(solution.to_c_expr(false), "a_b && a_b_2 && a_b_2_2");
+    assert_eq!(
+        solution.to_string_with(qmc::Style::VerilogOps),
+        "\\a_b  & a_b_2 & a_b_2_2"
+    );
+
+    let _guard = qmc::set_default_format(qmc::Style::CStyle);
+
+    assert_eq!(
+        qmc::Solution::from(&solution).to_string(),
+        solution.to_c_expr(false)
+    );
+}
+
+#[test]
+fn to_latex() {
+    let variables = ["x_1", "B", "a&b"];
+    let solution = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_latex(),
+        r"(\mathrm{x\_1} \lor \mathrm{a\&b}) \land (\overline{\mathrm{x\_1}} \lor B)"
+    );
+
+    let solution = qmc::BooleanFunction::new(&["A"], &[], &[0, 1])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_latex(), "0");
+}
+
+#[test]
+fn to_mathml() {
+    let solution = qmc::BooleanFunction::new(&["x<1>", "B", "C"], &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_mathml(),
+        concat!(
+            r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mrow>"#,
+            "<mrow><mo>(</mo><mi>x&lt;1&gt;</mi><mo>∧</mo><mi>B</mi><mo>)</mo></mrow>",
+            "<mo>∨</mo>",
+            r#"<mrow><mo>(</mo><mover accent="true"><mi>x&lt;1&gt;</mi><mo>‾</mo></mover>"#,
+            "<mo>∧</mo><mi>C</mi><mo>)</mo></mrow>",
+            "</mrow></math>"
+        )
+    );
+
+    let solution = qmc::BooleanFunction::new(&["A"], &[0, 1], &[])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_mathml(),
+        r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mrow><mn>1</mn></mrow></math>"#
+    );
+}
+
+#[test]
+fn to_dot() {
+    let solution = qmc::BooleanFunction::new(&["A", "B"], &[1, 2], &[0, 3])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_dot("F"),
+        r#"digraph {
+  rankdir=LR;
+  x0 [label="A", shape=plaintext];
+  x1 [label="B", shape=plaintext];
+  n0 [label="NOT", shape=box];
+  n1 [label="NOT", shape=box];
+  t0 [label="AND", shape=box];
+  t1 [label="AND", shape=box];
+  o [label="OR", shape=box];
+  y [label="F", shape=plaintext];
+  x0 -> n0;
+  x1 -> n1;
+  x0 -> t0;
+  n1 -> t0;
+  n0 -> t1;
+  x1 -> t1;
+  t0 -> o;
+  t1 -> o;
+  o -> y;
+}
+"#
+    );
+
+    let solution = qmc::BooleanFunction::new(&["A", "B\"C"], &[1, 3], &[0, 2])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_dot("F"),
+        r#"digraph {
+  rankdir=LR;
+  x0 [label="A", shape=plaintext];
+  x1 [label="B\"C", shape=plaintext];
+  y [label="F", shape=plaintext];
+  x1 -> y;
+}
+"#
+    );
+
+    let solution = qmc::BooleanFunction::new(&["A"], &[], &[0, 1])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(solution.to_dot("F").contains(
+        "  c [label=\"0\", shape=plaintext];\n  y [label=\"F\", shape=plaintext];\n  c -> y;\n"
+    ));
+}
+
+#[test]
+fn to_python_expr() {
+    let variables = ["in", "B", "c.d"];
+    let solution = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_python_expr(), "(in_ or c_d) and (not in_ or B)");
+
+    let solution = qmc::BooleanFunction::new(&["A"], &[0, 1], &[])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_python_expr(), "True");
+
+    let solution =
+        qmc::BooleanFunction::new(&["if", "if_", "c.d", "c_d"], &[15], &Vec::from_iter(0..15))
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+    assert_eq!(solution.to_python_expr(), "if_ and if__2 and c_d and c_d_2");
+}
+
+#[test]
+fn to_logisim_expr() {
+    let variables = ["S[1]", "or", "in.a"];
+    let solution = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_logisim_expr(), "(S[1] | in_a) & (~S[1] | or_)");
+
+    let export =
+        qmc::TableExport::from_logisim("S[1..0] | Y\n00 | 1\n01 | 1\n10 | 0\n11 | 1\n").unwrap();
+    let solution = export.tables()[0]
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_logisim_expr(), "~S[1] | S[0]");
+}
+
+#[test]
+fn to_rust() {
+    let variables = ["Type", "self", "2c", "D"];
+    let solution = qmc::BooleanFunction::new(
+        &variables,
+        &[2, 3, 6, 7, 9, 11, 13, 15],
+        &[0, 1, 4, 5, 8, 10, 12, 14],
+    )
+    .unwrap()
+    .minimize(qmc::POS, false, None)
+    .unwrap()
+    .remove(0);
+
+    assert_eq!(solution.to_string(), "(Type ∨ 2c) ∧ (~Type ∨ D)");
+    assert_eq!(
+        solution.to_rust_closure().unwrap(),
+        "|r#type: bool, _self_: bool, _2c: bool, d: bool| (r#type || _2c) && (!r#type || d)"
+    );
+    assert_eq!(
+        solution.to_rust_const_fn("fn").unwrap(),
+        "const fn r#fn(r#type: bool, _self_: bool, _2c: bool, d: bool) -> bool {\n    (r#type || _2c) && (!r#type || d)\n}\n"
+    );
+
+    let solution = qmc::BooleanFunction::new(&["A"], &[], &[0, 1])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_rust_closure().unwrap(), "|_a: bool| false");
+
+    // Both variables are used by the first two functions, and only the second one by the last function.
+    for (variables, minterms, maxterms) in [
+        (["A", "a"], &[3][..], &[0, 1, 2][..]),
+        (["a b", "a_b"], &[3], &[0, 1, 2]),
+        (["b", "_b"], &[1, 3], &[0, 2]),
+    ] {
+        let solution = qmc::BooleanFunction::new(&variables, minterms, maxterms)
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+        assert!(matches!(
+            solution.to_rust_closure(),
+            Err(qmc::Error::UnsupportedName(_))
+        ));
+        assert!(matches!(
+            solution.to_rust_const_fn("f"),
+            Err(qmc::Error::UnsupportedName(_))
+        ));
+    }
+}
+
+#[test]
+fn to_verilog() {
+    let variables = ["a[0]", "wire", "c d"];
+    let solution = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_verilog("1st").unwrap(),
+        concat!(
+            "module \\1st (input \\a[0] , input \\wire , input \\c_d , output F0);\n",
+            "  assign F0 = (\\a[0]  | \\c_d ) & (~\\a[0]  | \\wire );\n",
+            "endmodule\n"
+        )
+    );
+
+    let solution = qmc::BooleanFunction::new(&["A", "B"], &[0, 1], &[2, 3])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_verilog("inv").unwrap(),
+        "module inv(input A, input B, output F0);\n  assign F0 = ~A;\nendmodule\n"
+    );
+
+    let solution = qmc::BooleanFunction::new(&["A"], &[0, 1], &[])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(solution
+        .to_verilog("one")
+        .unwrap()
+        .contains("assign F0 = 1'b1;"));
+
+    for variables in [["F0", "B"], ["a b", "a_b"], ["a\tb", "a b"], ["A", "é"]] {
+        let solution = qmc::BooleanFunction::new(&variables, &[3], &[0, 1, 2])
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+        assert!(matches!(
+            solution.to_verilog("f"),
+            Err(qmc::Error::UnsupportedName(_))
+        ));
+    }
+
+    let solution = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0, 1, 2])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(matches!(
+        solution.to_verilog("ƒ"),
+        Err(qmc::Error::UnsupportedName(_))
+    ));
+}
+
+#[test]
+fn to_systemverilog() {
+    let function = qmc::BooleanFunction::new(&["logic", "B", "C"], &[1, 7], &[0, 2, 4, 6]).unwrap();
+    let mut solution = function.minimize(qmc::SOP, false, None).unwrap().remove(0);
+
+    assert_eq!(solution.to_string(), "C");
+    assert_eq!(
+        solution.to_systemverilog("int").unwrap(),
+        "function automatic logic \\int (input logic \\logic , B, C);\n  return C;\nendfunction\n"
+    );
+
+    function.certify(&mut solution).unwrap();
+
+    assert_eq!(
+        solution.to_systemverilog("f").unwrap(),
+        concat!(
+            "function automatic logic f(input logic \\logic , B, C);\n",
+            "  // Don't cares assigned 1: 3, 5\n",
+            "  return C;\n",
+            "endfunction\n"
+        )
+    );
+
+    for variables in [["f", "B"], ["a b", "a_b"], ["a\tb", "a b"], ["A", "é"]] {
+        let solution = qmc::BooleanFunction::new(&variables, &[3], &[0, 1, 2])
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+        assert!(matches!(
+            solution.to_systemverilog("f"),
+            Err(qmc::Error::UnsupportedName(_))
+        ));
+    }
+}
+
+#[test]
+fn to_vhdl() {
+    let variables = ["a[0]", "Signal", "c__d"];
+    let solution = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+    let vhdl = solution.to_vhdl("x\\y").unwrap();
+
+    assert!(vhdl.contains("entity \\x\\\\y\\ is\n"));
+    assert!(vhdl
+        .contains("  port (\\a[0]\\, \\Signal\\, \\c__d\\ : in std_logic; F0 : out std_logic);\n"));
+    assert!(vhdl.contains("  F0 <= (\\a[0]\\ or \\c__d\\) and (not \\a[0]\\ or \\Signal\\);\n"));
+
+    let solution = qmc::BooleanFunction::new(&["A", "B"], &[0, 1], &[2, 3])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(solution
+        .to_vhdl("inv")
+        .unwrap()
+        .contains("  F0 <= not A;\n"));
+
+    let solution = qmc::BooleanFunction::new(&["A"], &[], &[0, 1])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(solution.to_vhdl("zero").unwrap().contains("  F0 <= '0';\n"));
+
+    for variables in [
+        ["A", "a"],
+        ["f0", "B"],
+        ["E", "B"],
+        ["a\tb", "B"],
+        ["A", "é"],
+    ] {
+        let solution = qmc::BooleanFunction::new(&variables, &[3], &[0, 1, 2])
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+        assert!(matches!(
+            solution.to_vhdl("e"),
+            Err(qmc::Error::UnsupportedName(_))
+        ));
+    }
+}
+
+#[test]
+fn to_smtlib() {
+    let variables = ["A", "B", "2C"];
+    let solution = qmc::BooleanFunction::new(&variables, &[1, 3, 6, 7], &[0, 2, 4, 5])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_string(), "(A ∨ 2C) ∧ (~A ∨ B)");
+    assert_eq!(
+        solution.to_smtlib("out 1").unwrap(),
+        "(define-fun |out 1| ((A Bool) (B Bool) (|2C| Bool)) Bool (and (or A |2C|) (or (not A) B)))"
+    );
+
+    let solution = qmc::BooleanFunction::new(&variables, &[0, 1, 2, 3], &[4, 5, 6, 7])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_smtlib("f").unwrap(),
+        "(define-fun f ((A Bool) (B Bool) (|2C| Bool)) Bool (not A))"
+    );
+
+    for (minterms, maxterms, constant) in [
+        ([].as_slice(), [0, 1].as_slice(), "false"),
+        (&[0, 1], &[], "true"),
+    ] {
+        for form in [qmc::SOP, qmc::POS] {
+            let solution = qmc::BooleanFunction::new(&["A"], minterms, maxterms)
+                .unwrap()
+                .minimize(form, false, None)
+                .unwrap()
+                .remove(0);
+
+            assert_eq!(
+                solution.to_smtlib("f").unwrap(),
+                format!("(define-fun f ((A Bool)) Bool {})", constant)
+            );
+        }
+    }
+
+    let solution = qmc::BooleanFunction::new(&["and", "true"], &[3], &[0, 1, 2])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_smtlib("let").unwrap(),
+        "(define-fun |let| ((|and| Bool) (|true| Bool)) Bool (and |and| |true|))"
+    );
+
+    for name in ["a|b", "a\\b"] {
+        assert!(matches!(
+            solution.to_smtlib(name),
+            Err(qmc::Error::UnsupportedName(_))
+        ));
+    }
+
+    let solution = qmc::BooleanFunction::new(&["A", "B|C"], &[3], &[0, 1, 2])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(matches!(
+        solution.to_smtlib("f"),
+        Err(qmc::Error::UnsupportedName(_))
+    ));
+}
+
+#[test]
+fn to_dimacs() {
+    let variables = ["A", "B\nC", "D"];
+    let solution = qmc::BooleanFunction::new(&variables, &[0, 1, 3], &[2, 6])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_string(), "~B\nC ∨ D");
+    assert_eq!(
+        solution.to_dimacs().unwrap(),
+        "c 1 A\nc 2 B C\nc 3 D\np cnf 3 1\n-2 3 0\n"
+    );
+
+    let one = qmc::BooleanFunction::new(&variables, &[0], &[])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+    let zero = qmc::BooleanFunction::new(&variables, &[], &[0])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(one.to_dimacs().unwrap().ends_with("p cnf 3 0\n"));
+    assert!(zero.to_dimacs().unwrap().ends_with("p cnf 3 1\n0\n"));
+}
+
+#[test]
+fn to_dimacs_sop() {
+    let solution = qmc::BooleanFunction::new(&["A"], &[1], &[0])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert!(matches!(
+        solution.to_dimacs(),
+        Err(qmc::Error::UnsupportedForm(qmc::SOP))
+    ));
+}
+
+#[test]
+fn anytime() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+    let minterms = [30, 22, 19, 4, 7, 14, 31, 17, 16, 24, 21, 2];
+    let maxterms = [1, 27, 6, 11, 8, 10, 0, 13, 9, 20, 23, 28, 26];
+
+    for form in [qmc::SOP, qmc::POS] {
+        let mut solutions: Vec<qmc::Cover> = vec![];
+
+        let solution = qmc::minimize_anytime(
+            variables,
+            &minterms,
+            &maxterms,
+            form,
+            Duration::from_secs(60),
+            |solution| solutions.push(solution.clone()),
+        )
+        .unwrap();
+
+        let expected = qmc::BooleanFunction::new(variables, &minterms, &maxterms)
+            .unwrap()
+            .minimize(form, false, None)
+            .unwrap();
+
+        assert!(!solutions.is_empty() && solutions.len() <= 2);
+        assert_eq!(solution.to_string(), solutions.last().unwrap().to_string());
+        assert_eq!(
+            solutions[0].guarantees().optimality,
+            qmc::Optimality::Heuristic
+        );
+        assert_eq!(solution.guarantees().optimality, qmc::Optimality::Minimum);
+        assert_eq!(
+            solution.terms().len(),
+            expected.last().unwrap().terms().len()
+        );
+    }
+}
+
 fn test(
     variable_count: u32,
     minterms: &[u32],
@@ -161,3 +1780,150 @@ fn get_dont_cares(
 
     all_terms.difference(&cares).copied().collect()
 }
+
+#[test]
+fn all_dont_cares() {
+    for (form, expected) in [(qmc::SOP, ["0", "1"]), (qmc::POS, ["1", "0"])] {
+        let solutions = qmc::BooleanFunction::new(&["A", "B"], &[], &[])
+            .unwrap()
+            .minimize(form, true, None)
+            .unwrap();
+
+        assert_eq!(
+            Vec::from_iter(solutions.iter().map(ToString::to_string)),
+            expected
+        );
+
+        for solution in &solutions {
+            assert!(solution.is_constant());
+            assert_eq!(solution.warnings(), [qmc::Warning::AllDontCares]);
+        }
+    }
+
+    let function = qmc::BooleanFunction::new(&["A"], &[], &[]).unwrap();
+    let solutions = function.minimize(qmc::SOP, false, None).unwrap();
+
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "0");
+    assert_eq!(solutions[0].warnings(), [qmc::Warning::AllDontCares]);
+
+    assert!(matches!(
+        qmc::minimize_minterms(&["A", "B"], &[], &[0, 1, 2, 3], true, None)
+            .unwrap()
+            .as_slice(),
+        [qmc::Solution::Zero, qmc::Solution::One]
+    ));
+    assert!(matches!(
+        qmc::minimize_maxterms(&["A", "B"], &[], &[0, 1, 2, 3], true, None)
+            .unwrap()
+            .as_slice(),
+        [qmc::Solution::One, qmc::Solution::Zero]
+    ));
+}
+
+#[test]
+fn no_warnings() {
+    let solutions = qmc::BooleanFunction::new(&["A", "B"], &[], &[0, 1, 2, 3])
+        .unwrap()
+        .minimize(qmc::SOP, true, None)
+        .unwrap();
+
+    assert!(solutions[0].is_constant());
+    assert!(solutions[0].warnings().is_empty());
+
+    let solutions = qmc::BooleanFunction::new(&["A", "B"], &[3], &[0])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap();
+
+    assert!(!solutions[0].is_constant());
+    assert!(solutions[0].warnings().is_empty());
+}
+
+#[test]
+fn cost_metrics() {
+    let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[4, 5, 7], &[0, 1, 2, 3, 6])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    // A ∧ (~B ∨ C)
+    assert_eq!(solution.term_count(), 2);
+    assert_eq!(solution.literal_count(), 3);
+    assert_eq!(solution.gate_input_count(), 4);
+
+    let solution = qmc::BooleanFunction::new(&["A", "B"], &[2, 3], &[0, 1])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    // A
+    assert_eq!(solution.term_count(), 1);
+    assert_eq!(solution.literal_count(), 1);
+    assert_eq!(solution.gate_input_count(), 0);
+
+    for (minterms, maxterms) in [(vec![0, 1, 2, 3], vec![]), (vec![], vec![0, 1, 2, 3])] {
+        let solution = qmc::BooleanFunction::new(&["A", "B"], &minterms, &maxterms)
+            .unwrap()
+            .minimize(qmc::SOP, false, None)
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(solution.term_count(), 0);
+        assert_eq!(solution.literal_count(), 0);
+        assert_eq!(solution.gate_input_count(), 0);
+    }
+}
+
+#[test]
+fn to_ast() {
+    use qmc::Expr::{And, Const, Not, Or, Var};
+
+    let var = |name: &str| Var(name.to_owned());
+
+    let solution = qmc::BooleanFunction::new(&["A", "B", "C"], &[4, 5, 7], &[0, 1, 2, 3, 6])
+        .unwrap()
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        solution.to_ast(),
+        And(vec![var("A"), Or(vec![Not(Box::new(var("B"))), var("C")])])
+    );
+
+    for term in 0..8 {
+        let assignment = [term & 4 != 0, term & 2 != 0, term & 1 != 0];
+        let value = |name: &str| assignment[solution.variable_index(name).unwrap()];
+
+        assert_eq!(
+            solution.to_ast().evaluate(&value),
+            solution.evaluate(&assignment)
+        );
+    }
+
+    let solution = qmc::BooleanFunction::new(&["A", "B"], &[2, 3], &[0, 1])
+        .unwrap()
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(solution.to_ast(), var("A"));
+
+    for (minterms, maxterms, expected) in [
+        (vec![0, 1, 2, 3], vec![], true),
+        (vec![], vec![0, 1, 2, 3], false),
+    ] {
+        for form in [qmc::SOP, qmc::POS] {
+            let solution = qmc::BooleanFunction::new(&["A", "B"], &minterms, &maxterms)
+                .unwrap()
+                .minimize(form, false, None)
+                .unwrap()
+                .remove(0);
+
+            assert_eq!(solution.to_ast(), Const(expected));
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn table_export_logisim_multi_bit() {
+    let export = qmc::TableExport::from_logisim(
+        "\
+# Truth table
+# Generated by Logisim-evolution
+
+S[1..0] E | Y
+~~~~~~~~~~~~~
+   00   0 | 0
+   00   1 | 1
+   01   - | -
+   1-   0 | 0
+   10   1 | 1
+   11   1 | 0
+",
+    )
+    .unwrap();
+
+    assert_eq!(export.inputs(), ["S[1]", "S[0]", "E"]);
+    assert_eq!(export.outputs(), ["Y"]);
+    assert_eq!(export.tables()[0].minterms(), [1, 5]);
+    assert_eq!(export.tables()[0].dont_cares(), [2, 3]);
+}
+
+#[test]
+fn table_export_digital() {
+    let csv = "\"A\",\"B\",,\"X\",\"Y\"\n0,0,,0,1\n0,1,,1,0\n1,0,,1,0\n1,1,,1,1\n";
+    let text = csv.replace(',', "\t").replace('"', "");
+
+    for export in [csv, &text] {
+        let export = qmc::TableExport::from_digital(export).unwrap();
+
+        assert_eq!(export.inputs(), ["A", "B"]);
+        assert_eq!(export.outputs(), ["X", "Y"]);
+        assert_eq!(export.tables()[0].minterms(), [1, 2, 3]);
+        assert_eq!(export.tables()[1].minterms(), [0, 3]);
+    }
+}
+
+#[test]
+fn table_export_invalid() {
+    let logisim = |s: &str| qmc::TableExport::from_logisim(s);
+
+    assert!(matches!(
+        logisim("A B\n0 0\n"),
+        Err(qmc::Error::InvalidTableExport(_))
+    ));
+    assert!(matches!(
+        logisim("A | Y\n0 | 1\n"),
+        Err(qmc::Error::InvalidTableExport(reason)) if reason == "missing row for term 1"
+    ));
+    assert!(matches!(
+        logisim("A | Y\n0 | 1\n- | 0\n"),
+        Err(qmc::Error::InvalidTableExport(reason)) if reason == "line 3: duplicate row for term 0"
+    ));
+    assert!(matches!(
+        logisim("A | Y\n0 | 1\n1 | E\n"),
+        Err(qmc::Error::InvalidTableExport(_))
+    ));
+    assert!(matches!(
+        logisim("A | Y\n0 1 | 1\n"),
+        Err(qmc::Error::InvalidTableExport(_))
+    ));
+    assert!(matches!(
+        qmc::TableExport::from_digital("A,Y\n0,1\n"),
+        Err(qmc::Error::InvalidTableExport(reason))
+            if reason == "line 1: expected an empty cell between the inputs and the outputs"
+    ));
+}
@@ -84,3 +84,88 @@ fn timeout3() {
     )
     .unwrap();
 }
+
+#[test]
+fn timeout_error() {
+    assert!(matches!(
+        qmc::minimize(
+            &qmc::DEFAULT_VARIABLES[..10],
+            TERMS1,
+            TERMS2,
+            qmc::SOP,
+            false,
+            Some(Duration::from_millis(100)),
+        ),
+        Err(qmc::Error::Timeout)
+    ));
+}
+
+#[test]
+fn anytime() {
+    let mut solutions: Vec<qmc::Cover> = vec![];
+
+    let solution = qmc::minimize_anytime(
+        &qmc::DEFAULT_VARIABLES[..10],
+        TERMS1,
+        TERMS2,
+        qmc::SOP,
+        Duration::from_secs(3),
+        |solution| solutions.push(solution.clone()),
+    )
+    .unwrap();
+
+    // The greedy solution is available before the exact one.
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solution.to_string(), solutions[0].to_string());
+    assert_eq!(solution.guarantees().optimality, qmc::Optimality::Heuristic);
+}
+
+#[test]
+#[should_panic(expected = "ExceedsStaticBudget")]
+fn static_budget() {
+    qmc::minimize_bounded(
+        &qmc::DEFAULT_VARIABLES[..10],
+        TERMS1,
+        TERMS2,
+        qmc::SOP,
+        false,
+        1_000_000_000,
+    )
+    .unwrap();
+}
+
+#[test]
+fn estimate_work() {
+    // Cyclic chart: 6 prime implicants covering 2 minterms each.
+    let function =
+        qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..3], &[0, 1, 2, 5, 6, 7], &[3, 4])
+            .unwrap();
+    assert_eq!(qmc::estimate_work(&function, qmc::SOP), 6 * 6 + (1 << 6));
+
+    // A and B both cover minterm 3.
+    let function =
+        qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..2], &[1, 2, 3], &[0]).unwrap();
+    assert_eq!(qmc::estimate_work(&function, qmc::SOP), 2 * 3 + 2);
+    assert_eq!(qmc::estimate_work(&function, qmc::POS), 1 + 1);
+}
+
+#[test]
+fn static_budget_many_terms() {
+    let minterms = Vec::from_iter((0..64).chain(128..192));
+    let maxterms = Vec::from_iter((64..128).chain(192..256));
+    let function =
+        qmc::BooleanFunction::new(&qmc::DEFAULT_VARIABLES[..8], &minterms, &maxterms).unwrap();
+
+    assert_eq!(qmc::estimate_work(&function, qmc::SOP), 128 + 1);
+
+    let solutions = qmc::minimize_bounded(
+        &qmc::DEFAULT_VARIABLES[..8],
+        &minterms,
+        &maxterms,
+        qmc::SOP,
+        false,
+        1_000,
+    )
+    .unwrap();
+    assert_eq!(solutions[0].to_string(), "~B");
+}
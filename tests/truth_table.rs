@@ -0,0 +1,196 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn truth_table_macro() {
+    let table = qmc::truth_table! {
+        Sel In0 In1 => Out;
+        0 0 0 => 0;
+        0 0 1 => 0;
+        0 1 0 => 1;
+        0 1 1 => 1;
+        1 0 0 => 0;
+        1 0 1 => 1;
+        1 1 0 => 0;
+        1 1 1 => 1;
+    };
+
+    assert_eq!(table.variables(), ["Sel", "In0", "In1"]);
+    assert_eq!(
+        qmc::minimize(
+            table.variables(),
+            &table.minterms(),
+            &table.maxterms(),
+            qmc::SOP,
+            false,
+            None,
+        )
+        .unwrap()
+        .pop()
+        .unwrap()
+        .to_string(),
+        "(Sel ∧ In1) ∨ (~Sel ∧ In0)"
+    );
+}
+
+#[test]
+fn truth_table_macro_unordered_rows() {
+    let table = qmc::truth_table! {
+        A B => Y;
+        1 1 => x;
+        0 0 => -;
+        1 0 => 1;
+        0 1 => 0
+    };
+
+    assert_eq!(table.outputs(), [None, Some(false), Some(true), None]);
+}
+
+#[test]
+#[should_panic(expected = "truth table does not contain all rows")]
+fn truth_table_macro_missing_row() {
+    qmc::truth_table! {
+        A B => Y;
+        0 0 => 1;
+        0 1 => 0;
+        1 1 => 0;
+    };
+}
+
+#[test]
+#[should_panic(expected = "is duplicated")]
+fn truth_table_macro_duplicated_row() {
+    qmc::truth_table! {
+        A => Y;
+        0 => 1;
+        1 => 0;
+        0 => 0;
+    };
+}
+
+#[test]
+#[should_panic(expected = "does not have a bit for each variable")]
+fn truth_table_macro_short_row() {
+    qmc::truth_table! {
+        A B => Y;
+        0 0 => 1;
+        0 1 => 0;
+        1 => 0;
+    };
+}
+
+#[test]
+#[should_panic(expected = "InvalidOutputCount")]
+fn invalid_output_count() {
+    qmc::TruthTable::new(&["A", "B"], &[Some(true), None, Some(false)]).unwrap();
+}
+
+#[test]
+fn minimize_truth_table() {
+    let table = qmc::truth_table! {
+        A B C => Y;
+        0 0 0 => 1;
+        0 0 1 => 0;
+        0 1 0 => X;
+        0 1 1 => 0;
+        1 0 0 => 0;
+        1 0 1 => 1;
+        1 1 0 => 0;
+        1 1 1 => X;
+    };
+    let function = qmc::BooleanFunction::from(&table);
+
+    assert_eq!(function.variables(), table.variables());
+    assert_eq!(
+        Vec::from_iter((0..8).map(|term| function.evaluate(term))),
+        table.outputs()
+    );
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solutions = table.minimize(form, true, None).unwrap();
+        let expected = qmc::minimize(
+            table.variables(),
+            &table.minterms(),
+            &table.maxterms(),
+            form,
+            true,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            solutions
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            expected.iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn truth_table_column() {
+    let table = qmc::TruthTable::from_column(&["A", "B", "C"], "10X0X101", qmc::BitOrder::MsbFirst)
+        .unwrap();
+
+    assert_eq!(table.minterms(), [0, 5, 7]);
+    assert_eq!(table.maxterms(), [1, 3, 6]);
+    assert_eq!(table.dont_cares(), [2, 4]);
+
+    // Rows 1 (A = 1) and 4 (C = 1) are swapped with terms 4 and 1.
+    let table = qmc::TruthTable::from_column(&["A", "B", "C"], "10X0X101", qmc::BitOrder::LsbFirst)
+        .unwrap();
+
+    assert_eq!(table.minterms(), [0, 5, 7]);
+    assert_eq!(table.maxterms(), [3, 4, 6]);
+    assert_eq!(table.dont_cares(), [1, 2]);
+}
+
+#[test]
+fn invalid_truth_table_column() {
+    for bit_order in [qmc::BitOrder::MsbFirst, qmc::BitOrder::LsbFirst] {
+        assert!(matches!(
+            qmc::TruthTable::from_column(&["A", "B"], "10X2", bit_order),
+            Err(qmc::Error::InvalidTruthTableColumn(_))
+        ));
+        assert!(matches!(
+            qmc::TruthTable::from_column(&["A", "B"], "10X", bit_order),
+            Err(qmc::Error::InvalidOutputCount(3))
+        ));
+    }
+}
+
+#[test]
+fn hex_truth_table() {
+    let table = qmc::TruthTable::from_hex(&["A", "B"], "0x6").unwrap();
+
+    assert_eq!(table.minterms(), [1, 2]);
+    assert_eq!(table.maxterms(), [0, 3]);
+
+    let table = qmc::TruthTable::from_hex(&qmc::DEFAULT_VARIABLES[..5], "8000fffe").unwrap();
+
+    assert_eq!(
+        table.maxterms(),
+        [0, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30]
+    );
+    assert!(table.dont_cares().is_empty());
+
+    let table = qmc::TruthTable::from_hex(&["A"], "2").unwrap();
+
+    assert_eq!(table.minterms(), [1]);
+}
+
+#[test]
+fn invalid_hex_truth_table() {
+    for hex in ["", "E", "0E8", "G8", "+8"] {
+        assert!(matches!(
+            qmc::TruthTable::from_hex(&["A", "B", "C"], hex),
+            Err(qmc::Error::InvalidHexTruthTable(_))
+        ));
+    }
+
+    // A bit beyond the 2 terms of 1 variable.
+    assert!(matches!(
+        qmc::TruthTable::from_hex(&["A"], "4"),
+        Err(qmc::Error::InvalidHexTruthTable(_))
+    ));
+}
@@ -0,0 +1,101 @@
+use quine_mccluskey as qmc;
+
+use qmc::Error;
+
+#[test]
+fn invalid_variable_set() {
+    assert!(matches!(
+        qmc::VariableSet::new::<&str>(&[]),
+        Err(Error::InvalidVariableCount(0))
+    ));
+    assert!(matches!(
+        qmc::VariableSet::new(&["A", "1"]),
+        Err(Error::InvalidVariable)
+    ));
+    assert!(matches!(
+        qmc::VariableSet::new(&["A", "A"]),
+        Err(Error::DuplicateVariables(_))
+    ));
+}
+
+#[test]
+fn checked_term() {
+    let variables = qmc::VariableSet::new(&["A", "B"]).unwrap();
+
+    assert_eq!(qmc::Term::new(&variables, 3).unwrap().value(), 3);
+    assert!(matches!(
+        qmc::Term::new(&variables, 4),
+        Err(Error::TermOutOfBounds {
+            variable_count: 2,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn checked_cube() {
+    let variables = qmc::VariableSet::new(&["A", "B", "C"]).unwrap();
+
+    assert_eq!(
+        qmc::Cube::new(&variables, "-1-").unwrap().terms(),
+        [2, 3, 6, 7]
+    );
+    assert!(matches!(
+        qmc::Cube::new(&variables, "-1"),
+        Err(Error::InvalidCube(cube)) if cube == "-1"
+    ));
+}
+
+#[test]
+fn function_from_terms() {
+    let variables = qmc::VariableSet::new(&["A", "B", "C"]).unwrap();
+    let terms = |values: &[u32]| {
+        values
+            .iter()
+            .map(|&value| qmc::Term::new(&variables, value).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    let function =
+        qmc::BooleanFunction::from_terms(&variables, &terms(&[0, 5]), &terms(&[1, 3, 4, 6]))
+            .unwrap();
+
+    assert_eq!(
+        function,
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 5], &[1, 3, 4, 6]).unwrap()
+    );
+    assert!(matches!(
+        qmc::BooleanFunction::from_terms(&variables, &terms(&[0]), &terms(&[0])),
+        Err(Error::TermConflict(_))
+    ));
+
+    let larger_variables = qmc::VariableSet::new(&["A", "B", "C", "D"]).unwrap();
+    let term = qmc::Term::new(&larger_variables, 8).unwrap();
+
+    assert!(matches!(
+        qmc::BooleanFunction::from_terms(&variables, &[term], &[]),
+        Err(Error::TermOutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn function_from_cubes() {
+    let variables = qmc::VariableSet::new(&["A", "B", "C"]).unwrap();
+    let cube = |cube| qmc::Cube::new(&variables, cube).unwrap();
+
+    let function =
+        qmc::BooleanFunction::from_cubes(&variables, &[cube("0-0"), cube("111")], &[cube("1-0")])
+            .unwrap();
+
+    assert_eq!(
+        function,
+        qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 2, 7], &[4, 6]).unwrap()
+    );
+
+    let other_cube: qmc::Cube = "1-".parse().unwrap();
+
+    assert!(matches!(
+        qmc::BooleanFunction::from_cubes(&variables, &[other_cube], &[]),
+        Err(Error::InvalidCube(_))
+    ));
+}
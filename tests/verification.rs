@@ -0,0 +1,92 @@
+use qmc::verification::{self, Cost, Discrepancy};
+use quine_mccluskey as qmc;
+
+fn cubes(cubes: &[&str]) -> Result<Vec<qmc::Cube>, qmc::Error> {
+    cubes.iter().map(|cube| cube.parse()).collect()
+}
+
+#[test]
+fn agreeing_reference() {
+    let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 5], &[1, 3, 4, 6]).unwrap();
+
+    let solution = function
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+    let report =
+        verification::cross_check(&function, &solution, |_, _| cubes(&["1-1", "0-0"])).unwrap();
+
+    assert_eq!(
+        report.solution_cost,
+        Cost {
+            terms: 2,
+            literals: 4
+        }
+    );
+    assert_eq!(report.reference_cost, report.solution_cost);
+    assert!(report.discrepancies.is_empty());
+
+    // The reference minimizes the complement for POS form.
+    let solution = function
+        .minimize(qmc::POS, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+    let report = verification::cross_check(&function, &solution, |_, form| {
+        assert_eq!(form, qmc::POS);
+        cubes(&["0-1", "1-0"])
+    })
+    .unwrap();
+
+    assert!(report.discrepancies.is_empty());
+}
+
+#[test]
+fn incorrect_reference() {
+    let function = qmc::BooleanFunction::new(&["A", "B", "C"], &[0, 5], &[1, 3, 4, 6]).unwrap();
+    let solution = function
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    let report =
+        verification::cross_check(&function, &solution, |_, _| cubes(&["1-1", "0--"])).unwrap();
+
+    assert_eq!(
+        report.discrepancies,
+        [
+            Discrepancy::Cost {
+                solution: Cost {
+                    terms: 2,
+                    literals: 4
+                },
+                reference: Cost {
+                    terms: 2,
+                    literals: 3
+                },
+            },
+            Discrepancy::ReferenceMismatch(1),
+            Discrepancy::ReferenceMismatch(3),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "ExternalMinimizer")]
+fn missing_espresso() {
+    let function = qmc::BooleanFunction::new(&["A"], &[0], &[1]).unwrap();
+    let solution = function
+        .minimize(qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    verification::cross_check(
+        &function,
+        &solution,
+        verification::espresso("/nonexistent/espresso"),
+    )
+    .unwrap();
+}